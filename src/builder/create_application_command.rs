@@ -350,6 +350,16 @@ impl CreateApplicationCommand {
         self
     }
 
+    /// Specifies if the command is age-restricted.
+    ///
+    /// **Note**: Discord only shows age-restricted commands in NSFW channels,
+    /// and hides them in DMs unless the user has opted into seeing them.
+    pub fn nsfw(&mut self, nsfw: bool) -> &mut Self {
+        self.0.insert("nsfw", Value::from(nsfw));
+
+        self
+    }
+
     /// Specifies if the command should not be usable by default
     ///
     /// **Note**: Setting it to false will disable it for anyone,
@@ -430,6 +440,13 @@ impl CreateApplicationCommand {
     }
 }
 
+/// A builder for bulk overwriting application commands.
+///
+/// The commands are sent as a JSON array in a single request, replacing all
+/// previously registered commands. See
+/// [`Command::set_global_application_commands`].
+///
+/// [`Command::set_global_application_commands`]: crate::model::application::command::Command::set_global_application_commands
 #[derive(Clone, Debug, Default)]
 pub struct CreateApplicationCommands(pub Vec<Value>);
 
@@ -471,3 +488,35 @@ impl CreateApplicationCommands {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CreateApplicationCommand, CreateApplicationCommands};
+    use crate::json::{json, Value};
+
+    #[test]
+    fn test_commands_serialize_as_array() {
+        let mut ping = CreateApplicationCommand::default();
+        ping.name("ping").description("Pong!");
+
+        let mut commands = CreateApplicationCommands::default();
+        commands
+            .add_application_command(ping)
+            .create_application_command(|c| c.name("echo").description("Echoes the input"));
+
+        let value = Value::from(commands.0);
+        let array = value.as_array().unwrap();
+
+        assert_eq!(array.len(), 2);
+        assert_eq!(array[0]["name"], json!("ping"));
+        assert_eq!(array[1]["description"], json!("Echoes the input"));
+    }
+
+    #[test]
+    fn test_nsfw() {
+        let mut command = CreateApplicationCommand::default();
+        command.name("roll").description("Rolls a die").nsfw(true);
+
+        assert_eq!(command.0["nsfw"], json!(true));
+    }
+}
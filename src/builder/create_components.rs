@@ -2,7 +2,12 @@ use std::collections::HashMap;
 
 use crate::internal::prelude::*;
 use crate::json::{self, from_number, Value};
-use crate::model::application::component::{ButtonStyle, InputTextStyle};
+use crate::model::application::component::{
+    ButtonStyle,
+    ComponentType,
+    InputTextStyle,
+    SelectMenuDefaultValue,
+};
 use crate::model::channel::ReactionType;
 
 /// A builder for creating several [`ActionRow`]s.
@@ -73,6 +78,11 @@ impl CreateActionRow {
     }
 
     /// Adds a button.
+    ///
+    /// **Note**: An action row can hold up to 5 buttons. Sending a message
+    /// with more will return a [`ModelError::ButtonAmount`].
+    ///
+    /// [`ModelError::ButtonAmount`]: crate::model::error::Error::ButtonAmount
     pub fn add_button(&mut self, button: CreateButton) -> &mut Self {
         let components =
             self.0.entry("components").or_insert_with(|| Value::from(Vec::<Value>::new()));
@@ -140,6 +150,10 @@ impl CreateActionRow {
 
 /// A builder for creating a [`Button`].
 ///
+/// Link buttons, created with [`Self::new_link`], open a URL and don't have a
+/// custom id. Every other button has a custom id and no URL. Setting one of
+/// the two removes the other.
+///
 /// [`Button`]: crate::model::application::component::Button
 #[derive(Clone, Debug)]
 pub struct CreateButton(pub HashMap<&'static str, Value>);
@@ -154,7 +168,24 @@ impl Default for CreateButton {
 }
 
 impl CreateButton {
+    /// Creates a primary button with the given custom id.
+    pub fn new<D: ToString>(custom_id: D) -> Self {
+        let mut btn = Self::default();
+        btn.custom_id(custom_id);
+        btn
+    }
+
+    /// Creates a link button opening the given url.
+    pub fn new_link<D: ToString>(url: D) -> Self {
+        let mut btn = Self::default();
+        btn.url(url);
+        btn
+    }
+
     /// Sets the style of the button.
+    ///
+    /// **Note**: The [`ButtonStyle::Link`] style is set by [`Self::url`], and
+    /// must not be combined with a custom id.
     pub fn style(&mut self, kind: ButtonStyle) -> &mut Self {
         self.0.insert("style", from_number(kind as u8));
         self
@@ -167,13 +198,26 @@ impl CreateButton {
     }
 
     /// Sets the custom id of the button, a developer-defined identifier.
+    ///
+    /// This removes the url, and turns a link button into a primary one.
     pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.remove("url");
+
+        if self.0.get("style") == Some(&from_number(ButtonStyle::Link as u8)) {
+            self.style(ButtonStyle::Primary);
+        }
+
         self.0.insert("custom_id", Value::from(id.to_string()));
         self
     }
 
     /// The url for url style button.
+    ///
+    /// This removes the custom id and sets the style to [`ButtonStyle::Link`].
     pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.remove("custom_id");
+        self.style(ButtonStyle::Link);
+
         self.0.insert("url", Value::from(url.to_string()));
         self
     }
@@ -229,6 +273,19 @@ impl CreateButton {
 pub struct CreateSelectMenu(pub HashMap<&'static str, Value>);
 
 impl CreateSelectMenu {
+    /// Sets the type of the select menu. By default, a select menu with
+    /// developer-defined [`options`] is created.
+    ///
+    /// Use one of [`ComponentType::UserSelect`], [`ComponentType::RoleSelect`],
+    /// [`ComponentType::MentionableSelect`] or [`ComponentType::ChannelSelect`]
+    /// to create a select menu auto-populated by Discord.
+    ///
+    /// [`options`]: Self::options
+    pub fn kind(&mut self, kind: ComponentType) -> &mut Self {
+        self.0.insert("type", from_number(kind as u8));
+        self
+    }
+
     /// The placeholder of the select menu.
     pub fn placeholder<D: ToString>(&mut self, label: D) -> &mut Self {
         self.0.insert("placeholder", Value::from(label.to_string()));
@@ -271,9 +328,30 @@ impl CreateSelectMenu {
         self
     }
 
+    /// Sets the values pre-selected in an auto-populated select menu.
+    ///
+    /// **Note**: The values must match the select menu's [`kind`], and there
+    /// can be at most [`max_values`] of them. Sending a message violating
+    /// either will return a [`ModelError::SelectMenuDefaultValueType`] or
+    /// [`ModelError::SelectMenuDefaultValueAmount`] respectively.
+    ///
+    /// [`kind`]: Self::kind
+    /// [`max_values`]: Self::max_values
+    /// [`ModelError::SelectMenuDefaultValueType`]: crate::model::error::Error::SelectMenuDefaultValueType
+    /// [`ModelError::SelectMenuDefaultValueAmount`]: crate::model::error::Error::SelectMenuDefaultValueAmount
+    pub fn default_values(&mut self, values: Vec<SelectMenuDefaultValue>) -> &mut Self {
+        let values = values
+            .into_iter()
+            .map(|value| json::to_value(value).expect("Failed to serialize default value"))
+            .collect::<Vec<_>>();
+
+        self.0.insert("default_values", Value::from(values));
+        self
+    }
+
     #[must_use]
     pub fn build(mut self) -> Value {
-        self.0.insert("type", from_number(3_u8));
+        self.0.entry("type").or_insert_with(|| from_number(ComponentType::SelectMenu as u8));
 
         json::hashmap_to_json_map(self.0.clone()).into()
     }
@@ -452,3 +530,46 @@ impl CreateInputText {
         json::hashmap_to_json_map(self.0.clone()).into()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CreateButton, CreateSelectMenu};
+    use crate::json::json;
+    use crate::model::application::component::ComponentType;
+    use crate::model::id::{RoleId, UserId};
+
+    #[test]
+    fn test_select_menu_default_values() {
+        let mut menu = CreateSelectMenu::default();
+        menu.custom_id("id")
+            .kind(ComponentType::MentionableSelect)
+            .default_values(vec![UserId(1).into(), RoleId(2).into()]);
+
+        let value = menu.build();
+        assert_eq!(value["type"], json!(7));
+        assert_eq!(
+            value["default_values"],
+            json!([{ "type": "user", "id": "1" }, { "type": "role", "id": "2" }])
+        );
+
+        let value = CreateSelectMenu::default().build();
+        assert_eq!(value["type"], json!(3));
+    }
+
+    #[test]
+    fn test_link_button_has_no_custom_id() {
+        let mut button = CreateButton::new("id");
+        button.label("Docs").url("https://docs.rs/serenity");
+
+        let value = button.build();
+        assert_eq!(value["style"], json!(5));
+        assert!(value.get("custom_id").is_none());
+
+        let mut button = CreateButton::new_link("https://docs.rs/serenity");
+        button.custom_id("id");
+
+        let value = button.build();
+        assert_eq!(value["style"], json!(1));
+        assert!(value.get("url").is_none());
+    }
+}
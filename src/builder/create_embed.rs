@@ -16,7 +16,8 @@
 
 use std::collections::HashMap;
 
-use crate::json::{self, from_number, json, Value};
+use crate::internal::prelude::*;
+use crate::json::{self, from_number, json};
 use crate::model::channel::Embed;
 use crate::model::Timestamp;
 #[cfg(feature = "utils")]
@@ -298,6 +299,54 @@ impl CreateEmbed {
 
         self
     }
+
+    /// Returns the number of characters counted towards the embed length
+    /// limit of [`EMBED_MAX_LENGTH`].
+    ///
+    /// Discord counts the unicode code points of the title, the description,
+    /// the name and value of each field, the footer text and the author name.
+    ///
+    /// [`EMBED_MAX_LENGTH`]: crate::constants::EMBED_MAX_LENGTH
+    #[must_use]
+    pub fn len(&self) -> usize {
+        embed_length(|key| self.0.get(key))
+    }
+
+    /// Whether none of the fields counted by [`Self::len`] hold any
+    /// characters.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Counts the characters of an embed's fields which are limited in total to
+/// [`EMBED_MAX_LENGTH`], using `get` to look up the embed's fields.
+///
+/// [`EMBED_MAX_LENGTH`]: crate::constants::EMBED_MAX_LENGTH
+pub(crate) fn embed_length<'a>(get: impl Fn(&str) -> Option<&'a Value>) -> usize {
+    let length = |value: Option<&Value>| match value {
+        Some(Value::String(text)) => text.chars().count(),
+        _ => 0,
+    };
+
+    let mut total = length(get("title")) + length(get("description"));
+
+    if let Some(Value::Object(author)) = get("author") {
+        total += length(author.get("name"));
+    }
+
+    if let Some(Value::Array(fields)) = get("fields") {
+        for field in fields {
+            total += length(field.get("name")) + length(field.get("value"));
+        }
+    }
+
+    if let Some(Value::Object(footer)) = get("footer") {
+        total += length(footer.get("text"));
+    }
+
+    total
 }
 
 impl Default for CreateEmbed {
@@ -518,4 +567,20 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_len() {
+        let mut builder = CreateEmbed::default();
+        assert!(builder.is_empty());
+
+        builder.title("ab");
+        builder.description("äöü");
+        builder.field("name", "value", false);
+        builder.footer(|f| f.text("footer").icon_url("https://example.com/icon.png"));
+        builder.author(|a| a.name("author"));
+        builder.url("https://example.com");
+
+        assert_eq!(builder.len(), 2 + 3 + 4 + 5 + 6 + 6);
+        assert!(!builder.is_empty());
+    }
 }
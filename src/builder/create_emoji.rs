@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::model::channel::AttachmentType;
+use crate::model::id::RoleId;
+
+/// A builder to create an [`Emoji`] from an image file or URL, for use via a
+/// number of model methods.
+///
+/// These are:
+///
+/// - [`PartialGuild::create_emoji_with`]
+/// - [`Guild::create_emoji_with`]
+/// - [`GuildId::create_emoji_with`]
+///
+/// Unlike [`GuildId::create_emoji`], the image does not need to be encoded
+/// beforehand: it is read, and encoded with its detected MIME type, when the
+/// emoji is created.
+///
+/// [`Emoji`]: crate::model::guild::Emoji
+/// [`PartialGuild::create_emoji_with`]: crate::model::guild::PartialGuild::create_emoji_with
+/// [`Guild::create_emoji_with`]: crate::model::guild::Guild::create_emoji_with
+/// [`GuildId::create_emoji_with`]: crate::model::id::GuildId::create_emoji_with
+/// [`GuildId::create_emoji`]: crate::model::id::GuildId::create_emoji
+#[derive(Clone, Debug, Default)]
+pub struct CreateEmoji<'a>(pub HashMap<&'static str, Value>, pub Option<AttachmentType<'a>>);
+
+impl<'a> CreateEmoji<'a> {
+    /// The name of the emoji to set.
+    ///
+    /// **Note**: Must be between 2 and 32 characters long, and can only
+    /// contain alphanumeric characters and underscores.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// The image of the emoji.
+    ///
+    /// **Note**: Must be a PNG, JPEG, GIF or WebP image, max 256 KiB.
+    pub fn image<T: Into<AttachmentType<'a>>>(&mut self, image: T) -> &mut Self {
+        self.1 = Some(image.into());
+        self
+    }
+
+    /// The roles allowed to use the emoji. If none are set, everyone can use
+    /// it.
+    pub fn roles<T: Into<RoleId>, It: IntoIterator<Item = T>>(&mut self, roles: It) -> &mut Self {
+        let roles = roles.into_iter().map(|role| Value::from(role.into().to_string())).collect();
+
+        self.0.insert("roles", Value::Array(roles));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateEmoji;
+    use crate::json::{json, Value};
+    use crate::model::id::RoleId;
+
+    #[test]
+    fn test_create_emoji() {
+        let mut builder = CreateEmoji::default();
+        builder.name("ferris").image(("data".as_bytes(), "ferris.png")).roles(vec![RoleId(1)]);
+
+        assert_eq!(
+            Value::from(crate::json::hashmap_to_json_map(builder.0)),
+            json!({
+                "name": "ferris",
+                "roles": ["1"],
+            })
+        );
+        assert!(builder.1.is_some());
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use super::{CreateChannel, EditRole};
+use crate::internal::prelude::*;
+use crate::json::{self, from_number, NULL};
+use crate::model::prelude::*;
+
+/// A builder to create a new [`Guild`], to be used in conjunction with
+/// [`Guild::create_with`].
+///
+/// Roles and channels are referenced by placeholder ids, which are only used
+/// within the request, e.g. to set a channel's permission overwrites for a
+/// role or to set the AFK channel. The @everyone role always has the
+/// placeholder id `0`.
+///
+/// **Note**: Only bots in less than 10 guilds can create guilds.
+///
+/// [`Guild`]: crate::model::guild::Guild
+/// [`Guild::create_with`]: crate::model::guild::Guild::create_with
+#[derive(Clone, Debug, Default)]
+pub struct CreateGuild(pub HashMap<&'static str, Value>);
+
+impl CreateGuild {
+    /// Set the name of the guild.
+    ///
+    /// **Note**: Must be between (and including) 2-100 characters.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// Set the icon of the guild.
+    ///
+    /// A base64-encoded string is accepted as the icon content. The
+    /// [`utils::read_image`] function can be used to read an image from a file.
+    ///
+    /// [`utils::read_image`]: crate::utils::read_image
+    pub fn icon(&mut self, icon: Option<&str>) -> &mut Self {
+        self.0.insert("icon", icon.map_or_else(|| NULL, |x| Value::from(x.to_string())));
+        self
+    }
+
+    /// Set the verification level of the guild.
+    pub fn verification_level(&mut self, verification_level: VerificationLevel) -> &mut Self {
+        self.0.insert("verification_level", from_number(verification_level.num()));
+        self
+    }
+
+    /// Set the default message notification level.
+    pub fn default_message_notifications(
+        &mut self,
+        level: DefaultMessageNotificationLevel,
+    ) -> &mut Self {
+        self.0.insert("default_message_notifications", from_number(level as u8));
+        self
+    }
+
+    /// Set the explicit content filter level.
+    pub fn explicit_content_filter(&mut self, level: ExplicitContentFilter) -> &mut Self {
+        self.0.insert("explicit_content_filter", from_number(level as u8));
+        self
+    }
+
+    /// Set the AFK channel by the placeholder id of one of the guild's
+    /// [channels].
+    ///
+    /// [channels]: Self::add_channel
+    pub fn afk_channel(&mut self, placeholder_id: u64) -> &mut Self {
+        self.0.insert("afk_channel_id", from_number(placeholder_id));
+        self
+    }
+
+    /// Set the amount of time in seconds a user is to be moved to the AFK
+    /// channel after being AFK.
+    pub fn afk_timeout(&mut self, timeout: u64) -> &mut Self {
+        self.0.insert("afk_timeout", from_number(timeout));
+        self
+    }
+
+    /// Set the system channel by the placeholder id of one of the guild's
+    /// [channels].
+    ///
+    /// [channels]: Self::add_channel
+    pub fn system_channel(&mut self, placeholder_id: u64) -> &mut Self {
+        self.0.insert("system_channel_id", from_number(placeholder_id));
+        self
+    }
+
+    /// Configures the @everyone role of the guild, e.g. its permissions.
+    ///
+    /// The @everyone role is always the first role of the guild, and has the
+    /// placeholder id `0`.
+    pub fn everyone_role<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut EditRole) -> &mut EditRole,
+    {
+        let role = Self::role(0, f);
+        let roles = self.roles();
+
+        if roles.first().and_then(|role| role.get("id")).and_then(Value::as_u64) == Some(0) {
+            roles[0] = role;
+        } else {
+            roles.insert(0, role);
+        }
+
+        self
+    }
+
+    /// Adds a role to the guild, which can be referenced by the given
+    /// placeholder id.
+    ///
+    /// **Note**: The first role of the guild is the @everyone role. Use
+    /// [`Self::everyone_role`] to configure it before adding other roles.
+    pub fn add_role<F>(&mut self, placeholder_id: u64, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut EditRole) -> &mut EditRole,
+    {
+        let role = Self::role(placeholder_id, f);
+        self.roles().push(role);
+        self
+    }
+
+    /// Adds a channel to the guild, which can be referenced by the given
+    /// placeholder id, e.g. as the [parent category] of another channel.
+    ///
+    /// [parent category]: CreateChannel::category
+    pub fn add_channel<F>(&mut self, placeholder_id: u64, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateChannel) -> &mut CreateChannel,
+    {
+        let mut channel = CreateChannel(HashMap::new());
+        f(&mut channel);
+        channel.0.insert("id", from_number(placeholder_id));
+
+        let channels = self.0.entry("channels").or_insert_with(|| Value::from(Vec::<Value>::new()));
+
+        if let Some(channels) = channels.as_array_mut() {
+            channels.push(Value::from(json::hashmap_to_json_map(channel.0)));
+        }
+
+        self
+    }
+
+    fn role<F>(placeholder_id: u64, f: F) -> Value
+    where
+        F: FnOnce(&mut EditRole) -> &mut EditRole,
+    {
+        let mut role = EditRole::default();
+        f(&mut role);
+        role.0.insert("id", from_number(placeholder_id));
+
+        Value::from(json::hashmap_to_json_map(role.0))
+    }
+
+    fn roles(&mut self) -> &mut Vec<Value> {
+        let roles = self.0.entry("roles").or_insert_with(|| Value::from(Vec::<Value>::new()));
+
+        roles.as_array_mut().expect("Guild roles were not an array")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreateGuild;
+    use crate::json::json;
+
+    #[test]
+    fn test_everyone_role_is_first() {
+        let mut guild = CreateGuild::default();
+        guild.add_role(1, |r| r.name("moderator")).everyone_role(|r| r.mentionable(false));
+
+        assert_eq!(
+            guild.0["roles"],
+            json!([{ "id": 0, "mentionable": false }, { "id": 1, "name": "moderator" }])
+        );
+
+        guild.everyone_role(|r| r.hoist(false));
+
+        assert_eq!(guild.0["roles"][0], json!({ "id": 0, "hoist": false }));
+        assert_eq!(guild.0["roles"].as_array().map(Vec::len), Some(2));
+    }
+}
@@ -37,6 +37,37 @@ impl<'a> CreateInteractionResponse<'a> {
         self.1 = data.1;
         self
     }
+
+    /// Responds with the given modal, setting the response type to
+    /// [`InteractionResponseType::Modal`].
+    ///
+    /// **Note**: Modals can be shown in response to application command and
+    /// message component interactions, but not to modal submits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::builder::{CreateInteractionResponse, CreateModal};
+    /// use serenity::model::application::component::InputTextStyle;
+    ///
+    /// let mut modal = CreateModal::default();
+    /// modal.custom_id("feedback").title("Feedback").components(|c| {
+    ///     c.create_action_row(|r| {
+    ///         r.create_input_text(|t| {
+    ///             t.custom_id("text").label("Your feedback").style(InputTextStyle::Paragraph)
+    ///         })
+    ///     })
+    /// });
+    ///
+    /// let mut response = CreateInteractionResponse::default();
+    /// response.modal(modal);
+    /// ```
+    pub fn modal(&mut self, modal: CreateModal) -> &mut Self {
+        self.0.insert("type", from_number(InteractionResponseType::Modal as u8));
+        self.0.insert("data", Value::from(json::hashmap_to_json_map(modal.0)));
+        self.1.clear();
+        self
+    }
 }
 
 impl<'a> Default for CreateInteractionResponse<'a> {
@@ -233,6 +264,74 @@ impl<'a> CreateInteractionResponseData<'a> {
     }
 }
 
+/// A builder for a modal, to be shown via [`CreateInteractionResponse::modal`].
+///
+/// [`Self::custom_id`], [`Self::title`], and [`Self::components`] are required
+/// fields.
+#[derive(Clone, Debug, Default)]
+pub struct CreateModal(pub HashMap<&'static str, Value>);
+
+impl CreateModal {
+    /// Sets the custom id of the modal, which is sent back when the modal is
+    /// submitted.
+    ///
+    /// **Note**: Must be up to 100 characters.
+    pub fn custom_id<D: ToString>(&mut self, id: D) -> &mut Self {
+        self.0.insert("custom_id", Value::from(id.to_string()));
+        self
+    }
+
+    /// Sets the title of the modal.
+    ///
+    /// **Note**: Must be up to 45 characters.
+    pub fn title<D: ToString>(&mut self, title: D) -> &mut Self {
+        self.0.insert("title", Value::from(title.to_string()));
+        self
+    }
+
+    /// Creates the components of the modal.
+    ///
+    /// **Note**: A modal must have between 1 and 5 action rows, each
+    /// containing a single [input text].
+    ///
+    /// [input text]: super::CreateActionRow::create_input_text
+    pub fn components<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        let mut components = CreateComponents::default();
+        f(&mut components);
+
+        self.set_components(components)
+    }
+
+    /// Sets the components of the modal.
+    pub fn set_components(&mut self, components: CreateComponents) -> &mut Self {
+        self.0.insert("components", Value::from(components.0));
+        self
+    }
+}
+
+/// A builder for the choices of an autocomplete response, to be used in
+/// conjunction with [`AutocompleteInteraction::create_autocomplete_response`].
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// # use serenity::model::application::interaction::autocomplete::AutocompleteInteraction;
+/// # async fn run(http: Http, interaction: AutocompleteInteraction) -> serenity::Result<()> {
+/// interaction
+///     .create_autocomplete_response(&http, |r| {
+///         r.add_string_choice("Red", "red")
+///             .add_string_choice_localized("Blue", "blue", [("de", "Blau"), ("fr", "Bleu")])
+///     })
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`AutocompleteInteraction::create_autocomplete_response`]: crate::model::application::interaction::autocomplete::AutocompleteInteraction::create_autocomplete_response
 #[derive(Clone, Debug)]
 pub struct CreateAutocompleteResponse(pub HashMap<&'static str, Value>);
 
@@ -266,6 +365,24 @@ impl CreateAutocompleteResponse {
         self.add_choice(choice)
     }
 
+    /// Adds a localized int autocomplete choice. See [`Self::add_int_choice`] for more info.
+    pub fn add_int_choice_localized<L: ToString, D: ToString>(
+        &mut self,
+        name: D,
+        value: i64,
+        locales: impl IntoIterator<Item = (L, D)>,
+    ) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "name_localizations": locales
+                .into_iter()
+                .map(|(locale, name)| (locale.to_string(), name.to_string()))
+                .collect::<Value>(),
+            "value" : value,
+        });
+        self.add_choice(choice)
+    }
+
     /// Adds a string autocomplete choice.
     ///
     /// **Note**: There can be no more than 25 choices set. Name must be between 1 and 100 characters. Value must be up to 100 characters.
@@ -277,6 +394,24 @@ impl CreateAutocompleteResponse {
         self.add_choice(choice)
     }
 
+    /// Adds a localized string autocomplete choice. See [`Self::add_string_choice`] for more info.
+    pub fn add_string_choice_localized<L: ToString, D: ToString, E: ToString>(
+        &mut self,
+        name: D,
+        value: E,
+        locales: impl IntoIterator<Item = (L, D)>,
+    ) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "name_localizations": locales
+                .into_iter()
+                .map(|(locale, name)| (locale.to_string(), name.to_string()))
+                .collect::<Value>(),
+            "value": value.to_string(),
+        });
+        self.add_choice(choice)
+    }
+
     /// Adds a number autocomplete choice.
     ///
     /// **Note**: There can be no more than 25 choices set. Name must be between 1 and 100 characters. Value must be between -2^53 and 2^53.
@@ -288,6 +423,24 @@ impl CreateAutocompleteResponse {
         self.add_choice(choice)
     }
 
+    /// Adds a localized number autocomplete choice. See [`Self::add_number_choice`] for more info.
+    pub fn add_number_choice_localized<L: ToString, D: ToString>(
+        &mut self,
+        name: D,
+        value: f64,
+        locales: impl IntoIterator<Item = (L, D)>,
+    ) -> &mut Self {
+        let choice = json!({
+            "name": name.to_string(),
+            "name_localizations": locales
+                .into_iter()
+                .map(|(locale, name)| (locale.to_string(), name.to_string()))
+                .collect::<Value>(),
+            "value" : value,
+        });
+        self.add_choice(choice)
+    }
+
     fn add_choice(&mut self, value: Value) -> &mut Self {
         let choices = self.0.entry("choices").or_insert_with(|| Value::Array(vec![]));
         let choices_arr = choices.as_array_mut().expect("Must be an array");
@@ -296,3 +449,35 @@ impl CreateAutocompleteResponse {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CreateAutocompleteResponse, CreateInteractionResponse, CreateModal};
+    use crate::json::json;
+
+    #[test]
+    fn test_modal_response() {
+        let mut modal = CreateModal::default();
+        modal.custom_id("feedback").title("Feedback");
+
+        let mut response = CreateInteractionResponse::default();
+        response.modal(modal);
+
+        assert_eq!(response.0["type"], json!(9));
+        assert_eq!(response.0["data"], json!({ "custom_id": "feedback", "title": "Feedback" }));
+    }
+
+    #[test]
+    fn test_autocomplete_localized_choice() {
+        let mut response = CreateAutocompleteResponse::default();
+        response.add_int_choice("One", 1).add_string_choice_localized("Two", "2", [("de", "Zwei")]);
+
+        assert_eq!(
+            response.0["choices"],
+            json!([
+                { "name": "One", "value": 1 },
+                { "name": "Two", "name_localizations": { "de": "Zwei" }, "value": "2" },
+            ])
+        );
+    }
+}
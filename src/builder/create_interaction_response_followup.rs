@@ -167,12 +167,21 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
     }
 
     /// Sets the flags for the response.
+    ///
+    /// To only toggle whether the followup is ephemeral, use
+    /// [`Self::ephemeral`] instead.
     pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
         self.0.insert("flags", from_number(flags.bits()));
         self
     }
 
-    /// Adds or removes the ephemeral flag
+    /// Adds or removes the ephemeral flag, which makes the followup only
+    /// visible to the user who invoked the interaction. Other flags are kept.
+    ///
+    /// **Note**: Like other followups, ephemeral followups can only be edited
+    /// or deleted using the interaction token, which expires 15 minutes after
+    /// the interaction was received. As ephemeral messages cannot be fetched
+    /// or edited through their channel, they can't be changed afterwards.
     pub fn ephemeral(&mut self, ephemeral: bool) -> &mut Self {
         let flags = self
             .0
@@ -191,6 +200,13 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
     }
 
     /// Creates components for this message.
+    ///
+    /// **Note**: A message can have up to 5 action rows, each holding up to 5
+    /// buttons. Sending a followup over either limit returns a
+    /// [`ModelError::ActionRowAmount`] or [`ModelError::ButtonAmount`].
+    ///
+    /// [`ModelError::ActionRowAmount`]: crate::model::error::Error::ActionRowAmount
+    /// [`ModelError::ButtonAmount`]: crate::model::error::Error::ButtonAmount
     pub fn components<F>(&mut self, f: F) -> &mut Self
     where
         F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
@@ -208,3 +224,24 @@ impl<'a> CreateInteractionResponseFollowup<'a> {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::CreateInteractionResponseFollowup;
+    use crate::json::from_number;
+    use crate::model::application::interaction::MessageFlags;
+
+    #[test]
+    fn test_ephemeral() {
+        let mut followup = CreateInteractionResponseFollowup::default();
+        followup.flags(MessageFlags::SUPPRESS_EMBEDS).ephemeral(true);
+
+        let flags = (MessageFlags::SUPPRESS_EMBEDS | MessageFlags::EPHEMERAL).bits();
+        assert_eq!(followup.0.get("flags"), Some(&from_number(flags)));
+
+        followup.ephemeral(false);
+
+        let flags = MessageFlags::SUPPRESS_EMBEDS.bits();
+        assert_eq!(followup.0.get("flags"), Some(&from_number(flags)));
+    }
+}
@@ -8,7 +8,7 @@ use crate::internal::prelude::*;
 use crate::json::{self, from_number, to_value};
 #[cfg(feature = "model")]
 use crate::model::channel::AttachmentType;
-use crate::model::channel::{MessageFlags, MessageReference, ReactionType};
+use crate::model::channel::{MessageFlags, MessageReference, MessageReferenceKind, ReactionType};
 use crate::model::id::StickerId;
 
 /// A builder to specify the contents of an [`Http::send_message`] request,
@@ -71,6 +71,20 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Returns the length of the message content in unicode code points, as
+    /// counted towards the limit of [`MESSAGE_CODE_LIMIT`].
+    ///
+    /// Refer to [`CreateEmbed::len`] for the length of the embeds.
+    ///
+    /// [`MESSAGE_CODE_LIMIT`]: crate::constants::MESSAGE_CODE_LIMIT
+    #[must_use]
+    pub fn content_len(&self) -> usize {
+        match self.0.get("content") {
+            Some(Value::String(content)) => content.chars().count(),
+            _ => 0,
+        }
+    }
+
     fn _add_embed(&mut self, embed: CreateEmbed) -> &mut Self {
         let map = json::hashmap_to_json_map(embed.0);
         let embed = Value::from(map);
@@ -98,8 +112,15 @@ impl<'a> CreateMessage<'a> {
 
     /// Add multiple embeds for the message.
     ///
+    /// A message can have up to 10 embeds, whose titles, descriptions, field names and values,
+    /// footer texts and author names may not exceed 6000 characters combined. Sending a message
+    /// over either limit returns a [`ModelError::EmbedAmount`] or [`ModelError::EmbedTooLarge`].
+    ///
     /// **Note**: This will keep all existing embeds. Use [`Self::set_embeds()`] to replace existing
     /// embeds.
+    ///
+    /// [`ModelError::EmbedAmount`]: crate::model::error::Error::EmbedAmount
+    /// [`ModelError::EmbedTooLarge`]: crate::model::error::Error::EmbedTooLarge
     pub fn add_embeds(&mut self, embeds: Vec<CreateEmbed>) -> &mut Self {
         for embed in embeds {
             self._add_embed(embed);
@@ -217,12 +238,52 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Set the reference message this message is a reply to.
+    ///
+    /// By default, sending the message fails if the referenced message was
+    /// deleted. See [`MessageReference::fail_if_not_exists`] to send it without
+    /// the reply instead.
+    ///
+    /// Replies must be sent in the channel of the referenced message. A
+    /// reference to a message of another channel is sent as a forward instead,
+    /// as if set via [`Self::forward_message`]. As forwards cannot have content
+    /// of their own, sending such a message with content, embeds, files,
+    /// stickers or components fails.
+    ///
+    /// # Examples
+    ///
+    /// Reference a message by its channel and message Ids:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// # async fn run(http: Http) -> serenity::Result<()> {
+    /// let (channel_id, message_id) = (ChannelId(1), MessageId(2));
+    ///
+    /// // A reply, as the referenced message is in the same channel.
+    /// channel_id
+    ///     .send_message(&http, |m| m.content("Reply").reference_message((channel_id, message_id)))
+    ///     .await?;
+    ///
+    /// // A forward, as the referenced message is in another channel.
+    /// ChannelId(3).send_message(&http, |m| m.reference_message((channel_id, message_id))).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     #[allow(clippy::unwrap_used)] // allowing unwrap here because serializing MessageReference should never error
     pub fn reference_message(&mut self, reference: impl Into<MessageReference>) -> &mut Self {
         self.0.insert("message_reference", to_value(reference.into()).unwrap());
         self
     }
 
+    /// Set the reference message this message forwards, which may be in
+    /// another channel.
+    ///
+    /// A forward shows the content of the referenced message and cannot have
+    /// content of its own, so no other fields should be set.
+    pub fn forward_message(&mut self, reference: impl Into<MessageReference>) -> &mut Self {
+        self.reference_message(reference.into().kind(MessageReferenceKind::Forward))
+    }
+
     /// Creates components for this message.
     pub fn components<F>(&mut self, f: F) -> &mut Self
     where
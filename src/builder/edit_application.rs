@@ -0,0 +1,85 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{from_number, NULL};
+use crate::model::application::ApplicationFlags;
+
+/// A builder to edit the current application's information, to be used in
+/// conjunction with [`CurrentApplicationInfo::edit`].
+///
+/// [`CurrentApplicationInfo::edit`]: crate::model::application::CurrentApplicationInfo::edit
+#[derive(Clone, Debug, Default)]
+pub struct EditApplication(pub HashMap<&'static str, Value>);
+
+impl EditApplication {
+    /// Sets the description of the application.
+    ///
+    /// **Note**: Must be at most 400 characters long.
+    pub fn description<S: ToString>(&mut self, description: S) -> &mut Self {
+        self.0.insert("description", Value::from(description.to_string()));
+        self
+    }
+
+    /// Sets the icon of the application. [`None`] can be passed to remove the
+    /// icon.
+    ///
+    /// A base64-encoded string is accepted as the icon content. The
+    /// [`utils::read_image`] function can be used to read an image from a file.
+    ///
+    /// [`utils::read_image`]: crate::utils::read_image
+    pub fn icon(&mut self, icon: Option<&str>) -> &mut Self {
+        let icon = icon.map_or(NULL, |x| Value::from(x.to_string()));
+
+        self.0.insert("icon", icon);
+        self
+    }
+
+    /// Sets the cover image of the application's store embed. [`None`] can be
+    /// passed to remove the image.
+    ///
+    /// A base64-encoded string is accepted as the image content.
+    pub fn cover_image(&mut self, cover_image: Option<&str>) -> &mut Self {
+        let cover_image = cover_image.map_or(NULL, |x| Value::from(x.to_string()));
+
+        self.0.insert("cover_image", cover_image);
+        self
+    }
+
+    /// Sets the tags describing the content and functionality of the
+    /// application.
+    ///
+    /// **Note**: An application can have up to 5 tags.
+    pub fn tags<It, S>(&mut self, tags: It) -> &mut Self
+    where
+        It: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let tags = tags.into_iter().map(|tag| Value::from(tag.to_string())).collect::<Vec<_>>();
+
+        self.0.insert("tags", Value::from(tags));
+        self
+    }
+
+    /// Sets the URL Discord sends interactions to, instead of the gateway.
+    /// [`None`] can be passed to receive interactions over the gateway again.
+    pub fn interactions_endpoint_url(&mut self, url: Option<&str>) -> &mut Self {
+        let url = url.map_or(NULL, |x| Value::from(x.to_string()));
+
+        self.0.insert("interactions_endpoint_url", url);
+        self
+    }
+
+    /// Sets the default custom authorization URL of the application.
+    pub fn custom_install_url<S: ToString>(&mut self, url: S) -> &mut Self {
+        self.0.insert("custom_install_url", Value::from(url.to_string()));
+        self
+    }
+
+    /// Sets the flags of the application.
+    ///
+    /// **Note**: Only the limited gateway intent flags can be changed.
+    pub fn flags(&mut self, flags: ApplicationFlags) -> &mut Self {
+        self.0.insert("flags", from_number(flags.bits()));
+        self
+    }
+}
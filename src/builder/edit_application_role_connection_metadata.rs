@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{self, from_number};
+use crate::model::application::role_connection::ApplicationRoleConnectionMetadataType;
+
+/// A builder for a role connection metadata record.
+///
+/// [`Self::kind`], [`Self::key`], [`Self::name`], and [`Self::description`]
+/// are required fields.
+#[derive(Clone, Debug, Default)]
+pub struct CreateApplicationRoleConnectionMetadataRecord(pub HashMap<&'static str, Value>);
+
+impl CreateApplicationRoleConnectionMetadataRecord {
+    /// Sets the type of the metadata value.
+    pub fn kind(&mut self, kind: ApplicationRoleConnectionMetadataType) -> &mut Self {
+        self.0.insert("type", from_number(kind as u8));
+        self
+    }
+
+    /// Sets the key of the metadata value in the user's role connection.
+    ///
+    /// **Note**: Must be between 1 and 50 characters, consisting only of
+    /// lowercase letters, digits and underscores.
+    pub fn key<D: ToString>(&mut self, key: D) -> &mut Self {
+        self.0.insert("key", Value::from(key.to_string()));
+        self
+    }
+
+    /// Sets the name of the record.
+    ///
+    /// **Note**: Must be between 1 and 100 characters.
+    pub fn name<D: ToString>(&mut self, name: D) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// Specifies a localized name of the record.
+    #[allow(clippy::default_trait_access)]
+    pub fn name_localized<D: ToString, E: ToString>(&mut self, locale: E, name: D) -> &mut Self {
+        self.0
+            .entry("name_localizations")
+            .or_insert_with(|| Value::Object(Default::default()))
+            .as_object_mut()
+            .expect("must be object")
+            .insert(locale.to_string(), Value::String(name.to_string()));
+        self
+    }
+
+    /// Sets the description of the record.
+    ///
+    /// **Note**: Must be between 1 and 200 characters.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::from(description.to_string()));
+        self
+    }
+
+    /// Specifies a localized description of the record.
+    #[allow(clippy::default_trait_access)]
+    pub fn description_localized<D: ToString, E: ToString>(
+        &mut self,
+        locale: E,
+        description: D,
+    ) -> &mut Self {
+        self.0
+            .entry("description_localizations")
+            .or_insert_with(|| Value::Object(Default::default()))
+            .as_object_mut()
+            .expect("must be object")
+            .insert(locale.to_string(), Value::String(description.to_string()));
+        self
+    }
+}
+
+/// A builder for overriding the role connection metadata records of the
+/// current application, to be used in conjunction with
+/// [`ApplicationRoleConnectionMetadata::set_records`].
+///
+/// **Note**: An application can have at most 5 records.
+///
+/// [`ApplicationRoleConnectionMetadata::set_records`]: crate::model::application::role_connection::ApplicationRoleConnectionMetadata::set_records
+#[derive(Clone, Debug, Default)]
+pub struct EditApplicationRoleConnectionMetadata(pub Vec<Value>);
+
+impl EditApplicationRoleConnectionMetadata {
+    /// Creates a new record.
+    pub fn create_record<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(
+            &mut CreateApplicationRoleConnectionMetadataRecord,
+        ) -> &mut CreateApplicationRoleConnectionMetadataRecord,
+    {
+        let mut data = CreateApplicationRoleConnectionMetadataRecord::default();
+        f(&mut data);
+
+        self.add_record(data)
+    }
+
+    /// Adds a new record.
+    pub fn add_record(
+        &mut self,
+        record: CreateApplicationRoleConnectionMetadataRecord,
+    ) -> &mut Self {
+        self.0.push(Value::from(json::hashmap_to_json_map(record.0)));
+        self
+    }
+
+    /// Sets all the records, replacing any that were added before.
+    pub fn set_records(
+        &mut self,
+        records: Vec<CreateApplicationRoleConnectionMetadataRecord>,
+    ) -> &mut Self {
+        self.0 = records.into_iter().map(|r| Value::from(json::hashmap_to_json_map(r.0))).collect();
+        self
+    }
+}
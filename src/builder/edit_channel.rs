@@ -1,7 +1,12 @@
 use std::collections::HashMap;
 
 use crate::json::{from_number, json, Value, NULL};
-use crate::model::channel::{PermissionOverwrite, PermissionOverwriteType, VideoQualityMode};
+use crate::model::channel::{
+    ChannelFlags,
+    PermissionOverwrite,
+    PermissionOverwriteType,
+    VideoQualityMode,
+};
 use crate::model::id::ChannelId;
 
 /// A builder to edit a [`GuildChannel`] for use via [`GuildChannel::edit`]
@@ -94,9 +99,12 @@ impl EditChannel {
 
     /// Is the channel inappropriate for work?
     ///
-    /// This is for [text] channels only.
+    /// This is for [text], [voice], [stage] and [forum] channels only.
     ///
     /// [text]: crate::model::channel::ChannelType::Text
+    /// [voice]: crate::model::channel::ChannelType::Voice
+    /// [stage]: crate::model::channel::ChannelType::Stage
+    /// [forum]: crate::model::channel::ChannelType::Forum
     pub fn nsfw(&mut self, nsfw: bool) -> &mut Self {
         self.0.insert("nsfw", Value::from(nsfw));
 
@@ -148,6 +156,31 @@ impl EditChannel {
         self
     }
 
+    /// The slowmode, in seconds, to set on threads created in the channel.
+    ///
+    /// Unlike [`Self::rate_limit_per_user`], this does not apply to the
+    /// channel itself, nor to existing threads: it is copied to each new
+    /// thread when it is created.
+    ///
+    /// **Note**: Must be between 0 and 21600 seconds (360 minutes or 6 hours).
+    /// Only available for text, news and forum channels.
+    #[doc(alias = "slowmode")]
+    pub fn default_thread_rate_limit_per_user(&mut self, seconds: u64) -> &mut Self {
+        self.0.insert("default_thread_rate_limit_per_user", from_number(seconds));
+
+        self
+    }
+
+    /// The flags of the channel, replacing the current flags.
+    ///
+    /// Set [`ChannelFlags::REQUIRE_TAG`] on a forum channel to require a tag
+    /// on new threads.
+    pub fn flags(&mut self, flags: ChannelFlags) -> &mut Self {
+        self.0.insert("flags", from_number(flags.bits()));
+
+        self
+    }
+
     /// A set of overwrites defining what a user or a user carrying a certain role can
     /// and cannot do.
     ///
@@ -0,0 +1,108 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{self, from_number, NULL};
+use crate::model::id::ChannelId;
+
+/// A builder to specify the new positions of several channels of a guild,
+/// which are all edited at once.
+///
+/// Refer to [`GuildId::edit_channel_positions`] for more information.
+///
+/// [`GuildId::edit_channel_positions`]: crate::model::id::GuildId::edit_channel_positions
+#[derive(Clone, Debug, Default)]
+pub struct EditChannelPositions(pub Vec<Value>);
+
+impl EditChannelPositions {
+    /// Sets the position of a channel.
+    pub fn position(&mut self, channel_id: impl Into<ChannelId>, position: u64) -> &mut Self {
+        self.channel(channel_id, |c| c.position(position))
+    }
+
+    /// Edits the position of a channel, optionally moving it to another
+    /// category.
+    pub fn channel<F>(&mut self, channel_id: impl Into<ChannelId>, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut EditChannelPosition) -> &mut EditChannelPosition,
+    {
+        let mut data = EditChannelPosition::default();
+        f(&mut data);
+
+        let mut map = json::hashmap_to_json_map(data.0);
+        map.insert("id".to_string(), Value::from(channel_id.into().to_string()));
+
+        self.0.push(Value::from(map));
+
+        self
+    }
+
+    /// Returns the IDs of the channels to edit, and of the categories they are
+    /// moved to.
+    #[cfg(feature = "model")]
+    pub(crate) fn channel_ids(&self) -> impl Iterator<Item = ChannelId> + '_ {
+        self.0
+            .iter()
+            .flat_map(|channel| {
+                ["id", "parent_id"].iter().filter_map(move |key| {
+                    channel.get(*key).and_then(Value::as_str).and_then(|id| id.parse().ok())
+                })
+            })
+            .map(ChannelId)
+    }
+}
+
+/// A builder to specify the new position of a single channel, used through
+/// [`EditChannelPositions::channel`].
+#[derive(Clone, Debug, Default)]
+pub struct EditChannelPosition(pub HashMap<&'static str, Value>);
+
+impl EditChannelPosition {
+    /// The new position of the channel.
+    pub fn position(&mut self, position: u64) -> &mut Self {
+        self.0.insert("position", from_number(position));
+        self
+    }
+
+    /// Moves the channel into a category, or out of its category if [`None`]
+    /// is given.
+    pub fn parent_id(&mut self, parent_id: Option<ChannelId>) -> &mut Self {
+        self.0.insert("parent_id", parent_id.map_or(NULL, |id| Value::from(id.to_string())));
+        self
+    }
+
+    /// Whether to sync the permission overwrites of the channel with its new
+    /// category, when moving it with [`Self::parent_id`].
+    pub fn lock_permissions(&mut self, lock_permissions: bool) -> &mut Self {
+        self.0.insert("lock_permissions", Value::from(lock_permissions));
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditChannelPositions;
+    use crate::json::{json, Value};
+    use crate::model::id::ChannelId;
+
+    #[test]
+    fn test_edit_channel_positions() {
+        let mut builder = EditChannelPositions::default();
+        builder
+            .position(1, 0)
+            .channel(2, |c| c.position(1).parent_id(Some(ChannelId(3))).lock_permissions(true));
+
+        assert_eq!(
+            Value::from(builder.0.clone()),
+            json!([
+                {"id": "1", "position": 0},
+                {"id": "2", "position": 1, "parent_id": "3", "lock_permissions": true},
+            ])
+        );
+        #[cfg(feature = "model")]
+        assert_eq!(builder.channel_ids().collect::<Vec<_>>(), vec![
+            ChannelId(1),
+            ChannelId(2),
+            ChannelId(3)
+        ]);
+    }
+}
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use crate::internal::prelude::*;
+use crate::json::NULL;
+use crate::model::id::ChannelId;
 
 /// A builder to specify the fields to edit in a [`GuildWidget`].
 ///
@@ -16,10 +18,47 @@ impl EditGuildWidget {
         self
     }
 
-    /// The server description shown in the welcome screen.
+    /// The channel the widget will generate an invite to.
+    ///
+    /// Refer to [`Self::channel`] to pass a [`ChannelId`] instead.
     pub fn channel_id(&mut self, id: u64) -> &mut Self {
         self.0.insert("channel_id", Value::from(id.to_string()));
 
         self
     }
+
+    /// The channel the widget will generate an invite to.
+    pub fn channel(&mut self, channel_id: impl Into<ChannelId>) -> &mut Self {
+        self.channel_id(channel_id.into().0)
+    }
+
+    /// Removes the channel, so that the widget does not generate an invite.
+    pub fn remove_channel(&mut self) -> &mut Self {
+        self.0.insert("channel_id", NULL);
+
+        self
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditGuildWidget;
+    use crate::json::{json, NULL};
+    use crate::model::id::ChannelId;
+
+    #[test]
+    fn test_channel_id() {
+        let mut widget = EditGuildWidget::default();
+        widget.enabled(true).channel_id(1);
+
+        assert_eq!(widget.0.get("channel_id"), Some(&json!("1")));
+
+        widget.channel(ChannelId(2));
+
+        assert_eq!(widget.0.get("channel_id"), Some(&json!("2")));
+
+        widget.remove_channel();
+
+        assert_eq!(widget.0.get("channel_id"), Some(&NULL));
+    }
 }
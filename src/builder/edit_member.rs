@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use crate::internal::prelude::*;
 use crate::json::{from_number, NULL};
+use crate::model::guild::GuildMemberFlags;
 use crate::model::id::{ChannelId, RoleId};
 use crate::model::Timestamp;
 
@@ -125,4 +126,23 @@ impl EditMember {
         self.0.insert("communication_disabled_until", NULL);
         self
     }
+
+    /// Sets the member's flags.
+    ///
+    /// **Note**: Only [`GuildMemberFlags::BYPASSES_VERIFICATION`] can be
+    /// edited, other flags are ignored by Discord.
+    ///
+    /// Requires the [Manage Guild] or [Manage Roles] permission, as well as
+    /// the [Moderate Members] permission or the [Kick Members] and
+    /// [Ban Members] permissions.
+    ///
+    /// [Manage Guild]: crate::model::permissions::Permissions::MANAGE_GUILD
+    /// [Manage Roles]: crate::model::permissions::Permissions::MANAGE_ROLES
+    /// [Moderate Members]: crate::model::permissions::Permissions::MODERATE_MEMBERS
+    /// [Kick Members]: crate::model::permissions::Permissions::KICK_MEMBERS
+    /// [Ban Members]: crate::model::permissions::Permissions::BAN_MEMBERS
+    pub fn flags(&mut self, flags: GuildMemberFlags) -> &mut Self {
+        self.0.insert("flags", from_number(flags.bits()));
+        self
+    }
 }
@@ -53,6 +53,18 @@ impl EditProfile {
         self
     }
 
+    /// Sets the banner of the current user. [`None`] can be passed to remove a
+    /// banner.
+    ///
+    /// A base64-encoded string is accepted as the banner content, like for
+    /// [`Self::avatar`].
+    pub fn banner(&mut self, banner: Option<&str>) -> &mut Self {
+        let banner = banner.map_or(NULL, |x| Value::from(x.to_string()));
+
+        self.0.insert("banner", banner);
+        self
+    }
+
     /// Modifies the current user's email address.
     ///
     /// Note that when modifying the email address, the current password must
@@ -95,6 +107,13 @@ impl EditProfile {
     /// and current discriminator, a new unique discriminator will be assigned.
     /// If there are no available discriminators with the requested username,
     /// an error will occur.
+    ///
+    /// **Note**: The username can only be changed twice per hour. Changing it
+    /// more often makes [`CurrentUser::edit`] return a
+    /// [`ModelError::UsernameRateLimited`].
+    ///
+    /// [`CurrentUser::edit`]: crate::model::user::CurrentUser::edit
+    /// [`ModelError::UsernameRateLimited`]: crate::model::error::Error::UsernameRateLimited
     pub fn username<S: ToString>(&mut self, username: S) -> &mut Self {
         self.0.insert("username", Value::from(username.to_string()));
         self
@@ -71,14 +71,12 @@ impl EditRole {
         map.insert("permissions", from_number(role.permissions.bits()));
         map.insert("position", from_number(role.position));
 
+        // The role's icon is not copied over: it is only a hash, which Discord
+        // would reject as image data. Leaving it out keeps the current icon.
         if let Some(unicode_emoji) = &role.unicode_emoji {
             map.insert("unicode_emoji", Value::String(unicode_emoji.clone()));
         }
 
-        if let Some(icon) = &role.icon {
-            map.insert("icon", Value::String(icon.clone()));
-        }
-
         EditRole(map)
     }
 
@@ -121,6 +119,11 @@ impl EditRole {
     }
 
     /// The unicode emoji to set as the role image.
+    ///
+    /// Discord does not allow a role to have both a unicode emoji and an
+    /// [icon], so this removes any icon previously set on the builder.
+    ///
+    /// [icon]: Self::icon
     pub fn unicode_emoji<S: ToString>(&mut self, unicode_emoji: S) -> &mut Self {
         self.0.remove("icon");
         self.0.insert("unicode_emoji", Value::String(unicode_emoji.to_string()));
@@ -130,10 +133,44 @@ impl EditRole {
 
     /// The image to set as the role icon.
     ///
+    /// Discord does not allow a role to have both an icon and a
+    /// [unicode emoji], so this removes any unicode emoji previously set on
+    /// the builder.
+    ///
+    /// **Note**: The guild must have the `ROLE_ICONS` feature.
+    ///
+    /// # Examples
+    ///
+    /// Set the icon of a role from a file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{model::id::{GuildId, RoleId}, http::Http};
+    /// # use std::sync::Arc;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Arc::new(Http::new("token"));
+    /// # let (guild_id, role_id) = (GuildId(1), RoleId(2));
+    /// use serenity::builder::EditRole;
+    ///
+    /// let mut builder = EditRole::default();
+    /// builder.icon(&http, "./icon.png").await?;
+    ///
+    /// guild_id
+    ///     .edit_role(&http, role_id, |r| {
+    ///         *r = builder;
+    ///         r
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
     /// # Errors
     ///
     /// May error if the icon is a URL and the HTTP request fails, or if the icon is a file
     /// on a path that doesn't exist.
+    ///
+    /// [unicode emoji]: Self::unicode_emoji
     #[cfg(feature = "model")]
     pub async fn icon<'a>(
         &mut self,
@@ -148,3 +185,32 @@ impl EditRole {
         Ok(self)
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::EditRole;
+    use crate::http::Http;
+    use crate::json::Value;
+    use crate::model::channel::AttachmentType;
+
+    #[tokio::test]
+    async fn test_icon_and_unicode_emoji_are_exclusive() {
+        let http = Http::new("token");
+        let icon = || AttachmentType::Bytes {
+            data: vec![1, 2, 3].into(),
+            filename: "icon.png".to_string(),
+        };
+
+        let mut builder = EditRole::default();
+        builder.icon(&http, icon()).await.unwrap();
+        builder.unicode_emoji("\u{1f980}");
+
+        assert!(!builder.0.contains_key("icon"));
+        assert_eq!(builder.0.get("unicode_emoji"), Some(&Value::from("\u{1f980}")));
+
+        builder.icon(&http, icon()).await.unwrap();
+
+        assert!(!builder.0.contains_key("unicode_emoji"));
+        assert_eq!(builder.0.get("icon"), Some(&Value::from("data:image/png;base64,AQID")));
+    }
+}
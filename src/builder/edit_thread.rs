@@ -1,7 +1,10 @@
 use std::collections::HashMap;
 
 use crate::json::{from_number, Value};
+use crate::model::channel::ChannelFlags;
+use crate::model::id::ForumTagId;
 
+/// A builder to edit a thread channel.
 #[derive(Debug, Clone, Default)]
 pub struct EditThread(pub HashMap<&'static str, Value>);
 
@@ -40,6 +43,46 @@ impl EditThread {
         self
     }
 
+    /// The tags applied to a thread of a forum channel, replacing the current
+    /// tags.
+    ///
+    /// **Note**: At most 5 tags can be applied, which must be available in the
+    /// forum channel.
+    pub fn applied_tags(
+        &mut self,
+        tags: impl IntoIterator<Item = impl Into<ForumTagId>>,
+    ) -> &mut Self {
+        let tags = tags.into_iter().map(|tag| from_number(tag.into().0)).collect::<Vec<_>>();
+        self.0.insert("applied_tags", Value::from(tags));
+
+        self
+    }
+
+    /// The flags of the thread, replacing the current flags.
+    ///
+    /// Set [`ChannelFlags::PINNED`] to pin a thread in its forum channel.
+    pub fn flags(&mut self, flags: ChannelFlags) -> &mut Self {
+        self.0.insert("flags", from_number(flags.bits()));
+
+        self
+    }
+
+    /// How many seconds must a user wait before sending another message in
+    /// the thread, independently of the slowmode of its parent channel.
+    ///
+    /// Bots, or users with the [Manage Messages] or [Manage Threads]
+    /// permissions are unaffected.
+    ///
+    /// **Note**: Must be between 0 and 21600 seconds (360 minutes or 6 hours).
+    ///
+    /// [Manage Messages]: crate::model::permissions::Permissions::MANAGE_MESSAGES
+    /// [Manage Threads]: crate::model::permissions::Permissions::MANAGE_THREADS
+    pub fn rate_limit_per_user(&mut self, seconds: u64) -> &mut Self {
+        self.0.insert("rate_limit_per_user", from_number(seconds));
+
+        self
+    }
+
     /// Whether non-moderators can add other non-moderators to a thread.
     ///
     /// **Note**: Only available on private threads.
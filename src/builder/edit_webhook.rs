@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{from_number, NULL};
+use crate::model::id::ChannelId;
+
+/// A builder to specify the fields to edit in a [`Webhook`].
+///
+/// **Note**: Moving the webhook to another channel is only possible when
+/// editing it with bot authentication, see [`Webhook::edit`] and
+/// [`Webhook::edit_with_token`].
+///
+/// [`Webhook`]: crate::model::webhook::Webhook
+/// [`Webhook::edit`]: crate::model::webhook::Webhook::edit
+/// [`Webhook::edit_with_token`]: crate::model::webhook::Webhook::edit_with_token
+#[derive(Clone, Debug, Default)]
+pub struct EditWebhook(pub HashMap<&'static str, Value>);
+
+impl EditWebhook {
+    /// Set the default name of the webhook.
+    ///
+    /// **Note**: Must be between (and including) 2-100 characters.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// Set the default avatar of the webhook, or [`None`] to reset it to the
+    /// default logo.
+    ///
+    /// A base64-encoded string is accepted as the avatar content. The
+    /// [`utils::read_image`] function can be used to read an image from a file.
+    ///
+    /// [`utils::read_image`]: crate::utils::read_image
+    pub fn avatar(&mut self, avatar: Option<&str>) -> &mut Self {
+        self.0.insert("avatar", avatar.map_or(NULL, |x| Value::from(x.to_string())));
+        self
+    }
+
+    /// Move the webhook to another channel of the same guild.
+    ///
+    /// **Note**: Requires bot authentication, this can't be used when editing
+    /// the webhook with its token.
+    pub fn channel_id(&mut self, channel_id: impl Into<ChannelId>) -> &mut Self {
+        self.0.insert("channel_id", from_number(channel_id.into().0));
+        self
+    }
+}
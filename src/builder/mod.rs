@@ -15,6 +15,8 @@ mod add_member;
 mod bot_auth_parameters;
 mod create_allowed_mentions;
 mod create_components;
+mod create_emoji;
+mod create_guild;
 mod create_interaction_response;
 mod create_interaction_response_followup;
 mod create_invite;
@@ -23,8 +25,11 @@ mod create_scheduled_event;
 mod create_stage_instance;
 mod create_sticker;
 mod create_thread;
+mod edit_application;
+mod edit_application_role_connection_metadata;
 mod edit_automod_rule;
 mod edit_channel;
+mod edit_channel_positions;
 mod edit_guild;
 mod edit_guild_welcome_screen;
 mod edit_guild_widget;
@@ -38,6 +43,7 @@ mod edit_stage_instance;
 mod edit_sticker;
 mod edit_thread;
 mod edit_voice_state;
+mod edit_webhook;
 mod edit_webhook_message;
 mod execute_webhook;
 mod get_messages;
@@ -68,11 +74,16 @@ pub use self::create_components::{
     CreateSelectMenuOption,
     CreateSelectMenuOptions,
 };
+#[cfg(feature = "model")]
+pub(crate) use self::create_embed::embed_length;
 pub use self::create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter};
+pub use self::create_emoji::CreateEmoji;
+pub use self::create_guild::CreateGuild;
 pub use self::create_interaction_response::{
     CreateAutocompleteResponse,
     CreateInteractionResponse,
     CreateInteractionResponseData,
+    CreateModal,
 };
 pub use self::create_interaction_response_followup::CreateInteractionResponseFollowup;
 pub use self::create_invite::CreateInvite;
@@ -81,8 +92,14 @@ pub use self::create_scheduled_event::CreateScheduledEvent;
 pub use self::create_stage_instance::CreateStageInstance;
 pub use self::create_sticker::CreateSticker;
 pub use self::create_thread::CreateThread;
+pub use self::edit_application::EditApplication;
+pub use self::edit_application_role_connection_metadata::{
+    CreateApplicationRoleConnectionMetadataRecord,
+    EditApplicationRoleConnectionMetadata,
+};
 pub use self::edit_automod_rule::EditAutoModRule;
 pub use self::edit_channel::EditChannel;
+pub use self::edit_channel_positions::{EditChannelPosition, EditChannelPositions};
 pub use self::edit_guild::EditGuild;
 pub use self::edit_guild_welcome_screen::EditGuildWelcomeScreen;
 pub use self::edit_guild_widget::EditGuildWidget;
@@ -96,6 +113,7 @@ pub use self::edit_stage_instance::EditStageInstance;
 pub use self::edit_sticker::EditSticker;
 pub use self::edit_thread::EditThread;
 pub use self::edit_voice_state::EditVoiceState;
+pub use self::edit_webhook::EditWebhook;
 pub use self::edit_webhook_message::EditWebhookMessage;
 pub use self::execute_webhook::ExecuteWebhook;
 pub use self::get_messages::GetMessages;
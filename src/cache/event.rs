@@ -27,11 +27,12 @@ use crate::model::event::{
     ReadyEvent,
     ThreadCreateEvent,
     ThreadDeleteEvent,
+    ThreadMembersUpdateEvent,
     ThreadUpdateEvent,
     UserUpdateEvent,
     VoiceStateUpdateEvent,
 };
-use crate::model::guild::{Guild, Member, Role};
+use crate::model::guild::{Guild, GuildMemberFlags, Member, Role};
 use crate::model::user::{CurrentUser, OnlineStatus};
 use crate::model::voice::VoiceState;
 
@@ -39,6 +40,10 @@ impl CacheUpdate for ChannelCreateEvent {
     type Output = Channel;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_channels {
+            return None;
+        }
+
         match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
@@ -68,6 +73,8 @@ impl CacheUpdate for ChannelCreateEvent {
                         channel.recipient = u.clone();
                     }
 
+                    cache.user_private_channels.insert(user_id, channel.id);
+
                     channel.id
                 };
 
@@ -112,6 +119,7 @@ impl CacheUpdate for ChannelDeleteEvent {
                 let id = { channel.id };
 
                 cache.private_channels.remove(&id);
+                cache.user_private_channels.remove_if(&channel.recipient.id, |_, v| *v == id);
             },
         };
 
@@ -126,6 +134,10 @@ impl CacheUpdate for ChannelUpdateEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_channels {
+            return None;
+        }
+
         match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
@@ -183,8 +195,17 @@ impl CacheUpdate for GuildCreateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
         cache.unavailable_guilds.remove(&self.guild.id);
+        let settings = cache.settings();
         let mut guild = self.guild.clone();
 
+        if !settings.cache_presences {
+            guild.presences.clear();
+        }
+
+        if !settings.cache_voice_states {
+            guild.voice_states.clear();
+        }
+
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user);
             if let Some(u) = cache.user(user_id) {
@@ -192,19 +213,30 @@ impl CacheUpdate for GuildCreateEvent {
             }
         }
 
-        for pair in guild.channels.clone() {
-            if let Channel::Guild(channel) = pair.1 {
-                cache.channels.insert(pair.0, channel);
-            }
+        if !settings.cache_members {
+            guild.members.clear();
         }
 
-        for pair in guild.channels.clone() {
-            if let Channel::Category(category) = pair.1 {
-                cache.categories.insert(pair.0, category);
+        if settings.cache_channels {
+            for pair in guild.channels.clone() {
+                if let Channel::Guild(channel) = pair.1 {
+                    cache.channels.insert(pair.0, channel);
+                }
+            }
+
+            for pair in guild.channels.clone() {
+                if let Channel::Category(category) = pair.1 {
+                    cache.categories.insert(pair.0, category);
+                }
             }
+        } else {
+            guild.channels.clear();
+            guild.threads.clear();
         }
 
-        cache.guilds.insert(self.guild.id, guild);
+        if settings.cache_guilds {
+            cache.guilds.insert(self.guild.id, guild);
+        }
 
         None
     }
@@ -264,7 +296,10 @@ impl CacheUpdate for GuildMemberAddEvent {
 
         if let Some(mut guild) = cache.guilds.get_mut(&self.member.guild_id) {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+
+            if cache.settings().cache_members {
+                guild.members.insert(user_id, self.member.clone());
+            }
         }
 
         None
@@ -290,6 +325,10 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         cache.update_user_entry(&self.user);
 
+        if !cache.settings().cache_members {
+            return None;
+        }
+
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
             let item = if let Some(member) = guild.members.get_mut(&self.user.id) {
                 let item = Some(member.clone());
@@ -304,6 +343,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                 member.mute.clone_from(&self.mute);
                 member.avatar.clone_from(&self.avatar);
                 member.communication_disabled_until.clone_from(&self.communication_disabled_until);
+                member.flags = self.flags;
 
                 item
             } else {
@@ -324,6 +364,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
                     permissions: None,
                     avatar: self.avatar.clone(),
                     communication_disabled_until: self.communication_disabled_until,
+                    flags: self.flags,
                 });
             }
 
@@ -342,6 +383,10 @@ impl CacheUpdate for GuildMembersChunkEvent {
             cache.update_user_entry(&member.user);
         }
 
+        if !cache.settings().cache_members {
+            return None;
+        }
+
         if let Some(mut g) = cache.guilds.get_mut(&self.guild_id) {
             g.members.extend(self.members.clone());
         }
@@ -413,16 +458,23 @@ impl CacheUpdate for GuildUpdateEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild.id) {
+            // Only the fields present in the partial guild are merged, so that
+            // cached collections such as members, channels and presences that
+            // are not sent in the update are kept.
             guild.afk_channel_id.clone_from(&self.guild.afk_channel_id);
             guild.afk_timeout = self.guild.afk_timeout;
+            guild.application_id = self.guild.application_id;
             guild.banner.clone_from(&self.guild.banner);
+            guild.description.clone_from(&self.guild.description);
             guild.discovery_splash.clone_from(&self.guild.discovery_splash);
+            guild.emojis.clone_from(&self.guild.emojis);
             guild.features.clone_from(&self.guild.features);
             guild.icon.clone_from(&self.guild.icon);
             guild.name.clone_from(&self.guild.name);
             guild.owner_id.clone_from(&self.guild.owner_id);
             guild.roles.clone_from(&self.guild.roles);
             guild.splash.clone_from(&self.guild.splash);
+            guild.stickers.clone_from(&self.guild.stickers);
             guild.vanity_url_code.clone_from(&self.guild.vanity_url_code);
             guild.welcome_screen.clone_from(&self.guild.welcome_screen);
             guild.default_message_notifications = self.guild.default_message_notifications;
@@ -488,7 +540,7 @@ impl CacheUpdate for MessageUpdateEvent {
         let Self {
             id, channel_id, content, edited_timestamp, tts, mention_everyone, mentions,
             mention_roles, mention_channels, attachments, embeds, reactions, pinned, flags,
-            components, sticker_items,
+            components, sticker_items, thread,
 
             author: _, timestamp: _,  nonce: _, kind: _, stickers: _,  guild_id: _,
         } = &self;
@@ -511,6 +563,7 @@ impl CacheUpdate for MessageUpdateEvent {
         if let Some(x) = reactions { message.reactions = x.clone() }
         if let Some(x) = components { message.components = x.clone() }
         if let Some(x) = sticker_items { message.sticker_items = x.clone() }
+        if let Some(x) = thread { message.thread = Some(x.clone()) }
 
         Some(old_message)
     }
@@ -528,18 +581,21 @@ impl CacheUpdate for PresenceUpdateEvent {
             self.presence.user.update_with_user(user);
         }
 
+        let settings = cache.settings();
+        let (cache_members, cache_presences) = (settings.cache_members, settings.cache_presences);
+
         if let Some(guild_id) = self.presence.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 // If the member went offline, remove them from the presence list.
                 if self.presence.status == OnlineStatus::Offline {
                     guild.presences.remove(&self.presence.user.id);
-                } else {
+                } else if cache_presences {
                     guild.presences.insert(self.presence.user.id, self.presence.clone());
                 }
 
                 // Create a partial member instance out of the presence update
                 // data.
-                if let Some(user) = self.presence.user.to_user() {
+                if let Some(user) = self.presence.user.to_user().filter(|_| cache_members) {
                     guild.members.entry(self.presence.user.id).or_insert_with(|| Member {
                         deaf: false,
                         guild_id,
@@ -553,12 +609,13 @@ impl CacheUpdate for PresenceUpdateEvent {
                         permissions: None,
                         avatar: None,
                         communication_disabled_until: None,
+                        flags: GuildMemberFlags::default(),
                     });
                 }
             }
         } else if self.presence.status == OnlineStatus::Offline {
             cache.presences.remove(&self.presence.user.id);
-        } else {
+        } else if cache_presences {
             cache.presences.insert(self.presence.user.id, self.presence.clone());
         }
 
@@ -570,6 +627,10 @@ impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_presences {
+            return None;
+        }
+
         for presence in &self.presences {
             cache.presences.insert(presence.user.id, presence.clone());
         }
@@ -612,6 +673,8 @@ impl CacheUpdate for ReadyEvent {
         // `ready.private_channels` will always be empty, and possibly be removed in the future.
         // So don't handle it at all.
 
+        let cache_presences = cache.settings().cache_presences;
+
         for (user_id, presence) in &mut ready.presences {
             if let Some(user) = presence.user.to_user() {
                 cache.update_user_entry(&user);
@@ -620,7 +683,9 @@ impl CacheUpdate for ReadyEvent {
                 presence.user.update_with_user(user);
             }
 
-            cache.presences.insert(*user_id, presence.clone());
+            if cache_presences {
+                cache.presences.insert(*user_id, presence.clone());
+            }
         }
 
         *cache.shard_count.write() = ready.shard.map_or(1, |s| s[1]);
@@ -634,6 +699,10 @@ impl CacheUpdate for ThreadCreateEvent {
     type Output = GuildChannel;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_channels {
+            return None;
+        }
+
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
@@ -651,6 +720,10 @@ impl CacheUpdate for ThreadUpdateEvent {
     type Output = GuildChannel;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_channels {
+            return None;
+        }
+
         let (guild_id, thread_id) = (self.thread.guild_id, self.thread.id);
 
         cache.guilds.get_mut(&guild_id).and_then(|mut g| {
@@ -676,6 +749,30 @@ impl CacheUpdate for ThreadDeleteEvent {
     }
 }
 
+impl CacheUpdate for ThreadMembersUpdateEvent {
+    type Output = ();
+
+    fn update(&mut self, cache: &Cache) -> Option<()> {
+        let current_user_id = cache.user.read().id;
+
+        if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
+            if let Some(thread) = guild.threads.iter_mut().find(|t| t.id == self.id) {
+                thread.member_count = Some(self.member_count);
+
+                if let Some(member) =
+                    self.added_members.iter().find(|m| m.user_id == Some(current_user_id))
+                {
+                    thread.member = Some(member.clone());
+                } else if self.removed_members_ids.contains(&current_user_id) {
+                    thread.member = None;
+                }
+            }
+        }
+
+        None
+    }
+}
+
 impl CacheUpdate for UserUpdateEvent {
     type Output = CurrentUser;
 
@@ -689,17 +786,22 @@ impl CacheUpdate for VoiceStateUpdateEvent {
     type Output = VoiceState;
 
     fn update(&mut self, cache: &Cache) -> Option<VoiceState> {
+        let settings = cache.settings();
+
         if let Some(guild_id) = self.voice_state.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
-                if let Some(member) = &self.voice_state.member {
-                    guild.members.insert(member.user.id, member.clone());
+                if settings.cache_members {
+                    if let Some(member) = &self.voice_state.member {
+                        guild.members.insert(member.user.id, member.clone());
+                    }
                 }
 
-                if self.voice_state.channel_id.is_some() {
+                if self.voice_state.channel_id.is_some() && settings.cache_voice_states {
                     // Update or add to the voice state list
                     guild.voice_states.insert(self.voice_state.user_id, self.voice_state.clone())
                 } else {
-                    // Remove the user from the voice state list
+                    // Remove the user from the voice state list, or a stale voice state
+                    // if voice states are not cached
                     guild.voice_states.remove(&self.voice_state.user_id)
                 }
             } else {
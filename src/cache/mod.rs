@@ -27,8 +27,15 @@
 //! while needing to hit the REST API as little as possible, then the answer
 //! is "yes".
 //!
+//! Without the cache, the IDs of the current user and application are still
+//! tracked once a shard is ready, see [`Http::current_user_id`] and
+//! [`Http::application_id`]. Everything else has to be requested over the REST
+//! API, or taken from the events received.
+//!
 //! [`Shard`]: crate::gateway::Shard
 //! [`http`]: crate::http
+//! [`Http::current_user_id`]: crate::http::Http::current_user_id
+//! [`Http::application_id`]: crate::http::Http::application_id
 
 use std::collections::hash_map::RandomState;
 use std::collections::{HashMap, VecDeque};
@@ -50,9 +57,11 @@ use crate::model::prelude::*;
 mod cache_update;
 mod event;
 mod settings;
+mod stats;
 
 pub use self::cache_update::CacheUpdate;
 pub use self::settings::Settings;
+pub use self::stats::CacheStats;
 
 type MessageCache = DashMap<ChannelId, DashMap<MessageId, Message>>;
 
@@ -151,6 +160,12 @@ pub struct Cache {
     /// A map of direct message channels that the current user has open with
     /// other users.
     pub(crate) private_channels: DashMap<ChannelId, PrivateChannel>,
+    /// A map of users to the Id of the direct message channel that the
+    /// current user has open with them.
+    ///
+    /// This allows looking up a [`PrivateChannel`] by its recipient without
+    /// iterating over [`Self::private_channels`].
+    pub(crate) user_private_channels: DashMap<UserId, ChannelId>,
     /// The total number of shards being used by the bot.
     pub(crate) shard_count: RwLock<u64>,
     /// A list of guilds which are "unavailable". Refer to the documentation for
@@ -670,6 +685,29 @@ impl Cache {
         Some(field_selector(member))
     }
 
+    /// Retrieves the bot's own [`Member`] in a [`Guild`] from the cache, using
+    /// the cached [`Self::current_user_id`].
+    ///
+    /// This is useful for checking the bot's permissions without making an
+    /// extra API call.
+    ///
+    /// Returns [`None`] if the guild or the bot's member is not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(member) = cache.current_user_member(7) {
+    ///     println!("The bot has {} roles", member.roles.len());
+    /// }
+    /// ```
+    #[inline]
+    pub fn current_user_member(&self, guild_id: impl Into<GuildId>) -> Option<Member> {
+        self._member(guild_id.into(), self.current_user_id())
+    }
+
     #[inline]
     pub fn guild_roles(&self, guild_id: impl Into<GuildId>) -> Option<HashMap<RoleId, Role>> {
         self._guild_roles(guild_id.into())
@@ -807,6 +845,40 @@ impl Cache {
         self.private_channels.get(&channel_id).map(|i| i.clone())
     }
 
+    /// Retrieves the [`PrivateChannel`] that the current user has open with
+    /// the given user, if it exists.
+    ///
+    /// # Examples
+    ///
+    /// Retrieve the direct message channel with a user and print its Id:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// if let Some(channel) = cache.user_private_channel(7) {
+    ///     println!("The channel is {}", channel.id);
+    /// }
+    /// ```
+    #[inline]
+    pub fn user_private_channel(&self, user_id: impl Into<UserId>) -> Option<PrivateChannel> {
+        self._user_private_channel(user_id.into())
+    }
+
+    fn _user_private_channel(&self, user_id: UserId) -> Option<PrivateChannel> {
+        let channel_id = *self.user_private_channels.get(&user_id)?;
+
+        self.private_channels.get(&channel_id).map(|i| i.clone())
+    }
+
+    /// Removes the [`PrivateChannel`] that the current user has open with
+    /// the given user from the cache, returning it if it existed.
+    pub(crate) fn remove_user_private_channel(&self, user_id: UserId) -> Option<PrivateChannel> {
+        let (_, channel_id) = self.user_private_channels.remove(&user_id)?;
+
+        self.private_channels.remove(&channel_id).map(|(_, channel)| channel)
+    }
+
     /// Retrieves a [`Guild`]'s role by their Ids.
     ///
     /// **Note**: This will clone the entire role. Instead, retrieve the guild
@@ -866,6 +938,13 @@ impl Cache {
         self.settings.write().max_messages = max;
     }
 
+    /// Sets whether to cache presences, see [`Settings::cache_presences`].
+    ///
+    /// Disabling it does not remove already cached presences.
+    pub fn set_cache_presences(&self, cache_presences: bool) {
+        self.settings.write().cache_presences = cache_presences;
+    }
+
     /// Retrieves a [`User`] from the cache's [`Self::users`] map, if it exists.
     ///
     /// The only advantage of this method is that you can pass in anything that
@@ -940,6 +1019,44 @@ impl Cache {
         self.categories.len()
     }
 
+    /// Returns a snapshot of the number of objects in the cache.
+    ///
+    /// The collections are counted one after another, and per-guild members
+    /// and roles one guild at a time, so the whole cache is never locked at
+    /// once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use serenity::cache::Cache;
+    /// #
+    /// # let cache = Cache::default();
+    /// let stats = cache.stats();
+    ///
+    /// println!("{} members cached across {} guilds", stats.members, stats.guilds);
+    /// ```
+    pub fn stats(&self) -> CacheStats {
+        let mut stats = CacheStats {
+            guilds: self.guilds.len(),
+            unavailable_guilds: self.unavailable_guilds.len(),
+            channels: self.channels.len(),
+            categories: self.categories.len(),
+            private_channels: self.private_channels.len(),
+            users: self.users.len(),
+            presences: self.presences.len(),
+            ..CacheStats::default()
+        };
+
+        for guild in &self.guilds {
+            stats.members += guild.members.len();
+            stats.roles += guild.roles.len();
+        }
+
+        stats.messages = self.messages.iter().map(|messages| messages.len()).sum();
+
+        stats
+    }
+
     /// Returns the optional category ID of a channel.
     #[inline]
     pub fn channel_category_id(&self, channel_id: ChannelId) -> Option<ChannelId> {
@@ -999,6 +1116,10 @@ impl Cache {
     }
 
     pub(crate) fn update_user_entry(&self, user: &User) {
+        if !self.settings.read().cache_users {
+            return;
+        }
+
         match self.users.entry(user.id) {
             Entry::Vacant(e) => {
                 e.insert(user.clone());
@@ -1021,6 +1142,7 @@ impl Default for Cache {
             messages: DashMap::default(),
             presences: DashMap::default(),
             private_channels: DashMap::with_capacity(128),
+            user_private_channels: DashMap::with_capacity(128),
             settings: RwLock::new(Settings::default()),
             shard_count: RwLock::new(1),
             unavailable_guilds: DashSet::default(),
@@ -1038,7 +1160,7 @@ mod test {
     use std::collections::HashMap;
 
     use crate::cache::{Cache, CacheUpdate, Settings};
-    use crate::json::from_number;
+    use crate::json::{from_number, from_value, json, Value};
     use crate::model::prelude::*;
 
     #[test]
@@ -1086,8 +1208,12 @@ mod test {
                 flags: None,
                 sticker_items: vec![],
                 referenced_message: None,
+                referenced_message_deleted: false,
                 interaction: None,
+                interaction_metadata: None,
                 components: vec![],
+                call: None,
+                thread: None,
             },
         };
 
@@ -1135,10 +1261,15 @@ mod test {
             rtc_region: None,
             video_quality_mode: None,
             message_count: None,
+            total_message_sent: None,
             member_count: None,
             thread_metadata: None,
             member: None,
             default_auto_archive_duration: None,
+            default_thread_rate_limit_per_user: None,
+            available_tags: Vec::new(),
+            applied_tags: Vec::new(),
+            flags: None,
         });
 
         // Add a channel delete event to the cache, the cached messages for that
@@ -1222,4 +1353,299 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId(2)));
     }
+
+    /// Builds a guild creation event for a guild with the given members.
+    fn guild_create_event(id: u64, members: Value) -> GuildCreateEvent {
+        from_value(json!({
+            "id": id.to_string(),
+            "afk_timeout": 0,
+            "channels": [],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "large": false,
+            "member_count": members.as_array().map_or(0, Vec::len),
+            "members": members,
+            "mfa_level": 0,
+            "name": "old name",
+            "owner_id": "3",
+            "presences": [],
+            "roles": [],
+            "splash": null,
+            "verification_level": 1,
+            "voice_states": [],
+            "preferred_locale": "en-US",
+            "nsfw_level": 0,
+            "system_channel_flags": 0,
+            "stickers": [],
+        }))
+        .unwrap()
+    }
+
+    /// Builds the JSON of a guild member with the given user ID.
+    fn member_json(guild_id: u64, user_id: u64) -> Value {
+        json!({
+            "guild_id": guild_id.to_string(),
+            "deaf": false,
+            "mute": false,
+            "roles": [],
+            "joined_at": null,
+            "user": {
+                "id": user_id.to_string(),
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+        })
+    }
+
+    #[test]
+    fn test_cache_guild_update_keeps_members() {
+        let cache = Cache::new();
+
+        let mut guild_create = guild_create_event(1, json!([member_json(1, 210)]));
+        assert!(cache.update(&mut guild_create).is_none());
+
+        let mut guild_update: GuildUpdateEvent = from_value(json!({
+            "id": "1",
+            "afk_timeout": 0,
+            "default_message_notifications": 0,
+            "emojis": [],
+            "features": [],
+            "icon": null,
+            "mfa_level": 0,
+            "name": "new name",
+            "owner_id": "3",
+            "roles": [],
+            "splash": null,
+            "verification_level": 1,
+            "description": "new description",
+            "nsfw_level": 0,
+            "system_channel_flags": 0,
+            "stickers": [],
+        }))
+        .unwrap();
+        assert!(cache.update(&mut guild_update).is_none());
+
+        let guild = cache.guild(GuildId(1)).unwrap();
+        assert_eq!(guild.name, "new name");
+        assert_eq!(guild.description.as_deref(), Some("new description"));
+        assert_eq!(guild.members.len(), 1);
+        assert!(guild.members.contains_key(&UserId(210)));
+    }
+
+    #[test]
+    fn test_cache_current_user_member() {
+        let cache = Cache::new();
+
+        let mut guild_create = guild_create_event(1, json!([member_json(1, 210)]));
+        cache.update(&mut guild_create);
+
+        assert!(cache.current_user_member(GuildId(1)).is_none());
+
+        cache.user.write().id = UserId(210);
+        assert_eq!(cache.current_user_member(GuildId(1)).unwrap().user.id, UserId(210));
+        assert!(cache.current_user_member(GuildId(2)).is_none());
+    }
+
+    #[test]
+    fn test_cache_stats() {
+        let mut settings = Settings::new();
+        settings.max_messages(10);
+        let cache = Cache::new_with_settings(settings);
+
+        let stats = cache.stats();
+        assert_eq!(stats.guilds, 0);
+        assert_eq!(stats.members, 0);
+
+        let mut guild_create = guild_create_event(1, json!([member_json(1, 2), member_json(1, 3)]));
+        cache.update(&mut guild_create);
+        let mut guild_create = guild_create_event(4, json!([member_json(4, 2)]));
+        cache.update(&mut guild_create);
+
+        let mut message_create: MessageCreateEvent = from_value(json!({
+            "id": "5",
+            "channel_id": "6",
+            "author": {
+                "id": "2",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "content": "",
+            "timestamp": "2023-01-01T00:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+        }))
+        .unwrap();
+        cache.update(&mut message_create);
+
+        let stats = cache.stats();
+        assert_eq!(stats.guilds, 2);
+        assert_eq!(stats.members, 3);
+        assert_eq!(stats.roles, 0);
+        assert_eq!(stats.messages, 1);
+    }
+
+    #[test]
+    fn test_cache_user_private_channels() {
+        let cache = Cache::new();
+
+        let private_channel = |channel_id: u64| {
+            from_value::<Channel>(json!({
+                "id": channel_id.to_string(),
+                "type": 1,
+                "recipients": [{
+                    "id": "2",
+                    "username": "user",
+                    "discriminator": "0001",
+                    "avatar": null,
+                }],
+            }))
+            .unwrap()
+        };
+
+        assert!(cache.user_private_channel(UserId(2)).is_none());
+
+        let mut channel_create = ChannelCreateEvent {
+            channel: private_channel(1),
+        };
+        cache.update(&mut channel_create);
+        assert_eq!(cache.user_private_channel(UserId(2)).map(|c| c.id), Some(ChannelId(1)));
+        assert!(cache.user_private_channel(UserId(3)).is_none());
+
+        // Deleting a previous channel with the user keeps the current one.
+        let mut channel_delete = ChannelDeleteEvent {
+            channel: private_channel(4),
+        };
+        cache.update(&mut channel_delete);
+        assert_eq!(cache.user_private_channel(UserId(2)).map(|c| c.id), Some(ChannelId(1)));
+
+        let mut channel_delete = ChannelDeleteEvent {
+            channel: private_channel(1),
+        };
+        cache.update(&mut channel_delete);
+        assert!(cache.user_private_channel(UserId(2)).is_none());
+        assert!(cache.private_channels.is_empty());
+
+        // A channel which turned out to be unknown is evicted by user.
+        cache.update(&mut channel_create);
+        assert_eq!(cache.remove_user_private_channel(UserId(2)).map(|c| c.id), Some(ChannelId(1)));
+        assert!(cache.user_private_channel(UserId(2)).is_none());
+        assert!(cache.private_channels.is_empty());
+        assert!(cache.remove_user_private_channel(UserId(2)).is_none());
+    }
+
+    #[test]
+    fn test_cache_presences_setting() {
+        let mut settings = Settings::new();
+        settings.cache_presences(false);
+        let cache = Cache::new_with_settings(settings);
+
+        let mut presence_update: PresenceUpdateEvent = from_value(json!({
+            "status": "online",
+            "user": { "id": "2" },
+        }))
+        .unwrap();
+
+        cache.update(&mut presence_update);
+        assert!(cache.presences.is_empty());
+
+        cache.set_cache_presences(true);
+        cache.update(&mut presence_update);
+        assert_eq!(cache.presences.get(&UserId(2)).map(|p| p.status), Some(OnlineStatus::Online));
+    }
+
+    #[test]
+    fn test_cache_update_settings() {
+        let mut settings = Settings::new();
+        settings.cache_channels(false).cache_users(false);
+        let cache = Cache::new_with_settings(settings);
+
+        let mut channel_create: ChannelCreateEvent = from_value(json!({
+            "id": "1",
+            "type": 1,
+            "recipients": [{
+                "id": "2",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            }],
+        }))
+        .unwrap();
+
+        assert!(cache.update(&mut channel_create).is_none());
+        assert!(cache.private_channels.is_empty());
+        assert!(cache.users.is_empty());
+
+        let mut channel_delete = ChannelDeleteEvent {
+            channel: channel_create.channel.clone(),
+        };
+        cache.private_channels.insert(ChannelId(1), match channel_create.channel {
+            Channel::Private(ref channel) => channel.clone(),
+            _ => unreachable!(),
+        });
+
+        // Deletions are applied regardless of the settings.
+        cache.update(&mut channel_delete);
+        assert!(cache.private_channels.is_empty());
+    }
+
+    #[test]
+    fn test_cache_thread_members_update() {
+        let cache = Cache::new();
+        cache.user.write().id = UserId(3);
+
+        let mut guild_create = guild_create_event(1, json!([]));
+        guild_create.guild.threads.push(
+            from_value(json!({
+                "id": "2",
+                "guild_id": "1",
+                "type": 11,
+                "name": "thread",
+                "member_count": 1,
+            }))
+            .unwrap(),
+        );
+        cache.update(&mut guild_create);
+
+        let mut event: ThreadMembersUpdateEvent = from_value(json!({
+            "id": "2",
+            "guild_id": "1",
+            "member_count": 2,
+            "added_members": [{
+                "id": "2",
+                "user_id": "3",
+                "join_timestamp": "2023-01-01T00:00:00.000000+00:00",
+                "flags": 0,
+            }],
+            "removed_member_ids": ["4"],
+        }))
+        .unwrap();
+        assert_eq!(event.removed_members_ids, vec![UserId(4)]);
+
+        cache.update(&mut event);
+        let thread = cache.guild_field(GuildId(1), |g| g.threads[0].clone()).unwrap();
+        assert_eq!(thread.member_count, Some(2));
+        assert_eq!(thread.member.and_then(|m| m.user_id), Some(UserId(3)));
+
+        event.added_members.clear();
+        event.removed_members_ids = vec![UserId(3)];
+        event.member_count = 1;
+
+        cache.update(&mut event);
+        let thread = cache.guild_field(GuildId(1), |g| g.threads[0].clone()).unwrap();
+        assert_eq!(thread.member_count, Some(1));
+        assert!(thread.member.is_none());
+    }
 }
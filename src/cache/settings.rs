@@ -1,5 +1,20 @@
 /// Settings for the cache.
 ///
+/// Each kind of data can be excluded from the cache separately, to save
+/// memory when it is only needed in the event handler. Events are still
+/// dispatched to the handler when they don't update the cache.
+///
+/// Disabling a kind of data has some consequences for later lookups:
+///
+/// - methods relying on the cache, such as [`Cache::guild`] or
+///   [`GuildChannel::permissions_for_user`], fail or fall back to
+///   an HTTP request for the excluded data;
+/// - the "old" values passed to the event handler with the `cache` feature,
+///   e.g. the previous channel in [`EventHandler::channel_update`], are [`None`]
+///   for the excluded data;
+/// - deletions are still applied, and already cached data is not removed when
+///   a setting is disabled later on.
+///
 /// # Examples
 ///
 /// Create new settings, specifying the maximum number of messages:
@@ -10,13 +25,97 @@
 /// let mut settings = CacheSettings::new();
 /// settings.max_messages(10);
 /// ```
-#[derive(Clone, Debug, Default)]
+///
+/// Cache guilds and channels, but neither members nor presences:
+///
+/// ```rust
+/// use serenity::cache::Settings as CacheSettings;
+///
+/// let mut settings = CacheSettings::new();
+/// settings.cache_members(false).cache_presences(false);
+/// ```
+///
+/// [`Cache::guild`]: super::Cache::guild
+/// [`GuildChannel::permissions_for_user`]: crate::model::channel::GuildChannel::permissions_for_user
+/// [`EventHandler::channel_update`]: crate::client::EventHandler::channel_update
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
+    /// Messages are not cached if this is 0, while message events are still
+    /// dispatched.
+    ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// Whether to cache guilds, as received with [`GuildCreateEvent`]s.
+    ///
+    /// Roles, emojis, stickers, members and voice states are stored as part of
+    /// their guild, so they are not cached either if this is disabled.
+    /// Channels of the guild are still cached if [`Self::cache_channels`] is
+    /// enabled.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`GuildCreateEvent`]: crate::model::event::GuildCreateEvent
+    pub cache_guilds: bool,
+    /// Whether to cache channels, categories and threads, both of guilds and
+    /// direct messages.
+    ///
+    /// Defaults to `true`.
+    pub cache_channels: bool,
+    /// Whether to cache the members of guilds.
+    ///
+    /// Permission calculations and [`Cache::member`] rely on cached members,
+    /// so disable this only if members are fetched when needed.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Cache::member`]: super::Cache::member
+    pub cache_members: bool,
+    /// Whether to cache the voice states of guilds, as received with the
+    /// [`GUILD_VOICE_STATES`] intent.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`GUILD_VOICE_STATES`]: crate::model::gateway::GatewayIntents::GUILD_VOICE_STATES
+    pub cache_voice_states: bool,
+    /// Whether to cache users seen in events, e.g. the users of members and
+    /// presences.
+    ///
+    /// [`Cache::user`] relies on cached users. The current user is always
+    /// cached.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Cache::user`]: super::Cache::user
+    pub cache_users: bool,
+    /// Whether to cache the presences of users, as received with the
+    /// [`GUILD_PRESENCES`] intent.
+    ///
+    /// Presences contain the user's status and activities, and are usually
+    /// the largest part of the cache in big guilds, as one is stored for each
+    /// online member. Disable this if the presences intent is enabled but
+    /// presences are only handled in events.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`GUILD_PRESENCES`]: crate::model::gateway::GatewayIntents::GUILD_PRESENCES
+    pub cache_presences: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_messages: 0,
+            cache_guilds: true,
+            cache_channels: true,
+            cache_members: true,
+            cache_voice_states: true,
+            cache_users: true,
+            cache_presences: true,
+        }
+    }
 }
 
 impl Settings {
@@ -48,4 +147,70 @@ impl Settings {
 
         self
     }
+
+    /// Sets whether to cache guilds.
+    ///
+    /// Refer to [`cache_guilds`] for more information.
+    ///
+    /// [`cache_guilds`]: #structfield.cache_guilds
+    pub fn cache_guilds(&mut self, cache_guilds: bool) -> &mut Self {
+        self.cache_guilds = cache_guilds;
+
+        self
+    }
+
+    /// Sets whether to cache channels.
+    ///
+    /// Refer to [`cache_channels`] for more information.
+    ///
+    /// [`cache_channels`]: #structfield.cache_channels
+    pub fn cache_channels(&mut self, cache_channels: bool) -> &mut Self {
+        self.cache_channels = cache_channels;
+
+        self
+    }
+
+    /// Sets whether to cache members.
+    ///
+    /// Refer to [`cache_members`] for more information.
+    ///
+    /// [`cache_members`]: #structfield.cache_members
+    pub fn cache_members(&mut self, cache_members: bool) -> &mut Self {
+        self.cache_members = cache_members;
+
+        self
+    }
+
+    /// Sets whether to cache voice states.
+    ///
+    /// Refer to [`cache_voice_states`] for more information.
+    ///
+    /// [`cache_voice_states`]: #structfield.cache_voice_states
+    pub fn cache_voice_states(&mut self, cache_voice_states: bool) -> &mut Self {
+        self.cache_voice_states = cache_voice_states;
+
+        self
+    }
+
+    /// Sets whether to cache users.
+    ///
+    /// Refer to [`cache_users`] for more information.
+    ///
+    /// [`cache_users`]: #structfield.cache_users
+    pub fn cache_users(&mut self, cache_users: bool) -> &mut Self {
+        self.cache_users = cache_users;
+
+        self
+    }
+
+    /// Sets whether to cache presences.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, cache_presences: bool) -> &mut Self {
+        self.cache_presences = cache_presences;
+
+        self
+    }
 }
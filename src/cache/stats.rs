@@ -0,0 +1,35 @@
+/// A snapshot of the number of objects in the cache, as returned by
+/// [`Cache::stats`].
+///
+/// As the cache is not locked as a whole while counting, the numbers may be
+/// slightly inconsistent with each other if events are processed in the
+/// meantime.
+///
+/// [`Cache::stats`]: super::Cache::stats
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct CacheStats {
+    /// The number of available guilds.
+    pub guilds: usize,
+    /// The number of guilds which are unavailable or not received yet.
+    pub unavailable_guilds: usize,
+    /// The number of guild channels, excluding categories.
+    pub channels: usize,
+    /// The number of channel categories.
+    pub categories: usize,
+    /// The number of private channels.
+    pub private_channels: usize,
+    /// The number of users.
+    pub users: usize,
+    /// The number of members, summed across all guilds.
+    ///
+    /// Comparing this with the guilds' member counts shows whether member
+    /// chunking has completed.
+    pub members: usize,
+    /// The number of roles, summed across all guilds.
+    pub roles: usize,
+    /// The number of messages, summed across all channels.
+    pub messages: usize,
+    /// The number of presences.
+    pub presences: usize,
+}
@@ -3,6 +3,7 @@
 
 use super::ShardId;
 use crate::gateway::ConnectionStage;
+use crate::model::Timestamp;
 
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug)]
@@ -25,3 +26,16 @@ pub struct ShardStageUpdateEvent {
     /// The ID of the shard that had its connection stage change.
     pub shard_id: ShardId,
 }
+
+/// A [`ShardStageUpdateEvent`] along with when the connection stage was
+/// changed, as received from [`ShardManager::stage_updates`].
+///
+/// [`ShardManager::stage_updates`]: super::ShardManager::stage_updates
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct TimestampedShardStageUpdate {
+    /// The connection stage change.
+    pub event: ShardStageUpdateEvent,
+    /// When the connection stage changed.
+    pub timestamp: Timestamp,
+}
@@ -54,10 +54,14 @@ mod shard_runner_message;
 use std::fmt;
 use std::time::Duration as StdDuration;
 
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+
 pub use self::shard_manager::{ShardManager, ShardManagerOptions};
 pub use self::shard_manager_monitor::{ShardManagerError, ShardManagerMonitor};
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
+pub(crate) use self::shard_queuer::ShardSettings;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
 pub use self::shard_runner_message::{ChunkGuildFilter, ShardRunnerMessage};
 use crate::gateway::ConnectionStage;
@@ -152,6 +156,27 @@ pub struct ShardRunnerInfo {
     pub stage: ConnectionStage,
 }
 
+/// The task running a [`ShardRunner`], tracked by the [`ShardManager`] to
+/// abort runners that do not shut down in time and to restart stuck shards.
+#[derive(Debug)]
+pub(crate) struct ShardRunnerTask {
+    /// The handle of the task.
+    pub(crate) handle: JoinHandle<()>,
+    /// When the shard runner last reported a heartbeat acknowledgement or a
+    /// change of its connection stage, or was started.
+    pub(crate) last_activity: Instant,
+}
+
+impl ShardRunnerTask {
+    /// Tracks the task of a shard runner which was just started.
+    pub(crate) fn new(handle: JoinHandle<()>) -> Self {
+        Self {
+            handle,
+            last_activity: Instant::now(),
+        }
+    }
+}
+
 impl AsRef<ShardMessenger> for ShardRunnerInfo {
     fn as_ref(&self) -> &ShardMessenger {
         &self.runner_tx
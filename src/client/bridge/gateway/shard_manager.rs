@@ -1,5 +1,6 @@
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::StreamExt;
@@ -8,22 +9,29 @@ use tokio::time::timeout;
 use tracing::{info, instrument, warn};
 use typemap_rev::TypeMap;
 
+use super::event::{ShardStageUpdateEvent, TimestampedShardStageUpdate};
 use super::{
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
+    ShardMessenger,
     ShardQueuer,
     ShardQueuerMessage,
     ShardRunnerInfo,
+    ShardRunnerTask,
+    ShardSettings,
 };
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
+use crate::gateway::ConnectionStage;
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
 use crate::model::gateway::GatewayIntents;
+use crate::model::id::GuildId;
+use crate::model::Timestamp;
 use crate::CacheAndHttp;
 
 /// A manager for handling the status of shards by starting them, restarting
@@ -112,17 +120,35 @@ pub struct ShardManager {
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
     shard_shutdown: Receiver<ShardId>,
+    /// How long a shard may go without reporting activity before the monitor
+    /// restarts it, if at all.
+    pub(crate) watchdog_timeout: Option<Duration>,
+    /// The tasks running the shard runners currently managed.
+    pub(crate) runner_tasks: Arc<Mutex<HashMap<ShardId, ShardRunnerTask>>>,
+    stage_update_txs: Vec<Sender<TimestampedShardStageUpdate>>,
+    intents: GatewayIntents,
 }
 
 impl ShardManager {
     /// Creates a new shard manager, returning both the manager and a monitor
     /// for usage in a separate thread.
     pub async fn new(opt: ShardManagerOptions<'_>) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
+        Self::new_with_settings(opt, ShardSettings::default()).await
+    }
+
+    /// Creates a new shard manager starting the shards with the given
+    /// settings.
+    pub(crate) async fn new_with_settings(
+        opt: ShardManagerOptions<'_>,
+        settings: ShardSettings,
+    ) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
         let (thread_tx, thread_rx) = mpsc::unbounded();
         let (shard_queue_tx, shard_queue_rx) = mpsc::unbounded();
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
+        let runner_tasks = Arc::new(Mutex::new(HashMap::new()));
         let (shutdown_send, shutdown_recv) = mpsc::unbounded();
+        let watchdog_timeout = settings.watchdog_timeout;
 
         let mut shard_queuer = ShardQueuer {
             data: Arc::clone(opt.data),
@@ -141,9 +167,10 @@ impl ShardManager {
             cache_and_http: Arc::clone(opt.cache_and_http),
             intents: opt.intents,
         };
+        let queuer_tasks = Arc::clone(&runner_tasks);
 
         spawn_named("shard_queuer::run", async move {
-            shard_queuer.run().await;
+            shard_queuer.run_with_settings(settings, queuer_tasks).await;
         });
 
         let manager = Arc::new(Mutex::new(Self {
@@ -153,7 +180,11 @@ impl ShardManager {
             shard_queuer: shard_queue_tx,
             shard_total: opt.shard_total,
             shard_shutdown: shutdown_recv,
+            watchdog_timeout,
+            runner_tasks,
             runners,
+            stage_update_txs: Vec::new(),
+            intents: opt.intents,
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
@@ -172,6 +203,121 @@ impl ShardManager {
         self.runners.lock().await.contains_key(&shard_id)
     }
 
+    /// Returns the Id of the shard that receives the events of the given guild.
+    ///
+    /// This is calculated as `(guild_id >> 22) % shard_total`, where
+    /// `shard_total` is the total number of shards in use, including the ones
+    /// not managed by this manager.
+    ///
+    /// **Note**: Direct messages are not tied to a guild and are only sent to
+    /// shard `0`. Use [`Self::shard_id_for`] to also handle them.
+    #[must_use]
+    pub fn shard_id_for_guild(&self, guild_id: impl Into<GuildId>) -> ShardId {
+        ShardId((guild_id.into().0 >> 22) % self.shard_total)
+    }
+
+    /// Returns the Id of the shard that receives the events of the given guild,
+    /// or of direct messages if [`None`] is given.
+    ///
+    /// Direct messages are only sent to shard `0`, so gateway commands related
+    /// to them should be sent through that shard too. For guilds, this is the
+    /// same as [`Self::shard_id_for_guild`].
+    #[must_use]
+    pub fn shard_id_for(&self, guild_id: Option<GuildId>) -> ShardId {
+        guild_id.map_or(ShardId(0), |guild_id| self.shard_id_for_guild(guild_id))
+    }
+
+    /// Returns the [`ShardMessenger`] of the shard runner responsible for the
+    /// given guild, e.g. to request its members.
+    ///
+    /// Returns [`None`] if the responsible shard is not instantiated by this
+    /// manager, e.g. because it is managed by another process.
+    ///
+    /// Refer to [`Self::shard_id_for_guild`] for how the shard is determined.
+    pub async fn messenger_for_guild(
+        &self,
+        guild_id: impl Into<GuildId>,
+    ) -> Option<ShardMessenger> {
+        let shard_id = self.shard_id_for_guild(guild_id);
+
+        self.runners.lock().await.get(&shard_id).map(|runner| runner.runner_tx.clone())
+    }
+
+    /// Returns the [`ShardMessenger`] of the shard runner responsible for the
+    /// given guild, or for direct messages if [`None`] is given.
+    ///
+    /// Returns [`None`] if the responsible shard is not instantiated by this
+    /// manager, e.g. because it is managed by another process.
+    ///
+    /// Refer to [`Self::shard_id_for`] for how the shard is determined.
+    pub async fn messenger_for(&self, guild_id: Option<GuildId>) -> Option<ShardMessenger> {
+        let shard_id = self.shard_id_for(guild_id);
+
+        self.runners.lock().await.get(&shard_id).map(|runner| runner.runner_tx.clone())
+    }
+
+    /// Returns a stream of the connection stage changes of all shards managed
+    /// by this manager, e.g. to observe when shards connect, resume or
+    /// disconnect.
+    ///
+    /// A shard that is shut down or restarted changes to the
+    /// [`ConnectionStage::Disconnected`] stage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # async fn run(client: Client) {
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut updates = client.shard_manager.lock().await.stage_updates();
+    ///
+    /// tokio::spawn(async move {
+    ///     while let Some(update) = updates.next().await {
+    ///         let event = update.event;
+    ///
+    ///         println!(
+    ///             "[{}] Shard {} changed from {} to {}",
+    ///             update.timestamp, event.shard_id, event.old, event.new
+    ///         );
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    ///
+    /// [`ConnectionStage::Disconnected`]: crate::gateway::ConnectionStage::Disconnected
+    pub fn stage_updates(&mut self) -> Receiver<TimestampedShardStageUpdate> {
+        let (tx, rx) = mpsc::unbounded();
+        self.stage_update_txs.push(tx);
+
+        rx
+    }
+
+    /// Sends a stage update, timestamped with the current time, to all streams
+    /// returned by [`Self::stage_updates`], dropping the ones that are no
+    /// longer received from.
+    pub(crate) fn send_stage_update(&mut self, event: ShardStageUpdateEvent) {
+        let update = TimestampedShardStageUpdate {
+            event,
+            timestamp: Timestamp::now(),
+        };
+
+        self.stage_update_txs.retain(|tx| tx.unbounded_send(update.clone()).is_ok());
+    }
+
+    /// Returns the gateway intents the shards identify with.
+    ///
+    /// If the shards fail with a [`GatewayError::DisallowedGatewayIntents`]
+    /// error, the [privileged intents] among them are likely not enabled for
+    /// the bot.
+    ///
+    /// [`GatewayError::DisallowedGatewayIntents`]: crate::gateway::GatewayError::DisallowedGatewayIntents
+    /// [privileged intents]: GatewayIntents::privileged
+    #[must_use]
+    pub fn intents(&self) -> GatewayIntents {
+        self.intents
+    }
+
     /// Initializes all shards that the manager is responsible for.
     ///
     /// This will communicate shard boots with the [`ShardQueuer`] so that they
@@ -276,7 +422,7 @@ impl ShardManager {
 
         drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::ShutdownShard(shard_id, code)));
 
-        match timeout(TIMEOUT, self.shard_shutdown.next()).await {
+        let timed_out = match timeout(TIMEOUT, self.shard_shutdown.next()).await {
             Ok(Some(shutdown_shard_id)) => {
                 if shutdown_shard_id != shard_id {
                     warn!(
@@ -284,14 +430,36 @@ impl ShardManager {
                         shard_id,
                     );
                 }
+
+                false
             },
-            Ok(None) => (),
+            Ok(None) => false,
             Err(why) => {
                 warn!("Failed to cleanly shutdown shard {}, reached timeout: {:?}", shard_id, why);
+
+                true
             },
+        };
+
+        let runner = self.runners.lock().await.remove(&shard_id);
+        let task = self.runner_tasks.lock().await.remove(&shard_id);
+
+        // A runner that did not shut down in time may be stuck, so abort it
+        // to close its connection, instead of letting it dispatch events
+        // alongside a restarted runner once it unblocks.
+        if let (true, Some(task)) = (timed_out, task) {
+            task.handle.abort();
         }
 
-        self.runners.lock().await.remove(&shard_id);
+        if let Some(runner) = runner {
+            if runner.stage != ConnectionStage::Disconnected {
+                self.send_stage_update(ShardStageUpdateEvent {
+                    new: ConnectionStage::Disconnected,
+                    old: runner.stage,
+                    shard_id,
+                });
+            }
+        }
     }
 
     /// Sends a shutdown message for all shards that the manager is responsible
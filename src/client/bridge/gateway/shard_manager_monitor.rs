@@ -1,10 +1,13 @@
 use std::sync::Arc;
+use std::time::Duration;
 
 use futures::channel::mpsc::{UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::StreamExt;
 use tokio::sync::Mutex;
+use tokio::time::{timeout_at, Instant};
 use tracing::{debug, instrument, warn};
 
+use super::event::ShardStageUpdateEvent;
 use super::{ShardManager, ShardManagerMessage};
 use crate::client::bridge::gateway::ShardId;
 
@@ -53,11 +56,42 @@ impl ShardManagerMonitor {
     /// - a [`ShardManagerMessage::ShutdownAll`] has been received
     /// - an error is returned while receiving a message from the
     /// channel (probably indicating that the shard manager should stop anyway)
+    ///
+    /// If a [`ClientBuilder::shard_watchdog_timeout`] is set, this also
+    /// periodically restarts stuck shards.
+    ///
+    /// [`ClientBuilder::shard_watchdog_timeout`]: crate::client::ClientBuilder::shard_watchdog_timeout
     #[instrument(skip(self))]
     pub async fn run(&mut self) -> Result<()> {
         debug!("Starting shard manager worker");
 
-        while let Some(value) = self.rx.next().await {
+        let watchdog_timeout = self.manager.lock().await.watchdog_timeout;
+        let next_watchdog_check = || watchdog_timeout.map(|timeout| Instant::now() + timeout / 2);
+        let mut next_check = next_watchdog_check();
+
+        loop {
+            let value = match next_check {
+                Some(deadline) => {
+                    if let Ok(value) = timeout_at(deadline, self.rx.next()).await {
+                        value
+                    } else {
+                        if let Some(timeout) = watchdog_timeout {
+                            self.restart_stuck_shards(timeout).await;
+                        }
+
+                        next_check = next_watchdog_check();
+
+                        continue;
+                    }
+                },
+                None => self.rx.next().await,
+            };
+
+            let value = match value {
+                Some(value) => value,
+                None => break,
+            };
+
             match value {
                 ShardManagerMessage::Restart(shard_id) => {
                     self.manager.lock().await.restart(shard_id).await;
@@ -68,12 +102,24 @@ impl ShardManagerMonitor {
                     latency,
                     stage,
                 } => {
-                    let manager = self.manager.lock().await;
-                    let mut runners = manager.runners.lock().await;
+                    let mut manager = self.manager.lock().await;
 
-                    if let Some(runner) = runners.get_mut(&id) {
+                    let old = manager.runners.lock().await.get_mut(&id).map(|runner| {
                         runner.latency = latency;
-                        runner.stage = stage;
+
+                        std::mem::replace(&mut runner.stage, stage)
+                    });
+
+                    if let Some(task) = manager.runner_tasks.lock().await.get_mut(&id) {
+                        task.last_activity = Instant::now();
+                    }
+
+                    if let Some(old) = old.filter(|old| *old != stage) {
+                        manager.send_stage_update(ShardStageUpdateEvent {
+                            new: stage,
+                            old,
+                            shard_id: id,
+                        });
                     }
                 },
                 ShardManagerMessage::Shutdown(shard_id, code) => {
@@ -113,4 +159,74 @@ impl ShardManagerMonitor {
 
         Ok(())
     }
+
+    /// Restarts the shards which didn't report any activity within the given
+    /// timeout.
+    async fn restart_stuck_shards(&mut self, timeout: Duration) {
+        let mut manager = self.manager.lock().await;
+
+        let stuck = {
+            let tasks = manager.runner_tasks.lock().await;
+            let last_activity = tasks.iter().map(|(id, task)| (*id, task.last_activity));
+
+            stuck_shards(last_activity, timeout, Instant::now())
+        };
+
+        for (shard_id, elapsed) in stuck {
+            warn!(
+                "[ShardMonitor] Restarting shard {}: no heartbeat acknowledgement or stage change for {:?}",
+                shard_id,
+                elapsed,
+            );
+
+            manager.restart(shard_id).await;
+            drop(self.shutdown.unbounded_send(shard_id));
+        }
+    }
+}
+
+/// Returns the shards which didn't report any activity for longer than the
+/// timeout as of `now`, given when each shard last reported activity, along
+/// with how long they have been inactive.
+fn stuck_shards(
+    last_activity: impl IntoIterator<Item = (ShardId, Instant)>,
+    timeout: Duration,
+    now: Instant,
+) -> Vec<(ShardId, Duration)> {
+    last_activity
+        .into_iter()
+        .map(|(shard_id, last_activity)| (shard_id, now.saturating_duration_since(last_activity)))
+        .filter(|(_, elapsed)| *elapsed > timeout)
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use tokio::time::Instant;
+
+    use super::stuck_shards;
+    use crate::client::bridge::gateway::ShardId;
+
+    #[test]
+    fn test_stuck_shards() {
+        let start = Instant::now();
+        let last_activity: Vec<_> =
+            (0..3).map(|id| (ShardId(id), start + Duration::from_secs(id * 10))).collect();
+
+        let timeout = Duration::from_secs(30);
+
+        assert!(stuck_shards(last_activity.clone(), timeout, start).is_empty());
+        assert!(stuck_shards(last_activity.clone(), timeout, start + timeout).is_empty());
+
+        let stuck = stuck_shards(last_activity.clone(), timeout, start + Duration::from_secs(35));
+        assert_eq!(stuck, vec![(ShardId(0), Duration::from_secs(35))]);
+
+        let stuck = stuck_shards(last_activity, timeout, start + Duration::from_secs(45));
+        assert_eq!(stuck, vec![
+            (ShardId(0), Duration::from_secs(45)),
+            (ShardId(1), Duration::from_secs(35)),
+        ]);
+    }
 }
@@ -10,7 +10,7 @@ use crate::collector::{
     ModalInteractionFilter,
     ReactionFilter,
 };
-use crate::gateway::InterMessage;
+use crate::gateway::{CreatePresence, InterMessage};
 use crate::model::prelude::*;
 
 /// A lightweight wrapper around an mpsc sender.
@@ -184,6 +184,36 @@ impl ShardMessenger {
         drop(self.send_to_shard(ShardRunnerMessage::SetPresence(status, activity)));
     }
 
+    /// Sets the user's full presence information, including multiple
+    /// activities and whether the user is AFK.
+    ///
+    /// Refer to [`CreatePresence`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Set the current user as idle, playing `"Heroes of the Storm"` and
+    /// listening to `"Spotify"`:
+    ///
+    /// ```rust,ignore
+    /// use serenity::model::gateway::Activity;
+    /// use serenity::model::user::OnlineStatus;
+    ///
+    /// shard.set_presence_with(|p| {
+    ///     p.status(OnlineStatus::Idle)
+    ///         .activity(Activity::playing("Heroes of the Storm"))
+    ///         .activity(Activity::listening("Spotify"))
+    /// });
+    /// ```
+    pub fn set_presence_with<F>(&self, f: F)
+    where
+        F: FnOnce(&mut CreatePresence) -> &mut CreatePresence,
+    {
+        let mut presence = CreatePresence::default();
+        f(&mut presence);
+
+        drop(self.tx.unbounded_send(InterMessage::SetPresence(presence)));
+    }
+
     /// Sets the user's current online status.
     ///
     /// Note that [`Offline`] is not a valid online status, so it is
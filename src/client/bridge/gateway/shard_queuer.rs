@@ -1,4 +1,4 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::sync::Arc;
 
 use futures::channel::mpsc::{UnboundedReceiver as Receiver, UnboundedSender as Sender};
@@ -17,20 +17,41 @@ use super::{
     ShardRunner,
     ShardRunnerInfo,
     ShardRunnerOptions,
+    ShardRunnerTask,
 };
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{ConnectionStage, InterMessage, Shard};
+use crate::gateway::{ConnectionStage, InterMessage, PresenceRateLimit, Shard};
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
 use crate::model::gateway::GatewayIntents;
+use crate::model::id::GuildId;
 use crate::CacheAndHttp;
 
 const WAIT_BETWEEN_BOOTS_IN_SECONDS: u64 = 5;
 
+/// The settings of the shards managed by a [`ShardManager`] and started by
+/// a [`ShardQueuer`], as set on the [`ClientBuilder`].
+///
+/// [`ShardManager`]: super::ShardManager
+///
+/// [`ClientBuilder`]: crate::client::ClientBuilder
+#[derive(Clone, Default)]
+pub(crate) struct ShardSettings {
+    /// The rate limit applied to the presence updates of each shard.
+    pub(crate) presence_rate_limit: PresenceRateLimit,
+    /// The maximum size of a decompressed gateway payload of the shards.
+    pub(crate) max_payload_size: Option<usize>,
+    /// The guilds whose events are dispatched, if restricted.
+    pub(crate) event_guild_allowlist: Option<Arc<HashSet<GuildId>>>,
+    /// How long a shard may go without reporting activity before it is
+    /// restarted, if at all.
+    pub(crate) watchdog_timeout: Option<Duration>,
+}
+
 /// The shard queuer is a simple loop that runs indefinitely to manage the
 /// startup of shards.
 ///
@@ -102,6 +123,19 @@ impl ShardQueuer {
     /// nature of the loop.
     #[instrument(skip(self))]
     pub async fn run(&mut self) {
+        self.run_with_settings(ShardSettings::default(), Arc::default()).await;
+    }
+
+    /// Begins the shard queuer loop, starting the shards with the given
+    /// settings and tracking the tasks running their runners in `tasks`.
+    ///
+    /// Refer to [`Self::run`] for more information.
+    #[instrument(skip(self, settings, tasks))]
+    pub(crate) async fn run_with_settings(
+        &mut self,
+        settings: ShardSettings,
+        tasks: Arc<Mutex<HashMap<ShardId, ShardRunnerTask>>>,
+    ) {
         // The duration to timeout from reads over the Rx channel. This can be
         // done in a loop, and if the read times out then a shard can be
         // started if one is presently waiting in the queue.
@@ -121,12 +155,12 @@ impl ShardQueuer {
                 },
                 Ok(Some(ShardQueuerMessage::Start(id, total))) => {
                     debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
-                    self.checked_start(id.0, total.0).await;
+                    self.checked_start(id.0, total.0, &settings, &tasks).await;
                 },
                 Ok(None) => break,
                 Err(_) => {
                     if let Some((id, total)) = self.queue.pop_front() {
-                        self.checked_start(id, total).await;
+                        self.checked_start(id, total, &settings, &tasks).await;
                     }
                 },
             }
@@ -154,12 +188,18 @@ impl ShardQueuer {
         sleep(to_sleep).await;
     }
 
-    #[instrument(skip(self))]
-    async fn checked_start(&mut self, id: u64, total: u64) {
+    #[instrument(skip(self, settings, tasks))]
+    async fn checked_start(
+        &mut self,
+        id: u64,
+        total: u64,
+        settings: &ShardSettings,
+        tasks: &Mutex<HashMap<ShardId, ShardRunnerTask>>,
+    ) {
         debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
         self.check_last_start().await;
 
-        if let Err(why) = self.start(id, total).await {
+        if let Err(why) = self.start(id, total, settings, tasks).await {
             warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
             info!("[Shard Queuer] Re-queueing start of shard {}", id);
 
@@ -169,8 +209,14 @@ impl ShardQueuer {
         self.last_start = Some(Instant::now());
     }
 
-    #[instrument(skip(self))]
-    async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
+    #[instrument(skip(self, settings, tasks))]
+    async fn start(
+        &mut self,
+        shard_id: u64,
+        shard_total: u64,
+        settings: &ShardSettings,
+        tasks: &Mutex<HashMap<ShardId, ShardRunnerTask>>,
+    ) -> Result<()> {
         let shard_info = [shard_id, shard_total];
 
         let mut shard = Shard::new(
@@ -182,6 +228,8 @@ impl ShardQueuer {
         .await?;
 
         shard.set_http(Arc::clone(&self.cache_and_http.http));
+        shard.set_presence_rate_limit(settings.presence_rate_limit);
+        shard.set_max_payload_size(settings.max_payload_size);
 
         let mut runner = ShardRunner::new(ShardRunnerOptions {
             data: Arc::clone(&self.data),
@@ -196,18 +244,21 @@ impl ShardQueuer {
             cache_and_http: Arc::clone(&self.cache_and_http),
         });
 
+        runner.set_event_guild_allowlist(settings.event_guild_allowlist.clone());
+
         let runner_info = ShardRunnerInfo {
             latency: None,
             runner_tx: ShardMessenger::new(runner.runner_tx()),
             stage: ConnectionStage::Disconnected,
         };
 
-        spawn_named("shard_queuer::stop", async move {
+        let task = spawn_named("shard_queuer::stop", async move {
             drop(runner.run().await);
             debug!("[ShardRunner {:?}] Stopping", runner.shard.shard_info());
         });
 
         self.runners.lock().await.insert(ShardId(shard_id), runner_info);
+        tasks.lock().await.insert(ShardId(shard_id), ShardRunnerTask::new(task));
 
         Ok(())
     }
@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use async_tungstenite::tungstenite;
@@ -8,6 +9,7 @@ use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSende
 use futures::{SinkExt, StreamExt};
 use serde::Deserialize;
 use tokio::sync::RwLock;
+use tokio::time::timeout_at;
 use tracing::{debug, error, info, instrument, trace, warn};
 use typemap_rev::TypeMap;
 
@@ -15,6 +17,8 @@ use super::event::{ClientEvent, ShardStageUpdateEvent};
 use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
+#[cfg(feature = "collector")]
+use crate::client::dispatch::is_guild_allowed;
 use crate::client::dispatch::{dispatch, DispatchEvent};
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "collector")]
@@ -35,6 +39,7 @@ use crate::internal::ws_impl::{ReceiverExt, SenderExt};
 #[cfg(feature = "collector")]
 use crate::model::application::interaction::Interaction;
 use crate::model::event::{Event, GatewayEvent};
+use crate::model::id::GuildId;
 use crate::CacheAndHttp;
 
 /// A runner for managing a [`Shard`] and its respective WebSocket client.
@@ -53,6 +58,7 @@ pub struct ShardRunner {
     #[cfg(feature = "voice")]
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     cache_and_http: Arc<CacheAndHttp>,
+    event_guild_allowlist: Option<Arc<HashSet<GuildId>>>,
     #[cfg(feature = "collector")]
     event_filters: Vec<EventFilter>,
     #[cfg(feature = "collector")]
@@ -83,6 +89,7 @@ impl ShardRunner {
             #[cfg(feature = "voice")]
             voice_manager: opt.voice_manager,
             cache_and_http: opt.cache_and_http,
+            event_guild_allowlist: None,
             #[cfg(feature = "collector")]
             event_filters: Vec::new(),
             #[cfg(feature = "collector")]
@@ -136,6 +143,14 @@ impl ShardRunner {
                 return self.request_restart().await;
             }
 
+            if let Err(why) = self.shard.update_pending_presence().await {
+                warn!(
+                    "[ShardRunner {:?}] Error sending delayed presence update: {:?}",
+                    self.shard.shard_info(),
+                    why
+                );
+            }
+
             let pre = self.shard.stage();
             let (event, action, successful) = self.recv_event().await?;
             let post = self.shard.stage();
@@ -185,6 +200,10 @@ impl ShardRunner {
 
             if let Some(event) = event {
                 #[cfg(feature = "collector")]
+                if self
+                    .event_guild_allowlist
+                    .as_ref()
+                    .map_or(true, |allowlist| is_guild_allowed(&event, allowlist))
                 {
                     self.handle_filters(&event);
                 }
@@ -243,6 +262,15 @@ impl ShardRunner {
         retain_mut(&mut self.event_filters, |f| f.send_event(&mut event));
     }
 
+    /// Sets the guilds whose events are dispatched and passed to collectors.
+    ///
+    /// Refer to [`ClientBuilder::event_guild_allowlist`] for more information.
+    ///
+    /// [`ClientBuilder::event_guild_allowlist`]: crate::client::ClientBuilder::event_guild_allowlist
+    pub(crate) fn set_event_guild_allowlist(&mut self, allowlist: Option<Arc<HashSet<GuildId>>>) {
+        self.event_guild_allowlist = allowlist;
+    }
+
     /// Clones the internal copy of the Sender to the shard runner.
     pub(super) fn runner_tx(&self) -> Sender<InterMessage> {
         self.runner_tx.clone()
@@ -336,6 +364,7 @@ impl ShardRunner {
             &self.runner_tx,
             self.shard.shard_info()[0],
             Arc::clone(&self.cache_and_http),
+            &self.event_guild_allowlist,
         )
         .await;
     }
@@ -469,6 +498,11 @@ impl ShardRunner {
                 // Value must be forwarded over the websocket
                 self.shard.client.send_json(&value).await.is_ok()
             },
+            InterMessage::SetPresence(presence) => {
+                self.shard.set_full_presence(presence);
+
+                self.shard.update_presence().await.is_ok()
+            },
         }
     }
 
@@ -537,7 +571,17 @@ impl ShardRunner {
     /// present event was successful.
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
-        let gw_event = match self.shard.client.recv_json().await {
+        // Stop waiting for an event once a delayed presence update is due, so
+        // that it is sent on time even if no events are received.
+        let presence_due = self.shard.pending_presence_due();
+        let max_payload_size = self.shard.max_payload_size();
+        let received = self.shard.client.recv_json(max_payload_size);
+        let received = match presence_due {
+            Some(due) => timeout_at(due.into(), received).await.unwrap_or(Ok(None)),
+            None => received.await,
+        };
+
+        let gw_event = match received {
             Ok(Some(value)) => GatewayEvent::deserialize(value).map(Some).map_err(From::from),
             Ok(None) => Ok(None),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
@@ -11,7 +11,7 @@ use crate::client::bridge::gateway::ShardMessenger;
 #[cfg(feature = "collector")]
 use crate::collector::{ComponentInteractionFilter, MessageFilter, ReactionFilter};
 #[cfg(feature = "gateway")]
-use crate::gateway::InterMessage;
+use crate::gateway::{CreatePresence, InterMessage};
 use crate::http::Http;
 use crate::model::prelude::*;
 
@@ -398,6 +398,48 @@ impl Context {
         self.shard.set_presence(activity, status);
     }
 
+    /// Sets the current user's full presence, including multiple activities
+    /// and whether the user is AFK.
+    ///
+    /// Refer to [`CreatePresence`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Setting the current user as idle, playing `"Heroes of the Storm"` and
+    /// listening to `"Spotify"`:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::gateway::Ready;
+    /// #
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn ready(&self, context: Context, _: Ready) {
+    ///         use serenity::model::gateway::Activity;
+    ///         use serenity::model::user::OnlineStatus;
+    ///
+    ///         context
+    ///             .set_presence_with(|p| {
+    ///                 p.status(OnlineStatus::Idle)
+    ///                     .activity(Activity::playing("Heroes of the Storm"))
+    ///                     .activity(Activity::listening("Spotify"))
+    ///             })
+    ///             .await;
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "gateway")]
+    #[allow(clippy::unused_async)]
+    #[inline]
+    pub async fn set_presence_with<F>(&self, f: F)
+    where
+        F: FnOnce(&mut CreatePresence) -> &mut CreatePresence,
+    {
+        self.shard.set_presence_with(f);
+    }
+
     /// Sets a new `filter` for the shard to check if a message event shall be
     /// sent back to `filter`'s paired receiver.
     #[cfg(feature = "collector")]
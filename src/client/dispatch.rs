@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 #[cfg(feature = "cache")]
 use std::fmt;
 use std::sync::Arc;
@@ -22,8 +23,9 @@ use crate::http::Http;
 use crate::internal::tokio::spawn_named;
 use crate::model::channel::{Channel, Message};
 use crate::model::event::Event;
+#[cfg(feature = "model")]
+use crate::model::event::RelatedId;
 use crate::model::guild::Member;
-#[cfg(feature = "cache")]
 use crate::model::id::GuildId;
 use crate::CacheAndHttp;
 
@@ -149,6 +151,9 @@ impl DispatchEvent {
             Self::Model(Event::ThreadDelete(ref mut event)) => {
                 update(cache_and_http, event);
             },
+            Self::Model(Event::ThreadMembersUpdate(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             _ => (),
         }
     }
@@ -165,8 +170,37 @@ pub(crate) fn dispatch<'rec>(
     runner_tx: &'rec Sender<InterMessage>,
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
+    event_guild_allowlist: &'rec Option<Arc<HashSet<GuildId>>>,
 ) -> BoxFuture<'rec, ()> {
     async move {
+        if let DispatchEvent::Model(Event::Ready(event)) = &event {
+            // Tracked regardless of the cache, so that the IDs are available
+            // without it.
+            let http = &cache_and_http.http;
+            http.set_current_user_id(event.ready.user.id.0);
+
+            if http.application_id().is_none() {
+                http.set_application_id(event.ready.application.id.0);
+            }
+        }
+
+        #[cfg(feature = "model")]
+        if let (DispatchEvent::Model(model_event), Some(allowlist)) =
+            (&mut event, event_guild_allowlist)
+        {
+            if !is_guild_allowed(model_event, allowlist) {
+                // Messages are not cached by `DispatchEvent::update`, but
+                // when dispatched, so cache them here instead.
+                if let Event::MessageCreate(message_event) = model_event {
+                    update(&cache_and_http, message_event);
+                } else {
+                    event.update(&cache_and_http);
+                }
+
+                return;
+            }
+        }
+
         match (event_handler, raw_event_handler) {
             (None, None) => {
                 event.update(&cache_and_http);
@@ -312,6 +346,17 @@ pub(crate) fn dispatch<'rec>(
     .boxed()
 }
 
+/// Whether an event is related to one of the allowed guilds, or to no guild
+/// at all.
+#[cfg(feature = "model")]
+pub(crate) fn is_guild_allowed(event: &Event, allowlist: &HashSet<GuildId>) -> bool {
+    match event.guild_id() {
+        RelatedId::Some(guild_id) => allowlist.contains(&guild_id),
+        RelatedId::Multiple(guild_ids) => guild_ids.iter().any(|id| allowlist.contains(id)),
+        RelatedId::Never | RelatedId::None => true,
+    }
+}
+
 async fn dispatch_message(
     context: Context,
     mut message: Message,
@@ -540,9 +585,15 @@ async fn handle_event(
 
             spawn_named("dispatch::event_handler::guild_member_update", async move {
                 feature_cache! {{
-                    if let Some(after) = _after {
-                        event_handler.guild_member_update(context, _before, after).await;
+                    if let Some(after) = _after.clone() {
+                        event_handler
+                            .guild_member_update(context.clone(), _before.clone(), after)
+                            .await;
                     }
+
+                    event_handler
+                        .guild_member_update_with_event(context, _before, _after, event)
+                        .await;
                 } else {
                     event_handler.guild_member_update(context, event).await;
                 }}
@@ -652,6 +703,16 @@ async fn handle_event(
                 }}
             });
         },
+        Event::MessagePollVoteAdd(event) => {
+            spawn_named("dispatch::event_handler::message_poll_vote_add", async move {
+                event_handler.message_poll_vote_add(context, event).await;
+            });
+        },
+        Event::MessagePollVoteRemove(event) => {
+            spawn_named("dispatch::event_handler::message_poll_vote_remove", async move {
+                event_handler.message_poll_vote_remove(context, event).await;
+            });
+        },
         Event::PresencesReplace(mut event) => {
             update(&cache_and_http, &mut event);
 
@@ -804,7 +865,9 @@ async fn handle_event(
                 event_handler.thread_member_update(context, event.member).await;
             });
         },
-        Event::ThreadMembersUpdate(event) => {
+        Event::ThreadMembersUpdate(mut event) => {
+            update(&cache_and_http, &mut event);
+
             spawn_named("dispatch::event_handler::thread_members_update", async move {
                 event_handler.thread_members_update(context, event).await;
             });
@@ -16,7 +16,9 @@ use crate::model::prelude::*;
 pub trait EventHandler: Send + Sync {
     /// Dispatched when the permissions of an application command was updated.
     ///
-    /// Provides said permission's data.
+    /// Provides said permission's data. If the permissions were updated for
+    /// all commands of the application, [`CommandPermission::applies_to_all_commands`]
+    /// returns `true`.
     async fn application_command_permissions_update(
         &self,
         _ctx: Context,
@@ -83,6 +85,11 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when a channel is updated.
     ///
     /// Provides the old channel data, and the new data.
+    ///
+    /// Refer to [`PermissionOverwriteChanges`] to compare the permission
+    /// overwrites of the old and new channel.
+    ///
+    /// [`PermissionOverwriteChanges`]: crate::model::channel::PermissionOverwriteChanges
     #[cfg(feature = "cache")]
     async fn channel_update(&self, _ctx: Context, _old: Option<Channel>, _new: Channel) {}
 
@@ -213,6 +220,28 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
+    /// Dispatched when a member is updated, like [`Self::guild_member_update`],
+    /// but also when the guild is not cached.
+    ///
+    /// Provides the member's old data and new data (if available), alongside
+    /// the event itself. Comparing the old and new data allows to tell which
+    /// nickname, roles or timeout changed.
+    ///
+    /// The old data is only available if the member was cached before the
+    /// update, and the new data only if the guild is cached.
+    ///
+    /// Note: This event will not trigger unless the "guild members" privileged intent
+    /// is enabled on the bot application page.
+    #[cfg(feature = "cache")]
+    async fn guild_member_update_with_event(
+        &self,
+        _ctx: Context,
+        _old_if_available: Option<Member>,
+        _new: Option<Member>,
+        _event: GuildMemberUpdateEvent,
+    ) {
+    }
+
     /// Dispatched when a member is updated (e.g their nickname is updated).
     ///
     /// Provides the new data.
@@ -364,6 +393,23 @@ pub trait EventHandler: Send + Sync {
     #[cfg(not(feature = "cache"))]
     async fn message_update(&self, _ctx: Context, _new_data: MessageUpdateEvent) {}
 
+    /// Dispatched when a user votes for an answer of a poll.
+    ///
+    /// Provides the ids of the user, the message and the answer voted for.
+    ///
+    /// **Note**: Requires the [`GatewayIntents::GUILD_MESSAGE_POLLS`] or
+    /// [`GatewayIntents::DIRECT_MESSAGE_POLLS`] intent.
+    async fn message_poll_vote_add(&self, _ctx: Context, _event: MessagePollVoteAddEvent) {}
+
+    /// Dispatched when a user removes their vote for an answer of a poll.
+    ///
+    /// Provides the ids of the user, the message and the answer the vote was
+    /// removed from.
+    ///
+    /// **Note**: Requires the [`GatewayIntents::GUILD_MESSAGE_POLLS`] or
+    /// [`GatewayIntents::DIRECT_MESSAGE_POLLS`] intent.
+    async fn message_poll_vote_remove(&self, _ctx: Context, _event: MessagePollVoteRemoveEvent) {}
+
     /// Dispatched when a new reaction is attached to a message.
     ///
     /// Provides the reaction's data.
@@ -25,10 +25,12 @@ mod error;
 #[cfg(feature = "gateway")]
 mod event_handler;
 
+use std::collections::HashSet;
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::{Context as FutContext, Poll};
+use std::time::Duration;
 
 use futures::future::BoxFuture;
 use tokio::sync::{Mutex, RwLock};
@@ -41,6 +43,7 @@ use self::bridge::gateway::{
     ShardManagerError,
     ShardManagerMonitor,
     ShardManagerOptions,
+    ShardSettings,
 };
 #[cfg(feature = "voice")]
 use self::bridge::voice::VoiceGatewayManager;
@@ -49,7 +52,7 @@ pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
 pub use self::event_handler::{EventHandler, RawEventHandler};
 #[cfg(feature = "gateway")]
-use super::gateway::GatewayError;
+use super::gateway::{GatewayError, PresenceRateLimit};
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "cache")]
@@ -60,7 +63,7 @@ use crate::http::Http;
 use crate::internal::prelude::*;
 #[cfg(feature = "gateway")]
 use crate::model::gateway::GatewayIntents;
-use crate::model::id::ApplicationId;
+use crate::model::id::{ApplicationId, GuildId};
 pub use crate::CacheAndHttp;
 
 /// A builder implementing [`Future`] building a [`Client`] to interact with Discord.
@@ -73,6 +76,10 @@ pub struct ClientBuilder {
     http: Option<Http>,
     fut: Option<BoxFuture<'static, Result<Client>>>,
     intents: GatewayIntents,
+    presence_rate_limit: PresenceRateLimit,
+    max_payload_size: Option<usize>,
+    watchdog_timeout: Option<Duration>,
+    event_guild_allowlist: Option<HashSet<GuildId>>,
     #[cfg(feature = "cache")]
     cache_settings: Option<CacheSettings>,
     #[cfg(feature = "framework")]
@@ -91,6 +98,10 @@ impl ClientBuilder {
             http: Some(http),
             fut: None,
             intents,
+            presence_rate_limit: PresenceRateLimit::new(),
+            max_payload_size: None,
+            watchdog_timeout: None,
+            event_guild_allowlist: None,
             #[cfg(feature = "cache")]
             cache_settings: Some(CacheSettings::new()),
             #[cfg(feature = "framework")]
@@ -310,6 +321,85 @@ impl ClientBuilder {
         self.intents
     }
 
+    /// Sets the rate limit applied to the presence updates of each shard, e.g.
+    /// via [`Context::set_activity`].
+    ///
+    /// Presence updates exceeding the rate limit are delayed, only sending the
+    /// most recent presence once the rate limit allows it. By default, this
+    /// follows Discord's limit of 5 updates per 60 seconds.
+    pub fn presence_rate_limit(mut self, rate_limit: PresenceRateLimit) -> Self {
+        self.presence_rate_limit = rate_limit;
+
+        self
+    }
+
+    /// Gets the presence rate limit. See [`Self::presence_rate_limit`] for more
+    /// info.
+    pub fn get_presence_rate_limit(&self) -> PresenceRateLimit {
+        self.presence_rate_limit
+    }
+
+    /// Sets the maximum size in bytes of a decompressed gateway payload.
+    ///
+    /// Payloads exceeding the size are logged and skipped instead of being
+    /// parsed, protecting against excessive memory usage. The events of
+    /// skipped payloads are lost, so the size should be generous; notably,
+    /// the [`GuildCreate`] payloads of large guilds can be several megabytes.
+    /// By default, there is no maximum size.
+    ///
+    /// [`GuildCreate`]: crate::model::event::Event::GuildCreate
+    pub fn max_payload_size(mut self, max_size: usize) -> Self {
+        self.max_payload_size = Some(max_size);
+
+        self
+    }
+
+    /// Gets the maximum payload size. See [`Self::max_payload_size`] for more
+    /// info.
+    pub fn get_max_payload_size(&self) -> Option<usize> {
+        self.max_payload_size
+    }
+
+    /// Restarts shards that received neither a heartbeat acknowledgement nor
+    /// changed their connection stage for the given duration, such as shards
+    /// whose runner is blocked.
+    ///
+    /// As heartbeats are acknowledged about every 41 seconds, the duration
+    /// should be a few minutes. By default, stuck shards are not restarted.
+    pub fn shard_watchdog_timeout(mut self, timeout: Duration) -> Self {
+        self.watchdog_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Gets the shard watchdog timeout. See [`Self::shard_watchdog_timeout`]
+    /// for more info.
+    pub fn get_shard_watchdog_timeout(&self) -> Option<Duration> {
+        self.watchdog_timeout
+    }
+
+    /// Only dispatches the events of the given guilds to the event handlers,
+    /// the framework and the collectors, dropping the events of any other
+    /// guild.
+    ///
+    /// Events unrelated to a guild, such as direct messages, are still
+    /// dispatched. The cache is still updated with the dropped events,
+    /// including messages.
+    ///
+    /// This is useful during development, to only process the events of test
+    /// guilds when sharing a token with a bot running in production guilds.
+    pub fn event_guild_allowlist(mut self, guild_ids: impl IntoIterator<Item = GuildId>) -> Self {
+        self.event_guild_allowlist = Some(guild_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Gets the guilds whose events are dispatched. See
+    /// [`Self::event_guild_allowlist`] for more info.
+    pub fn get_event_guild_allowlist(&self) -> Option<&HashSet<GuildId>> {
+        self.event_guild_allowlist.as_ref()
+    }
+
     /// Sets an event handler with multiple methods for each possible event.
     pub fn event_handler<H: EventHandler + 'static>(mut self, event_handler: H) -> Self {
         self.event_handler = Some(Arc::new(event_handler));
@@ -363,6 +453,12 @@ impl Future for ClientBuilder {
             let event_handler = self.event_handler.take();
             let raw_event_handler = self.raw_event_handler.take();
             let intents = self.intents;
+            let settings = ShardSettings {
+                presence_rate_limit: self.presence_rate_limit,
+                max_payload_size: self.max_payload_size,
+                event_guild_allowlist: self.event_guild_allowlist.take().map(Arc::new),
+                watchdog_timeout: self.watchdog_timeout,
+            };
 
             let mut http = self.http.take().unwrap();
             if let Some(event_handler) = event_handler.clone() {
@@ -391,25 +487,25 @@ impl Future for ClientBuilder {
                     },
                 }));
 
-                let (shard_manager, shard_manager_worker) = {
-                    ShardManager::new(ShardManagerOptions {
-                        data: &data,
-                        event_handler: &event_handler,
-                        raw_event_handler: &raw_event_handler,
-                        #[cfg(feature = "framework")]
-                        framework: &framework,
-                        shard_index: 0,
-                        shard_init: 0,
-                        shard_total: 0,
-                        #[cfg(feature = "voice")]
-                        voice_manager: &voice_manager,
-                        ws_url: &ws_url,
-                        cache_and_http: &cache_and_http,
-                        intents,
-                    })
-                    .await
+                let options = ShardManagerOptions {
+                    data: &data,
+                    event_handler: &event_handler,
+                    raw_event_handler: &raw_event_handler,
+                    #[cfg(feature = "framework")]
+                    framework: &framework,
+                    shard_index: 0,
+                    shard_init: 0,
+                    shard_total: 0,
+                    #[cfg(feature = "voice")]
+                    voice_manager: &voice_manager,
+                    ws_url: &ws_url,
+                    cache_and_http: &cache_and_http,
+                    intents,
                 };
 
+                let (shard_manager, shard_manager_worker) =
+                    ShardManager::new_with_settings(options, settings).await;
+
                 Ok(Client {
                     data,
                     shard_manager,
@@ -761,6 +857,16 @@ impl Client {
     pub async fn start_autosharded(&mut self) -> Result<()> {
         let (x, y) = {
             let res = self.cache_and_http.http.get_bot_gateway().await?;
+            let limit = &res.session_start_limit;
+
+            if limit.remaining < res.shards {
+                tracing::warn!(
+                    "Only {} of {} session starts remaining, resetting in {:?}",
+                    limit.remaining,
+                    limit.total,
+                    limit.reset_after_duration(),
+                );
+            }
 
             (res.shards as u64 - 1, res.shards as u64)
         };
@@ -0,0 +1,256 @@
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context as FutContext, Poll};
+use std::time::Duration;
+
+use futures::stream::Stream;
+
+use crate::client::bridge::gateway::{ChunkGuildFilter, ShardMessenger};
+use crate::collector::{EventCollector, EventCollectorBuilder};
+use crate::model::event::{Event, EventType, GuildMembersChunkEvent};
+use crate::model::id::GuildId;
+use crate::Result;
+
+static NONCE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Requests the members of a guild and builds a stream of the
+/// [`GuildMembersChunkEvent`]s sent in response.
+///
+/// The chunks are correlated to the request via a nonce, which is generated
+/// if none is set.
+#[must_use = "Builders do nothing unless built"]
+pub struct MemberChunkCollectorBuilder {
+    shard: ShardMessenger,
+    guild_id: GuildId,
+    limit: Option<u16>,
+    filter: ChunkGuildFilter,
+    nonce: Option<String>,
+    timeout: Option<Duration>,
+}
+
+impl MemberChunkCollectorBuilder {
+    /// Creates a builder requesting all members of the given guild.
+    ///
+    /// **Note**: Requesting all members requires the `GUILD_MEMBERS` intent.
+    pub fn new(shard_messenger: impl AsRef<ShardMessenger>, guild_id: impl Into<GuildId>) -> Self {
+        Self {
+            shard: shard_messenger.as_ref().clone(),
+            guild_id: guild_id.into(),
+            limit: None,
+            filter: ChunkGuildFilter::None,
+            nonce: None,
+            timeout: None,
+        }
+    }
+
+    /// Limits how many members will be requested.
+    pub fn limit(mut self, limit: u16) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    /// Sets which members will be requested.
+    pub fn filter(mut self, filter: ChunkGuildFilter) -> Self {
+        self.filter = filter;
+
+        self
+    }
+
+    /// Sets the nonce to identify the chunks with.
+    ///
+    /// **Note**: Discord limits the nonce to 32 bytes.
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+
+        self
+    }
+
+    /// Sets a `duration` for how long the collector shall wait for all chunks
+    /// to arrive.
+    pub fn timeout(mut self, duration: Duration) -> Self {
+        self.timeout = Some(duration);
+
+        self
+    }
+
+    /// Sends the request and builds the [`MemberChunkCollector`] receiving its
+    /// chunks.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Collector`] if the underlying [`EventCollector`] fails
+    /// to build.
+    ///
+    /// [`Error::Collector`]: crate::Error::Collector
+    pub fn build(self) -> Result<MemberChunkCollector> {
+        let nonce = self.nonce.unwrap_or_else(|| {
+            format!("serenity-{}", NONCE_COUNTER.fetch_add(1, Ordering::Relaxed))
+        });
+
+        let filter_nonce = nonce.clone();
+        let mut builder = EventCollectorBuilder::new(&self.shard)
+            .add_event_type(EventType::GuildMembersChunk)
+            .add_guild_id(self.guild_id)
+            .filter(move |event| {
+                matches!(&**event, Event::GuildMembersChunk(chunk)
+                    if chunk.nonce.as_deref() == Some(filter_nonce.as_str()))
+            });
+
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(timeout);
+        }
+
+        let collector = builder.build()?;
+
+        self.shard.chunk_guild(self.guild_id, self.limit, self.filter, Some(nonce));
+
+        Ok(MemberChunkCollector {
+            collector: Some(collector),
+        })
+    }
+}
+
+/// A member chunk collector yields the [`GuildMembersChunkEvent`]s answering a
+/// single members request as they arrive.
+///
+/// The stream ends after the last chunk has been received, or once the
+/// timeout elapsed. Each chunk's [`chunk_index`] and [`chunk_count`] can be
+/// used to track the progress.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::client::bridge::gateway::ShardMessenger;
+/// # use serenity::model::id::GuildId;
+/// # async fn run(shard: ShardMessenger) -> serenity::Result<()> {
+/// use std::time::Duration;
+///
+/// use serenity::collector::MemberChunkCollectorBuilder;
+/// use serenity::futures::StreamExt;
+///
+/// let mut chunks = MemberChunkCollectorBuilder::new(&shard, GuildId(81384788765712384))
+///     .timeout(Duration::from_secs(60))
+///     .build()?;
+///
+/// while let Some(chunk) = chunks.next().await {
+///     println!("Received chunk {} of {}", chunk.chunk_index + 1, chunk.chunk_count);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`chunk_index`]: GuildMembersChunkEvent::chunk_index
+/// [`chunk_count`]: GuildMembersChunkEvent::chunk_count
+pub struct MemberChunkCollector {
+    /// The collector receiving the chunks, taken once the last one arrived.
+    collector: Option<EventCollector>,
+}
+
+impl MemberChunkCollector {
+    /// Stops collecting, this will implicitly be done once the
+    /// collector drops.
+    /// In case the drop does not appear until later, it is preferred to
+    /// stop the collector early.
+    pub fn stop(mut self) {
+        self.finish();
+    }
+
+    fn finish(&mut self) {
+        if let Some(collector) = self.collector.take() {
+            collector.stop();
+        }
+    }
+}
+
+impl Stream for MemberChunkCollector {
+    type Item = GuildMembersChunkEvent;
+
+    fn poll_next(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let collector = match self.collector.as_mut() {
+                Some(collector) => collector,
+                None => return Poll::Ready(None),
+            };
+
+            match Pin::new(collector).poll_next(ctx) {
+                Poll::Ready(Some(event)) => {
+                    if let Event::GuildMembersChunk(chunk) = &*event {
+                        // Stop listening for events once the last chunk arrived.
+                        if chunk.chunk_index + 1 >= chunk.chunk_count {
+                            self.finish();
+                        }
+
+                        return Poll::Ready(Some(chunk.clone()));
+                    }
+                },
+                Poll::Ready(None) => {
+                    self.finish();
+
+                    return Poll::Ready(None);
+                },
+                Poll::Pending => return Poll::Pending,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use futures::channel::mpsc::unbounded;
+    use futures::StreamExt;
+
+    use super::*;
+    use crate::client::bridge::gateway::{ShardClientMessage, ShardRunnerMessage};
+    use crate::collector::LazyArc;
+    use crate::gateway::InterMessage;
+
+    fn chunk(nonce: &str, chunk_index: u32) -> Event {
+        Event::GuildMembersChunk(GuildMembersChunkEvent {
+            guild_id: GuildId(1),
+            members: HashMap::new(),
+            chunk_index,
+            chunk_count: 2,
+            nonce: Some(nonce.to_string()),
+        })
+    }
+
+    #[tokio::test]
+    async fn test_collect_chunks_until_last() {
+        let (sender, mut receiver) = unbounded();
+        let msg = ShardMessenger::new(sender);
+
+        let mut collector =
+            MemberChunkCollectorBuilder::new(&msg, GuildId(1)).nonce("request").build().unwrap();
+
+        let mut filter = match receiver.next().await {
+            Some(InterMessage::Client(message)) => match *message {
+                ShardClientMessage::Runner(ShardRunnerMessage::SetEventFilter(filter)) => filter,
+                _ => panic!("expected an event filter"),
+            },
+            _ => panic!("expected an event filter"),
+        };
+
+        assert!(matches!(
+            receiver.next().await,
+            Some(InterMessage::Client(message)) if matches!(
+                &*message,
+                ShardClientMessage::Runner(ShardRunnerMessage::ChunkGuild { nonce, .. })
+                    if nonce.as_deref() == Some("request")
+            )
+        ));
+
+        for event in [chunk("other", 0), chunk("request", 0), chunk("request", 1)] {
+            filter.send_event(&mut LazyArc::new(&event));
+        }
+
+        assert_eq!(collector.next().await.map(|c| c.chunk_index), Some(0));
+        assert_eq!(collector.next().await.map(|c| c.chunk_index), Some(1));
+        assert!(collector.next().await.is_none());
+
+        // The event collector is stopped after the last chunk.
+        assert!(!filter.send_event(&mut LazyArc::new(&chunk("request", 1))));
+    }
+}
@@ -9,12 +9,14 @@ pub use error::Error as CollectorError;
 
 pub mod component_interaction_collector;
 pub mod event_collector;
+pub mod member_chunk_collector;
 pub mod message_collector;
 pub mod modal_interaction_collector;
 pub mod reaction_collector;
 
 pub use component_interaction_collector::*;
 pub use event_collector::*;
+pub use member_chunk_collector::*;
 pub use message_collector::*;
 pub use modal_interaction_collector::*;
 pub use reaction_collector::*;
@@ -16,7 +16,7 @@ use tokio::time::{sleep, Sleep};
 
 use crate::client::bridge::gateway::ShardMessenger;
 use crate::collector::LazyArc;
-use crate::model::channel::Reaction;
+use crate::model::channel::{Reaction, ReactionType};
 use crate::model::id::UserId;
 
 macro_rules! impl_reaction_collector {
@@ -70,6 +70,16 @@ macro_rules! impl_reaction_collector {
                     self
                 }
 
+                /// Sets the emoji the reaction must use.
+                /// If a reaction uses a different emoji, it won't be received.
+                ///
+                /// Custom emojis are compared by their Id only.
+                pub fn emoji(mut self, emoji: impl Into<ReactionType>) -> Self {
+                    self.filter.as_mut().unwrap().emoji = Some(emoji.into());
+
+                    self
+                }
+
                 /// Sets the guild in which the reaction must occur.
                 /// If a reaction is not on a message with this guild ID, it won't be received.
                 pub fn guild_id(mut self, guild_id: impl Into<u64>) -> Self {
@@ -238,6 +248,11 @@ impl ReactionFilter {
         self.options.guild_id.map_or(true, |id| Some(id) == reaction.guild_id.map(|g| g.0))
             && self.options.message_id.map_or(true, |id| id == reaction.message_id.0)
             && self.options.channel_id.map_or(true, |id| id == reaction.channel_id.0)
+            && self
+                .options
+                .emoji
+                .as_ref()
+                .map_or(true, |emoji| is_same_emoji(emoji, &reaction.emoji))
             && self
                 .options
                 .author_id
@@ -254,6 +269,20 @@ impl ReactionFilter {
     }
 }
 
+fn is_same_emoji(expected: &ReactionType, emoji: &ReactionType) -> bool {
+    match (expected, emoji) {
+        (
+            ReactionType::Custom {
+                id: expected, ..
+            },
+            ReactionType::Custom {
+                id, ..
+            },
+        ) => expected == id,
+        _ => expected == emoji,
+    }
+}
+
 #[derive(Clone)]
 struct FilterOptions {
     filter_limit: Option<u32>,
@@ -263,6 +292,7 @@ struct FilterOptions {
     guild_id: Option<u64>,
     author_id: Option<u64>,
     message_id: Option<u64>,
+    emoji: Option<ReactionType>,
     accept_added: bool,
     accept_removed: bool,
 }
@@ -277,6 +307,7 @@ impl Default for FilterOptions {
             guild_id: None,
             author_id: None,
             message_id: None,
+            emoji: None,
             accept_added: true,
             accept_removed: false,
         }
@@ -376,6 +407,7 @@ impl fmt::Debug for FilterOptions {
             .field("channel_id", &self.channel_id)
             .field("guild_id", &self.guild_id)
             .field("author_id", &self.author_id)
+            .field("emoji", &self.emoji)
             .finish()
     }
 }
@@ -418,3 +450,48 @@ impl Drop for ReactionCollector {
         self.receiver.close();
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json, Value};
+    use crate::model::id::EmojiId;
+
+    fn reaction(emoji: Value) -> Reaction {
+        from_value(json!({
+            "channel_id": "1",
+            "message_id": "2",
+            "user_id": "3",
+            "emoji": emoji,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_emoji_constraint() {
+        let (filter, _receiver) = ReactionFilter::new(FilterOptions {
+            emoji: Some(ReactionType::Custom {
+                animated: false,
+                id: EmojiId(4),
+                name: None,
+            }),
+            ..FilterOptions::default()
+        });
+
+        let custom = reaction(json!({ "id": "4", "name": "yes" }));
+        let other = reaction(json!({ "id": "5", "name": "yes" }));
+        let unicode = reaction(json!({ "id": null, "name": "✅" }));
+
+        assert!(filter.is_passing_constraints(&mut LazyReactionAction::new(&custom, true)));
+        assert!(!filter.is_passing_constraints(&mut LazyReactionAction::new(&other, true)));
+        assert!(!filter.is_passing_constraints(&mut LazyReactionAction::new(&unicode, true)));
+
+        let (filter, _receiver) = ReactionFilter::new(FilterOptions {
+            emoji: Some('✅'.into()),
+            ..FilterOptions::default()
+        });
+
+        assert!(filter.is_passing_constraints(&mut LazyReactionAction::new(&unicode, true)));
+        assert!(!filter.is_passing_constraints(&mut LazyReactionAction::new(&custom, true)));
+    }
+}
@@ -9,6 +9,48 @@ pub const EMBED_MAX_COUNT: usize = 10;
 /// The maximum number of stickers in a message.
 pub const STICKER_MAX_COUNT: usize = 3;
 
+/// The maximum number of action rows in a message.
+pub const ACTION_ROW_MAX_COUNT: usize = 5;
+
+/// The maximum number of buttons in an action row.
+pub const BUTTON_MAX_COUNT: usize = 5;
+
+/// The maximum length of an application's description.
+pub const APPLICATION_DESCRIPTION_MAX_LENGTH: usize = 400;
+
+/// The maximum number of tags of an application.
+pub const APPLICATION_TAG_MAX_COUNT: usize = 5;
+
+/// The maximum length of the text of a custom status.
+pub const CUSTOM_STATUS_MAX_LENGTH: usize = 128;
+
+/// The maximum number of activities in a presence update.
+pub const PRESENCE_ACTIVITY_MAX_COUNT: usize = 5;
+
+/// The maximum number of choices in an autocomplete response.
+pub const AUTOCOMPLETE_CHOICE_MAX_COUNT: usize = 25;
+
+/// The maximum number of role connection metadata records of an application.
+pub const ROLE_CONNECTION_METADATA_MAX_COUNT: usize = 5;
+
+/// The maximum length of a role connection metadata record's key.
+pub const ROLE_CONNECTION_METADATA_KEY_MAX_LENGTH: usize = 50;
+
+/// The maximum number of tags applied to a thread of a forum channel.
+pub const FORUM_THREAD_TAG_MAX_COUNT: usize = 5;
+
+/// The maximum size of an emoji image, in bytes.
+pub const EMOJI_IMAGE_MAX_SIZE: usize = 256 * 1024;
+
+/// The maximum length of an emoji name.
+pub const EMOJI_NAME_MAX_LENGTH: usize = 32;
+
+/// The maximum slowmode of a channel or thread, in seconds.
+pub const RATE_LIMIT_PER_USER_MAX: u64 = 21600;
+
+/// The maximum age of messages that can be bulk deleted, in seconds.
+pub const BULK_DELETE_MAX_AGE: i64 = 14 * 24 * 60 * 60;
+
 /// The gateway version used by the library. The gateway URL is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 10;
@@ -22,6 +64,15 @@ pub const MESSAGE_CODE_LIMIT: usize = 2000;
 /// The maximum number of members the bot can fetch at once
 pub const MEMBER_FETCH_LIMIT: u64 = 1000;
 
+/// The maximum number of thread members the bot can fetch at once
+pub const THREAD_MEMBER_FETCH_LIMIT: u64 = 100;
+
+/// The maximum number of scheduled event users the bot can fetch at once
+pub const SCHEDULED_EVENT_USER_FETCH_LIMIT: u64 = 100;
+
+/// The maximum number of guilds of the current user the bot can fetch at once
+pub const CURRENT_USER_GUILD_FETCH_LIMIT: u64 = 200;
+
 /// The [UserAgent] sent along with every request.
 ///
 /// [UserAgent]: ::reqwest::header::USER_AGENT
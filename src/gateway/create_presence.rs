@@ -0,0 +1,141 @@
+use crate::constants;
+use crate::model::gateway::Activity;
+use crate::model::user::OnlineStatus;
+
+/// A builder to specify the full presence of a shard, including multiple
+/// activities, for use via [`ShardMessenger::set_presence_with`] or
+/// [`Context::set_presence_with`].
+///
+/// By default, the presence is [`OnlineStatus::Online`] without activities.
+///
+/// At most [`constants::PRESENCE_ACTIVITY_MAX_COUNT`] activities are kept,
+/// any further activity is ignored.
+///
+/// **Note**: Discord only displays the first activity of bot users.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::gateway::CreatePresence;
+/// use serenity::model::gateway::Activity;
+/// use serenity::model::user::OnlineStatus;
+///
+/// let mut presence = CreatePresence::default();
+/// presence
+///     .status(OnlineStatus::Idle)
+///     .activities(vec![Activity::playing("a game"), Activity::listening("music")])
+///     .afk(true);
+///
+/// assert_eq!(presence.get_activities().len(), 2);
+/// ```
+///
+/// [`ShardMessenger::set_presence_with`]: crate::client::bridge::gateway::ShardMessenger::set_presence_with
+/// [`Context::set_presence_with`]: crate::client::Context::set_presence_with
+#[derive(Clone, Debug, Default)]
+pub struct CreatePresence {
+    activities: Vec<Activity>,
+    status: OnlineStatus,
+    afk: bool,
+}
+
+impl CreatePresence {
+    /// Sets the online status.
+    ///
+    /// Note that [`OnlineStatus::Offline`] is not a valid online status, so
+    /// it is converted to [`OnlineStatus::Invisible`].
+    pub fn status(&mut self, mut status: OnlineStatus) -> &mut Self {
+        if status == OnlineStatus::Offline {
+            status = OnlineStatus::Invisible;
+        }
+
+        self.status = status;
+        self
+    }
+
+    /// Adds an activity to the presence.
+    ///
+    /// The activity is ignored if the presence already has
+    /// [`constants::PRESENCE_ACTIVITY_MAX_COUNT`] activities.
+    pub fn activity(&mut self, activity: Activity) -> &mut Self {
+        if self.activities.len() < constants::PRESENCE_ACTIVITY_MAX_COUNT {
+            self.activities.push(activity);
+        }
+
+        self
+    }
+
+    /// Sets the activities of the presence, replacing any previously added
+    /// activity.
+    ///
+    /// Only the first [`constants::PRESENCE_ACTIVITY_MAX_COUNT`] activities
+    /// are kept.
+    pub fn activities(&mut self, activities: impl IntoIterator<Item = Activity>) -> &mut Self {
+        self.activities =
+            activities.into_iter().take(constants::PRESENCE_ACTIVITY_MAX_COUNT).collect();
+        self
+    }
+
+    /// Sets whether the shard is AFK, which affects whether push
+    /// notifications are sent to mobile devices.
+    pub fn afk(&mut self, afk: bool) -> &mut Self {
+        self.afk = afk;
+        self
+    }
+
+    /// Gets the online status.
+    #[must_use]
+    pub fn get_status(&self) -> OnlineStatus {
+        self.status
+    }
+
+    /// Gets the activities.
+    #[must_use]
+    pub fn get_activities(&self) -> &[Activity] {
+        &self.activities
+    }
+
+    /// Gets whether the shard is AFK.
+    #[must_use]
+    pub fn get_afk(&self) -> bool {
+        self.afk
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::CreatePresence;
+    use crate::constants;
+    use crate::model::gateway::Activity;
+    use crate::model::user::OnlineStatus;
+
+    #[test]
+    fn test_create_presence() {
+        let mut presence = CreatePresence::default();
+        assert_eq!(presence.get_status(), OnlineStatus::Online);
+
+        presence
+            .status(OnlineStatus::Offline)
+            .activity(Activity::playing("a"))
+            .activity(Activity::playing("b"));
+        assert_eq!(presence.get_status(), OnlineStatus::Invisible);
+        assert_eq!(presence.get_activities().len(), 2);
+
+        presence.activities(None).afk(true);
+        assert!(presence.get_activities().is_empty());
+        assert!(presence.get_afk());
+    }
+
+    #[test]
+    fn test_create_presence_activity_max_count() {
+        let max = constants::PRESENCE_ACTIVITY_MAX_COUNT;
+        let mut presence = CreatePresence::default();
+
+        presence.activities((0..=max).map(|i| Activity::playing(i.to_string())));
+        assert_eq!(presence.get_activities().len(), max);
+        assert_eq!(presence.get_activities().last().unwrap().name, (max - 1).to_string());
+
+        presence.activity(Activity::playing("ignored"));
+        assert_eq!(presence.get_activities().len(), max);
+        assert_ne!(presence.get_activities().last().unwrap().name, "ignored");
+    }
+}
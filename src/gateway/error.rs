@@ -3,6 +3,8 @@ use std::fmt;
 
 use async_tungstenite::tungstenite::protocol::CloseFrame;
 
+use crate::model::gateway::GatewayIntents;
+
 /// An error that occurred while attempting to deal with the gateway.
 ///
 /// Note that - from a user standpoint - there should be no situation in which
@@ -52,6 +54,15 @@ pub enum Error {
     ///
     /// If an connection has been established but privileged gateway intents
     /// were provided without enabling them prior.
+    ///
+    /// At least one of the provided [privileged intents] is not enabled for
+    /// the bot. The intents that were provided can be retrieved with
+    /// [`ShardManager::intents`]. Privileged intents must be enabled in the
+    /// [Developer Portal], and require approval for verified bots.
+    ///
+    /// [privileged intents]: crate::model::gateway::GatewayIntents::privileged
+    /// [`ShardManager::intents`]: crate::client::bridge::gateway::ShardManager::intents
+    /// [Developer Portal]: https://discord.com/developers/applications
     DisallowedGatewayIntents,
 }
 
@@ -71,11 +82,28 @@ impl fmt::Display for Error {
             Self::OverloadedShard => f.write_str("Shard has too many guilds"),
             Self::ReconnectFailure => f.write_str("Failed to Reconnect"),
             Self::InvalidGatewayIntents => f.write_str("Invalid gateway intents were provided"),
-            Self::DisallowedGatewayIntents => {
-                f.write_str("Disallowed gateway intents were provided")
-            },
+            Self::DisallowedGatewayIntents => write!(
+                f,
+                "Disallowed gateway intents were provided, enable the privileged intents {:?} \
+                 that are used by the bot at https://discord.com/developers/applications",
+                GatewayIntents::privileged()
+            ),
         }
     }
 }
 
 impl StdError for Error {}
+
+#[cfg(test)]
+mod test {
+    use super::Error;
+
+    #[test]
+    fn test_disallowed_gateway_intents_names_privileged_intents() {
+        let message = Error::DisallowedGatewayIntents.to_string();
+
+        assert!(message.contains("GUILD_MEMBERS"));
+        assert!(message.contains("GUILD_PRESENCES"));
+        assert!(message.contains("MESSAGE_CONTENT"));
+    }
+}
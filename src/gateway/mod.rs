@@ -46,13 +46,18 @@
 //! [`Client::start_shards`]: crate::Client::start_shards
 //! [docs]: https://discordapp.com/developers/docs/topics/gateway#sharding
 
+mod create_presence;
 mod error;
+mod presence_rate_limit;
 mod shard;
 mod ws_client_ext;
 
 use std::fmt;
 
+pub use self::create_presence::CreatePresence;
 pub use self::error::Error as GatewayError;
+pub use self::presence_rate_limit::PresenceRateLimit;
+pub(crate) use self::presence_rate_limit::PresenceThrottle;
 pub use self::shard::Shard;
 pub use self::ws_client_ext::WebSocketGatewayClientExt;
 #[cfg(feature = "client")]
@@ -157,6 +162,9 @@ pub enum InterMessage {
     #[cfg(feature = "client")]
     Client(Box<ShardClientMessage>),
     Json(Value),
+    /// Indicates that the shard is to update its presence in its entirety,
+    /// including multiple activities.
+    SetPresence(CreatePresence),
 }
 
 #[derive(Debug)]
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// The rate limit applied to the presence updates sent by a [`Shard`].
+///
+/// Discord allows a shard to update its presence 5 times per 60 seconds, and
+/// may disconnect shards exceeding that. Presence updates exceeding the rate
+/// limit are not sent right away: only the most recent presence is kept, and
+/// sent once the rate limit allows it again.
+///
+/// # Examples
+///
+/// Allow 4 presence updates per 60 seconds, to leave room for updates sent
+/// elsewhere:
+///
+/// ```rust
+/// # use serenity::gateway::PresenceRateLimit;
+/// use std::time::Duration;
+///
+/// let rate_limit = PresenceRateLimit::new().limit(4).period(Duration::from_secs(60));
+/// ```
+///
+/// [`Shard`]: super::Shard
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PresenceRateLimit {
+    limit: u8,
+    period: Duration,
+}
+
+impl PresenceRateLimit {
+    /// Creates the default rate limit, which allows 5 presence updates per 60
+    /// seconds.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            limit: 5,
+            period: Duration::from_secs(60),
+        }
+    }
+
+    /// Creates a rate limit that never delays presence updates.
+    ///
+    /// **Note**: Sending too many presence updates may get the shard
+    /// disconnected.
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::new().limit(0)
+    }
+
+    /// Sets how many presence updates can be sent per [`Self::period`]. A
+    /// value of `0` disables the rate limit.
+    #[must_use]
+    pub fn limit(mut self, limit: u8) -> Self {
+        self.limit = limit;
+
+        self
+    }
+
+    /// Sets the period the [`Self::limit`] applies to.
+    #[must_use]
+    pub fn period(mut self, period: Duration) -> Self {
+        self.period = period;
+
+        self
+    }
+
+    /// Whether presence updates are rate limited at all.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.limit > 0
+    }
+}
+
+impl Default for PresenceRateLimit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Tracks the presence updates sent by a shard within its rate limit.
+#[derive(Debug, Default)]
+pub(crate) struct PresenceThrottle {
+    rate_limit: PresenceRateLimit,
+    sent: VecDeque<Instant>,
+}
+
+impl PresenceThrottle {
+    pub(crate) fn set_rate_limit(&mut self, rate_limit: PresenceRateLimit) {
+        self.rate_limit = rate_limit;
+    }
+
+    /// Returns how long to wait until another presence update can be sent, or
+    /// [`None`] if it can be sent right away.
+    pub(crate) fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        if !self.rate_limit.is_enabled() {
+            return None;
+        }
+
+        while self
+            .sent
+            .front()
+            .map_or(false, |sent| now.saturating_duration_since(*sent) >= self.rate_limit.period)
+        {
+            self.sent.pop_front();
+        }
+
+        if self.sent.len() < usize::from(self.rate_limit.limit) {
+            return None;
+        }
+
+        self.sent
+            .front()
+            .map(|sent| self.rate_limit.period.saturating_sub(now.saturating_duration_since(*sent)))
+    }
+
+    /// Records a presence update sent at the given instant.
+    pub(crate) fn record(&mut self, now: Instant) {
+        if self.rate_limit.is_enabled() {
+            self.sent.push_back(now);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use super::{PresenceRateLimit, PresenceThrottle};
+
+    #[test]
+    fn test_wait_time() {
+        let mut throttle = PresenceThrottle::default();
+        throttle.set_rate_limit(PresenceRateLimit::new().limit(2));
+
+        let start = Instant::now();
+
+        for _ in 0..2 {
+            assert_eq!(throttle.wait_time(start), None);
+            throttle.record(start);
+        }
+
+        let now = start + Duration::from_secs(15);
+        assert_eq!(throttle.wait_time(now), Some(Duration::from_secs(45)));
+
+        let now = start + Duration::from_secs(60);
+        assert_eq!(throttle.wait_time(now), None);
+    }
+
+    #[test]
+    fn test_disabled() {
+        let mut throttle = PresenceThrottle::default();
+        throttle.set_rate_limit(PresenceRateLimit::disabled());
+
+        let now = Instant::now();
+
+        for _ in 0..10 {
+            assert_eq!(throttle.wait_time(now), None);
+            throttle.record(now);
+        }
+    }
+}
@@ -7,10 +7,14 @@ use tokio::sync::Mutex;
 use tracing::{debug, error, info, instrument, trace, warn};
 use url::Url;
 
+use super::ws_client_ext::send_presence;
 use super::{
     ConnectionStage,
+    CreatePresence,
     CurrentPresence,
     GatewayError,
+    PresenceRateLimit,
+    PresenceThrottle,
     ReconnectType,
     ShardAction,
     WebSocketGatewayClientExt,
@@ -61,6 +65,13 @@ use crate::model::user::OnlineStatus;
 pub struct Shard {
     pub client: WsStream,
     current_presence: CurrentPresence,
+    /// The full presence of the shard, of which [`Self::current_presence`]
+    /// only holds the first activity.
+    presence: CreatePresence,
+    /// When the current presence is due to be sent, if it was delayed as the
+    /// presence update rate limit was hit.
+    presence_pending: Option<Instant>,
+    presence_throttle: PresenceThrottle,
     /// A tuple of:
     ///
     /// - the last instant that a heartbeat was sent
@@ -77,6 +88,9 @@ pub struct Shard {
     // This _must_ be set to `true` in `Shard::handle_event`'s
     // `Ok(GatewayEvent::HeartbeatAck)` arm.
     last_heartbeat_acknowledged: bool,
+    /// The maximum size of a decompressed payload, above which payloads are
+    /// skipped.
+    max_payload_size: Option<usize>,
     seq: u64,
     session_id: Option<String>,
     shard_info: [u64; 2],
@@ -146,10 +160,14 @@ impl Shard {
         Ok(Shard {
             client,
             current_presence,
+            presence: CreatePresence::default(),
+            presence_pending: None,
+            presence_throttle: PresenceThrottle::default(),
             heartbeat_instants,
             heartbeat_interval,
             http: None,
             last_heartbeat_acknowledged,
+            max_payload_size: None,
             seq,
             stage,
             started: Instant::now(),
@@ -168,12 +186,46 @@ impl Shard {
         self.http = Some(http);
     }
 
+    /// Sets the rate limit applied to [presence updates].
+    ///
+    /// [presence updates]: Self::update_presence
+    pub fn set_presence_rate_limit(&mut self, rate_limit: PresenceRateLimit) {
+        self.presence_throttle.set_rate_limit(rate_limit);
+    }
+
+    /// Sets the maximum size in bytes of a decompressed gateway payload.
+    ///
+    /// Payloads exceeding the size are logged and skipped, rather than being
+    /// decompressed and parsed in their entirety. Note that the skipped events
+    /// are lost, e.g. a skipped [`GuildCreate`] leaves the guild uncached.
+    ///
+    /// By default, there is no maximum size.
+    ///
+    /// [`GuildCreate`]: crate::model::event::Event::GuildCreate
+    pub fn set_max_payload_size(&mut self, max_size: Option<usize>) {
+        self.max_payload_size = max_size;
+    }
+
+    /// Retrieves the maximum size of a decompressed gateway payload. See
+    /// [`Self::set_max_payload_size`] for more info.
+    #[inline]
+    pub fn max_payload_size(&self) -> Option<usize> {
+        self.max_payload_size
+    }
+
     /// Retrieves the current presence of the shard.
     #[inline]
     pub fn current_presence(&self) -> &CurrentPresence {
         &self.current_presence
     }
 
+    /// Retrieves the full presence of the shard, including all of its
+    /// activities.
+    #[inline]
+    pub fn presence(&self) -> &CreatePresence {
+        &self.presence
+    }
+
     /// Retrieves the heartbeat instants of the shard.
     ///
     /// This is the time of when a heartbeat was sent and when an
@@ -253,6 +305,7 @@ impl Shard {
     #[inline]
     #[instrument(skip(self))]
     pub fn set_activity(&mut self, activity: Option<Activity>) {
+        self.presence.activities(activity.clone());
         self.current_presence.0 = activity;
     }
 
@@ -265,12 +318,17 @@ impl Shard {
 
     #[inline]
     #[instrument(skip(self))]
-    pub fn set_status(&mut self, mut status: OnlineStatus) {
-        if status == OnlineStatus::Offline {
-            status = OnlineStatus::Invisible;
-        }
+    pub fn set_status(&mut self, status: OnlineStatus) {
+        self.presence.status(status);
+        self.current_presence.1 = self.presence.get_status();
+    }
 
-        self.current_presence.1 = status;
+    /// Sets the full presence of the shard, including multiple activities and
+    /// whether it is AFK.
+    #[instrument(skip(self))]
+    pub fn set_full_presence(&mut self, presence: CreatePresence) {
+        self.current_presence = (presence.get_activities().first().cloned(), presence.get_status());
+        self.presence = presence;
     }
 
     /// Retrieves a copy of the current shard information.
@@ -437,9 +495,12 @@ impl Shard {
                 return Err(Error::Gateway(GatewayError::InvalidGatewayIntents));
             },
             Some(close_codes::DISALLOWED_GATEWAY_INTENTS) => {
+                let privileged = self.intents & GatewayIntents::privileged();
+
                 error!(
-                    "[Shard {:?}] Disallowed gateway intents have been provided.",
-                    self.shard_info
+                    "[Shard {:?}] Disallowed gateway intents have been provided, the \
+                     privileged intents {:?} may not be enabled.",
+                    self.shard_info, privileged
                 );
 
                 return Err(Error::Gateway(GatewayError::DisallowedGatewayIntents));
@@ -799,9 +860,60 @@ impl Shard {
         Ok(())
     }
 
+    /// Sends the current presence to the gateway.
+    ///
+    /// If the [presence rate limit] has been hit, the presence is sent later on
+    /// via [`Self::update_pending_presence`] instead. Only the most recent
+    /// presence is sent then.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the presence update could not be sent over the
+    /// WebSocket.
+    ///
+    /// [presence rate limit]: Self::set_presence_rate_limit
     #[instrument(skip(self))]
     pub async fn update_presence(&mut self) -> Result<()> {
-        self.client.send_presence_update(&self.shard_info, &self.current_presence).await
+        let now = Instant::now();
+
+        if let Some(wait) = self.presence_throttle.wait_time(now) {
+            debug!(
+                "[Shard {:?}] Presence update rate limited, sending in {:?}",
+                self.shard_info, wait
+            );
+
+            self.presence_pending = Some(now + wait);
+
+            return Ok(());
+        }
+
+        self.presence_pending = None;
+        self.presence_throttle.record(now);
+
+        send_presence(&mut self.client, &self.shard_info, &self.presence).await
+    }
+
+    /// Sends the current presence if a previous update was delayed by the
+    /// presence rate limit, and the rate limit allows it by now.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the presence update could not be sent over the
+    /// WebSocket.
+    #[instrument(skip(self))]
+    pub async fn update_pending_presence(&mut self) -> Result<()> {
+        if self.presence_pending.map_or(false, |due| due <= Instant::now()) {
+            self.update_presence().await?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns when a presence update delayed by the presence rate limit is
+    /// due to be sent via [`Self::update_pending_presence`], if any.
+    #[inline]
+    pub fn pending_presence_due(&self) -> Option<Instant> {
+        self.presence_pending
     }
 }
 
@@ -6,7 +6,7 @@ use tracing::{debug, instrument, trace};
 
 use crate::client::bridge::gateway::ChunkGuildFilter;
 use crate::constants::{self, OpCode};
-use crate::gateway::{CurrentPresence, WsStream};
+use crate::gateway::{CreatePresence, CurrentPresence, WsStream};
 use crate::internal::prelude::*;
 use crate::internal::ws_impl::SenderExt;
 use crate::json::json;
@@ -128,25 +128,12 @@ impl WebSocketGatewayClientExt for WsStream {
         shard_info: &[u64; 2],
         current_presence: &CurrentPresence,
     ) -> Result<()> {
-        let &(ref activity, ref status) = current_presence;
-        let now = SystemTime::now();
+        let &(ref activity, status) = current_presence;
 
-        debug!("[Shard {:?}] Sending presence update", shard_info);
+        let mut presence = CreatePresence::default();
+        presence.status(status).activities(activity.clone());
 
-        self.send_json(&json!({
-            "op": OpCode::StatusUpdate.num(),
-            "d": {
-                "afk": false,
-                "since": now,
-                "status": status.name(),
-                "game": activity.as_ref().map(|x| json!({
-                    "name": x.name,
-                    "type": x.kind,
-                    "url": x.url,
-                })),
-            },
-        }))
-        .await
+        send_presence(self, shard_info, &presence).await
     }
 
     #[instrument(skip(self, token))]
@@ -171,3 +158,40 @@ impl WebSocketGatewayClientExt for WsStream {
         .map_err(From::from)
     }
 }
+
+/// Sends the full presence of a shard, including all of its activities.
+#[instrument(skip(client))]
+pub(crate) async fn send_presence(
+    client: &mut WsStream,
+    shard_info: &[u64; 2],
+    presence: &CreatePresence,
+) -> Result<()> {
+    let now = SystemTime::now();
+
+    debug!("[Shard {:?}] Sending presence update", shard_info);
+
+    let activities = presence
+        .get_activities()
+        .iter()
+        .map(|x| {
+            json!({
+                "name": x.name,
+                "type": x.kind,
+                "url": x.url,
+                "state": x.state,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    client
+        .send_json(&json!({
+            "op": OpCode::StatusUpdate.num(),
+            "d": {
+                "afk": presence.get_afk(),
+                "since": now,
+                "status": presence.get_status().name(),
+                "activities": activities,
+            },
+        }))
+        .await
+}
@@ -1,27 +1,33 @@
 #![allow(clippy::missing_errors_doc)]
 
 use std::borrow::Cow;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
+use futures::stream::Stream;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use reqwest::header::{HeaderMap as Headers, HeaderValue, CONTENT_TYPE};
 use reqwest::{Client, ClientBuilder, Response as ReqwestResponse, StatusCode, Url};
 use serde::de::DeserializeOwned;
+use tokio::time::sleep;
 use tracing::{debug, instrument, trace};
 
 use super::multipart::Multipart;
 use super::ratelimiting::{RatelimitedRequest, Ratelimiter};
 use super::request::Request;
+use super::retry::RetryPolicy;
 use super::routing::RouteInfo;
 use super::typing::Typing;
 use super::{AttachmentType, GuildPagination, HttpError, UserPagination};
 use crate::internal::prelude::*;
 use crate::json::prelude::*;
 use crate::model::application::command::{Command, CommandPermission};
+use crate::model::application::role_connection::ApplicationRoleConnectionMetadata;
 use crate::model::guild::automod::Rule;
 use crate::model::prelude::*;
 use crate::{constants, utils};
@@ -52,6 +58,10 @@ pub struct HttpBuilder {
     token: String,
     proxy: Option<Url>,
     application_id: Option<u64>,
+    request_timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    retry_policy: RetryPolicy,
+    request_callback: Option<RequestCallback>,
 }
 
 impl HttpBuilder {
@@ -65,6 +75,10 @@ impl HttpBuilder {
             token: parse_token(token),
             proxy: None,
             application_id: None,
+            request_timeout: None,
+            connect_timeout: None,
+            retry_policy: RetryPolicy::disabled(),
+            request_callback: None,
         }
     }
 
@@ -124,10 +138,17 @@ impl HttpBuilder {
     /// ratelimiter.
     ///
     /// The proxy should be in the form of the protocol and hostname, e.g.
-    /// `http://127.0.0.1:3000` or `http://myproxy.example`
+    /// `http://127.0.0.1:3000` or `http://myproxy.example`, optionally
+    /// followed by a path prefix. For example, with a proxy of
+    /// `http://127.0.0.1:3000/discord`, a request to
+    /// `https://discord.com/api/v10/users/@me` is sent to
+    /// `http://127.0.0.1:3000/discord/api/v10/users/@me`. This also allows
+    /// pointing the client at a mock server for testing.
     ///
     /// This will simply send HTTP API requests to the proxy instead of Discord
     /// API to allow the proxy to intercept, rate limit, and forward requests.
+    /// The ratelimiter keeps working with a proxy; if the proxy handles rate
+    /// limits itself, it can be disabled via [`Self::ratelimiter_disabled`].
     /// This is different than a native proxy's behavior where it will tunnel
     /// requests that use TLS via [`HTTP CONNECT`] method (e.g. using
     /// [`reqwest::Proxy`]).
@@ -141,6 +162,83 @@ impl HttpBuilder {
         Ok(self)
     }
 
+    /// Sets the timeout of each request, from connecting until the response
+    /// body has been received. By default, requests do not time out.
+    ///
+    /// Requests that time out return an [`HttpError::Request`], for which
+    /// [`HttpError::is_timeout`] returns `true`. They may be retried.
+    ///
+    /// **Note**: This is only applied to the default client. When setting a
+    /// client via [`Self::client`], configure it with
+    /// [`reqwest::ClientBuilder::timeout`] instead.
+    #[must_use]
+    pub fn request_timeout(mut self, timeout: Duration) -> Self {
+        self.request_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the timeout for connecting to Discord, or to the [proxy] if one is
+    /// set. By default, connecting does not time out.
+    ///
+    /// Connections that time out return an [`HttpError::Request`], for which
+    /// [`HttpError::is_timeout`] returns `true`. They may be retried.
+    ///
+    /// **Note**: This is only applied to the default client. When setting a
+    /// client via [`Self::client`], configure it with
+    /// [`reqwest::ClientBuilder::connect_timeout`] instead.
+    ///
+    /// [proxy]: Self::proxy
+    #[must_use]
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the policy used to retry requests that failed due to a transient
+    /// error. If one isn't provided, requests are never retried, as with
+    /// [`RetryPolicy::disabled`].
+    #[must_use]
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+
+        self
+    }
+
+    /// Sets a callback to be called with each request before it is sent,
+    /// e.g. to log or inspect it.
+    ///
+    /// The parts of the multipart form of attachment uploads can be inspected
+    /// via [`Request::multipart_ref`] and [`Multipart::parts`].
+    ///
+    /// # Examples
+    ///
+    /// Print the parts of each attachment upload:
+    ///
+    /// ```rust
+    /// use serenity::http::HttpBuilder;
+    ///
+    /// let http = HttpBuilder::new("token")
+    ///     .request_callback(|request| {
+    ///         if let Some(multipart) = request.multipart_ref() {
+    ///             println!("{:#?}", multipart.parts());
+    ///         }
+    ///     })
+    ///     .build();
+    /// ```
+    ///
+    /// [`Multipart::parts`]: super::multipart::Multipart::parts
+    #[must_use]
+    pub fn request_callback(
+        mut self,
+        request_callback: impl Fn(&Request<'_>) + Send + Sync + 'static,
+    ) -> Self {
+        self.request_callback = Some(Box::new(request_callback));
+
+        self
+    }
+
     /// Use the given configuration to build the `Http` client.
     #[must_use]
     pub fn build(self) -> Http {
@@ -148,25 +246,40 @@ impl HttpBuilder {
 
         let application_id = AtomicU64::new(self.application_id.unwrap_or_default());
 
+        let (request_timeout, connect_timeout) = (self.request_timeout, self.connect_timeout);
         let client = self.client.unwrap_or_else(|| {
-            let builder = configure_client_backend(Client::builder());
+            let mut builder = configure_client_backend(Client::builder());
+
+            if let Some(timeout) = request_timeout {
+                builder = builder.timeout(timeout);
+            }
+
+            if let Some(timeout) = connect_timeout {
+                builder = builder.connect_timeout(timeout);
+            }
+
             builder.build().expect("Cannot build reqwest::Client")
         });
 
-        let ratelimiter = self.ratelimiter.unwrap_or_else(|| {
+        let mut ratelimiter = self.ratelimiter.unwrap_or_else(|| {
             let client = client.clone();
             Ratelimiter::new(client, token.to_string())
         });
 
+        ratelimiter.set_proxy(self.proxy.clone());
+
         let ratelimiter_disabled = self.ratelimiter_disabled;
 
         Http {
             client,
             ratelimiter,
             ratelimiter_disabled,
+            retry_policy: self.retry_policy,
             proxy: self.proxy,
             token,
             application_id,
+            current_user_id: AtomicU64::new(0),
+            request_callback: self.request_callback,
         }
     }
 }
@@ -200,17 +313,23 @@ pub struct Http {
     pub(crate) client: Client,
     pub ratelimiter: Ratelimiter,
     pub ratelimiter_disabled: bool,
+    pub retry_policy: RetryPolicy,
     pub proxy: Option<Url>,
     pub token: String,
     application_id: AtomicU64,
+    current_user_id: AtomicU64,
+    request_callback: Option<RequestCallback>,
 }
 
+type RequestCallback = Box<dyn Fn(&Request<'_>) + Send + Sync>;
+
 impl fmt::Debug for Http {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Http")
             .field("client", &self.client)
             .field("ratelimiter", &self.ratelimiter)
             .field("ratelimiter_disabled", &self.ratelimiter_disabled)
+            .field("retry_policy", &self.retry_policy)
             .field("proxy", &self.proxy)
             .finish()
     }
@@ -230,9 +349,12 @@ impl Http {
             client,
             ratelimiter: Ratelimiter::new(client2, token.to_string()),
             ratelimiter_disabled: false,
+            retry_policy: RetryPolicy::disabled(),
             proxy: None,
             token,
             application_id: AtomicU64::new(0),
+            current_user_id: AtomicU64::new(0),
+            request_callback: None,
         }
     }
 
@@ -263,6 +385,57 @@ impl Http {
         self.application_id.store(application_id, Ordering::Relaxed);
     }
 
+    /// Returns the ID of the current user, if known.
+    ///
+    /// When using the [`Client`], it is set once a shard received the
+    /// [`Ready`] event, even if the `cache` feature is disabled. The ID of the
+    /// application is set at the same time, unless it was set beforehand.
+    ///
+    /// [`Client`]: crate::Client
+    /// [`Ready`]: crate::model::gateway::Ready
+    pub fn current_user_id(&self) -> Option<u64> {
+        let current_user_id = self.current_user_id.load(Ordering::Relaxed);
+
+        if current_user_id == 0 {
+            None
+        } else {
+            Some(current_user_id)
+        }
+    }
+
+    pub fn set_current_user_id(&self, current_user_id: u64) {
+        self.current_user_id.store(current_user_id, Ordering::Relaxed);
+    }
+
+    /// Adds a [`User`] to a group DM with a valid OAuth2 access token of that
+    /// user with the `gdm.join` scope, optionally setting their nickname.
+    ///
+    /// **Note**: The group DM must have been created by the current
+    /// application via [`Self::create_group_dm`].
+    pub async fn add_group_recipient(
+        &self,
+        group_id: u64,
+        user_id: u64,
+        access_token: &str,
+        nick: Option<&str>,
+    ) -> Result<()> {
+        let body = to_vec(&json!({
+            "access_token": access_token,
+            "nick": nick,
+        }))?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::AddGroupRecipient {
+                group_id,
+                user_id,
+            },
+        })
+        .await
+    }
+
     /// Adds a [`User`] to a [`Guild`] with a valid OAuth2 access token.
     ///
     /// Returns the created [`Member`] object, or nothing if the user is already a member of the guild.
@@ -539,7 +712,15 @@ impl Http {
         .await
     }
 
-    /// Creates new global application commands.
+    /// Overwrites all global application commands in a single request.
+    ///
+    /// The body must be a JSON array of commands. Any existing global command
+    /// missing from the array is deleted, and the full list of registered
+    /// commands is returned.
+    ///
+    /// Refer to [Discord's docs] for more information.
+    ///
+    /// [Discord's docs]: https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-global-application-commands
     pub async fn create_global_application_commands(&self, map: &Value) -> Result<Vec<Command>> {
         self.fire(Request {
             body: Some(map.to_string().as_bytes()),
@@ -552,7 +733,15 @@ impl Http {
         .await
     }
 
-    /// Creates new guild application commands.
+    /// Overwrites all application commands of a guild in a single request.
+    ///
+    /// The body must be a JSON array of commands. Any existing command of the
+    /// guild missing from the array is deleted, and the full list of
+    /// registered commands is returned.
+    ///
+    /// Refer to [Discord's docs] for more information.
+    ///
+    /// [Discord's docs]: https://discord.com/developers/docs/interactions/application-commands#bulk-overwrite-guild-application-commands
     pub async fn create_guild_application_commands(
         &self,
         guild_id: u64,
@@ -637,6 +826,33 @@ impl Http {
         .await
     }
 
+    /// Creates a new guild from a [`GuildTemplate`].
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full [`Guild`]
+    /// will be received over a [`Shard`], if at least one is running.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: This endpoint can be used only by bots in less than 10 guilds.
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template
+    pub async fn create_guild_from_template(
+        &self,
+        code: &str,
+        map: &Value,
+    ) -> Result<PartialGuild> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate {
+                code,
+            },
+        })
+        .await
+    }
+
     /// Creates an [`Integration`] for a [`Guild`].
     ///
     /// Refer to Discord's [docs] for field information.
@@ -664,6 +880,26 @@ impl Http {
         .await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of a guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-template
+    pub async fn create_guild_template(&self, guild_id: u64, map: &Value) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate {
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a response to an [`Interaction`] from the gateway.
     ///
     /// Refer to Discord's [docs] for the object it takes.
@@ -767,6 +1003,35 @@ impl Http {
         .await
     }
 
+    /// Creates a group DM with the users whose OAuth2 access tokens are given,
+    /// optionally setting their nicknames within the group DM.
+    ///
+    /// **Note**: Each access token must have been granted the `gdm.join`
+    /// scope. Discord limits the number of group DMs a bot can create.
+    pub async fn create_group_dm(
+        &self,
+        access_tokens: &[&str],
+        nicks: &HashMap<UserId, String>,
+    ) -> Result<GroupChannel> {
+        let nicks = nicks
+            .iter()
+            .map(|(user_id, nick)| (user_id.to_string(), Value::from(nick.clone())))
+            .collect::<JsonMap>();
+
+        let body = to_vec(&json!({
+            "access_tokens": access_tokens,
+            "nicks": nicks,
+        }))?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreatePrivateChannel,
+        })
+        .await
+    }
+
     /// Creates a private channel with a user.
     pub async fn create_private_channel(&self, map: &Value) -> Result<PrivateChannel> {
         let body = to_vec(map)?;
@@ -1060,6 +1325,24 @@ impl Http {
         .await
     }
 
+    /// Deletes a guild template, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate {
+                guild_id,
+                code,
+            },
+        })
+        .await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
         self.fire(Request {
@@ -1819,7 +2102,52 @@ impl Http {
         .await
     }
 
+    /// Overrides the role connection metadata records of the current
+    /// application.
+    ///
+    /// The body must be a JSON array of at most 5 records.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/application-role-connection-metadata#update-application-role-connection-metadata-records
+    pub async fn edit_application_role_connection_metadata(
+        &self,
+        map: &Value,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request {
+            body: Some(map.to_string().as_bytes()),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::EditApplicationRoleConnectionMetadata {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
+    /// Edits the current application's information.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/application#edit-current-application
+    pub async fn edit_current_application_info(
+        &self,
+        map: &JsonMap,
+    ) -> Result<CurrentApplicationInfo> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::EditCurrentApplicationInfo,
+        })
+        .await
+    }
+
     /// Edits the current user's profile settings.
+    ///
+    /// **Note**: The username can only be changed twice per hour.
     pub async fn edit_profile(&self, map: &JsonMap) -> Result<CurrentUser> {
         let body = to_vec(map)?;
 
@@ -2383,6 +2711,21 @@ impl Http {
         .await
     }
 
+    /// Gets the role connection metadata records of the current application.
+    pub async fn get_application_role_connection_metadata(
+        &self,
+    ) -> Result<Vec<ApplicationRoleConnectionMetadata>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetApplicationRoleConnectionMetadata {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
     /// Gets all audit logs in a specific guild.
     pub async fn get_audit_logs(
         &self,
@@ -2494,7 +2837,32 @@ impl Http {
         .await
     }
 
-    /// Gets current bot gateway.
+    /// Gets current bot gateway, including the recommended number of shards
+    /// and the [`SessionStartLimit`] of the bot.
+    ///
+    /// # Examples
+    ///
+    /// Check how many sessions can still be started today:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let gateway = http.get_bot_gateway().await?;
+    /// let limit = gateway.session_start_limit;
+    ///
+    /// println!(
+    ///     "{}/{} sessions remaining, resetting in {:?}",
+    ///     limit.remaining,
+    ///     limit.total,
+    ///     limit.reset_after_duration(),
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`SessionStartLimit`]: crate::model::gateway::SessionStartLimit
     pub async fn get_bot_gateway(&self) -> Result<BotGateway> {
         self.fire(Request {
             body: None,
@@ -2526,12 +2894,55 @@ impl Http {
             headers: None,
             route: RouteInfo::GetChannelThreadMembers {
                 channel_id,
+                with_member: false,
+                after: None,
+                limit: None,
+            },
+        })
+        .await
+    }
+
+    /// Gets a page of thread members for a thread, ordered by their user id.
+    ///
+    /// Pass `with_member` to include the guild member data of each thread
+    /// member, which also enables pagination: only the thread members after
+    /// the user id `after` are returned, at most `limit` of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if the `limit` is not between 1 and 100.
+    pub async fn get_channel_thread_members_paginated(
+        &self,
+        channel_id: u64,
+        with_member: bool,
+        after: Option<u64>,
+        limit: Option<u64>,
+    ) -> Result<Vec<ThreadMember>> {
+        if let Some(l) = limit {
+            if !(1..=constants::THREAD_MEMBER_FETCH_LIMIT).contains(&l) {
+                return Err(Error::NotInRange("limit", l, 1, constants::THREAD_MEMBER_FETCH_LIMIT));
+            }
+        }
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetChannelThreadMembers {
+                channel_id,
+                with_member,
+                after,
+                limit,
             },
         })
         .await
     }
 
     /// Gets all active threads from a guild.
+    ///
+    /// Refer to Discord's [docs] for details on the response.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/guild#list-active-guild-threads
     pub async fn get_guild_active_threads(&self, guild_id: u64) -> Result<ThreadsData> {
         self.fire(Request {
             body: None,
@@ -2753,6 +3164,13 @@ impl Http {
     }
 
     /// Gets all emojis of a guild.
+    ///
+    /// Unlike the emojis of a cached [`Guild`], the [`user`] who created each
+    /// emoji is included if the current user has the [Manage Emojis and
+    /// Stickers] permission.
+    ///
+    /// [`user`]: Emoji::user
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
     pub async fn get_emojis(&self, guild_id: u64) -> Result<Vec<Emoji>> {
         self.fire(Request {
             body: None,
@@ -2955,24 +3373,57 @@ impl Http {
 
     /// Gets integrations that a guild has.
     pub async fn get_guild_integrations(&self, guild_id: u64) -> Result<Vec<Integration>> {
+        let mut value = self
+            .request(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                route: RouteInfo::GetGuildIntegrations {
+                    guild_id,
+                },
+            })
+            .await?
+            .json::<Value>()
+            .await?;
+
+        // The integrations returned by this endpoint don't contain the guild Id.
+        if let Some(values) = value.as_array_mut() {
+            let num = from_number(guild_id);
+
+            for value in values {
+                if let Some(element) = value.as_object_mut() {
+                    element.insert("guild_id".to_string(), num.clone());
+                }
+            }
+        }
+
+        from_value::<Vec<Integration>>(value).map_err(From::from)
+    }
+
+    /// Gets all invites to a guild.
+    pub async fn get_guild_invites(&self, guild_id: u64) -> Result<Vec<RichInvite>> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuildIntegrations {
+            route: RouteInfo::GetGuildInvites {
                 guild_id,
             },
         })
         .await
     }
 
-    /// Gets all invites to a guild.
-    pub async fn get_guild_invites(&self, guild_id: u64) -> Result<Vec<RichInvite>> {
+    /// Gets the templates of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuildInvites {
+            route: RouteInfo::GetGuildTemplates {
                 guild_id,
             },
         })
@@ -3001,6 +3452,38 @@ impl Http {
         .map_err(From::from)
     }
 
+    /// Gets a guild's vanity invite code along with its number of uses, or
+    /// [`None`] if the guild has no vanity URL.
+    pub async fn get_guild_vanity_url_uses(&self, guild_id: u64) -> Result<Option<GuildVanityUrl>> {
+        #[derive(Deserialize)]
+        struct GuildVanityUrlResponse {
+            code: Option<String>,
+            #[serde(default)]
+            uses: u64,
+        }
+
+        self.request(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetGuildVanityUrl {
+                guild_id,
+            },
+        })
+        .await?
+        .json::<GuildVanityUrlResponse>()
+        .await
+        .map(|x| {
+            let uses = x.uses;
+
+            x.code.map(|code| GuildVanityUrl {
+                code,
+                uses,
+            })
+        })
+        .map_err(From::from)
+    }
+
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
     /// user to offset the result by.
     pub async fn get_guild_members(
@@ -3199,6 +3682,13 @@ impl Http {
     }
 
     /// Retrieves a list of stickers in a [`Guild`].
+    ///
+    /// Unlike the stickers of a cached [`Guild`], the [`user`] who uploaded
+    /// each sticker is included if the current user has the [Manage Emojis
+    /// and Stickers] permission.
+    ///
+    /// [`user`]: Sticker::user
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
     pub async fn get_guild_stickers(&self, guild_id: u64) -> Result<Vec<Sticker>> {
         let mut value = self
             .request(Request {
@@ -3330,6 +3820,162 @@ impl Http {
         .await
     }
 
+    /// Gets a paginated list of the current user's guilds, including the
+    /// approximate member counts of each guild.
+    ///
+    /// The `limit` has a maximum value of 200.
+    ///
+    /// Refer to [`Self::get_current_user_guilds_with_counts_iter`] to fetch all
+    /// guilds.
+    ///
+    /// [Discord's documentation][docs]
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/user#get-current-user-guilds
+    pub async fn get_current_user_guilds_with_counts(
+        &self,
+        target: Option<&GuildPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<GuildInfoWithCounts>> {
+        let (after, before) = match target {
+            None => (None, None),
+            Some(GuildPagination::After(id)) => (Some(id.0), None),
+            Some(GuildPagination::Before(id)) => (None, Some(id.0)),
+        };
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetGuildsWithCounts {
+                after,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
+    /// Streams over all guilds of the current user.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Self::get_guilds`]. A buffer of at most 200 guilds is used to reduce
+    /// the number of calls necessary.
+    ///
+    /// The stream ends after yielding an error.
+    ///
+    /// Refer to [`Self::get_current_user_guilds_with_counts_iter`] to include
+    /// the approximate member counts of the guilds as well.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut guilds = http.get_current_user_guilds_iter().boxed();
+    /// while let Some(guild_result) = guilds.next().await {
+    ///     match guild_result {
+    ///         Ok(guild) => println!("{} is one of my guilds", guild.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_current_user_guilds_iter(&self) -> impl Stream<Item = Result<GuildInfo>> + '_ {
+        self.current_user_guilds_iter(false)
+    }
+
+    /// Streams over all guilds of the current user, including the approximate
+    /// member counts of each guild.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Self::get_current_user_guilds_with_counts`]. A buffer of at most 200
+    /// guilds is used to reduce the number of calls necessary.
+    ///
+    /// The stream ends after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut guilds = http.get_current_user_guilds_with_counts_iter().boxed();
+    /// while let Some(guild_result) = guilds.next().await {
+    ///     match guild_result {
+    ///         Ok(guild) => {
+    ///             println!("{} has {} members", guild.info.name, guild.approximate_member_count)
+    ///         },
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_current_user_guilds_with_counts_iter(
+        &self,
+    ) -> impl Stream<Item = Result<GuildInfoWithCounts>> + '_ {
+        self.current_user_guilds_iter(true)
+    }
+
+    /// Streams over all guilds of the current user, deserialized as `T`.
+    fn current_user_guilds_iter<T>(&self, with_counts: bool) -> impl Stream<Item = Result<T>> + '_
+    where
+        T: DeserializeOwned + 'static,
+        for<'a> &'a T: Into<GuildId>,
+    {
+        let limit = constants::CURRENT_USER_GUILD_FETCH_LIMIT;
+        let init_state = (Vec::new(), None, false);
+
+        futures::stream::unfold(init_state, move |(mut buffer, mut after, mut done)| async move {
+            if buffer.is_empty() && !done {
+                let after_id = after.map(|id: GuildId| id.0);
+                let route = if with_counts {
+                    RouteInfo::GetGuildsWithCounts {
+                        after: after_id,
+                        before: None,
+                        limit: Some(limit),
+                    }
+                } else {
+                    RouteInfo::GetGuilds {
+                        after: after_id,
+                        before: None,
+                        limit: Some(limit),
+                    }
+                };
+
+                let request = Request {
+                    body: None,
+                    multipart: None,
+                    headers: None,
+                    route,
+                };
+
+                match self.fire::<Vec<T>>(request).await {
+                    Ok(guilds) => {
+                        // If shorter than the limit, there are no more results.
+                        done = guilds.len() < limit as usize;
+                        after = guilds.last().map(Into::into);
+
+                        // Reverse to optimize pop()
+                        buffer = guilds;
+                        buffer.reverse();
+                    },
+                    // End the stream after yielding the error, instead of
+                    // requesting the same page again.
+                    Err(error) => return Some((Err(error), (buffer, after, true))),
+                }
+            }
+
+            buffer.pop().map(|guild| (Ok(guild), (buffer, after, done)))
+        })
+    }
+
     /// Gets information about a specific invite.
     ///
     /// # Arguments
@@ -3453,6 +4099,9 @@ impl Http {
     }
 
     /// Gets user Ids based on their reaction to a message. This endpoint is dumb.
+    ///
+    /// Only normal reactions are considered. Use [`Self::get_reaction_users_of_kind`]
+    /// to get the users of super reactions.
     pub async fn get_reaction_users(
         &self,
         channel_id: u64,
@@ -3460,6 +4109,29 @@ impl Http {
         reaction_type: &ReactionType,
         limit: u8,
         after: Option<u64>,
+    ) -> Result<Vec<User>> {
+        self.get_reaction_users_of_kind(
+            channel_id,
+            message_id,
+            reaction_type,
+            ReactionKind::Normal,
+            limit,
+            after,
+        )
+        .await
+    }
+
+    /// Gets user Ids based on their reaction of the given kind to a message.
+    ///
+    /// [`ReactionKind::Unknown`] is treated like [`ReactionKind::Normal`].
+    pub async fn get_reaction_users_of_kind(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        reaction_type: &ReactionType,
+        kind: ReactionKind,
+        limit: u8,
+        after: Option<u64>,
     ) -> Result<Vec<User>> {
         let reaction = reaction_type.as_data();
 
@@ -3467,12 +4139,22 @@ impl Http {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetReactionUsers {
-                after,
-                channel_id,
-                limit,
-                message_id,
-                reaction,
+            route: match kind {
+                ReactionKind::Normal | ReactionKind::Unknown => RouteInfo::GetReactionUsers {
+                    after,
+                    channel_id,
+                    limit,
+                    message_id,
+                    reaction,
+                },
+                ReactionKind::Burst => RouteInfo::GetReactionUsersOfKind {
+                    after,
+                    channel_id,
+                    kind: kind as u8,
+                    limit,
+                    message_id,
+                    reaction,
+                },
             },
         })
         .await
@@ -3491,6 +4173,19 @@ impl Http {
         .await
     }
 
+    /// Gets a guild template by its code.
+    pub async fn get_template(&self, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetTemplate {
+                code,
+            },
+        })
+        .await
+    }
+
     /// Gets the current unresolved incidents from Discord's Status API.
     ///
     /// Does not require authentication.
@@ -3806,6 +4501,20 @@ impl Http {
         .await
     }
 
+    /// Removes a [`User`] from a group DM created by the current application.
+    pub async fn remove_group_recipient(&self, group_id: u64, user_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::RemoveGroupRecipient {
+                group_id,
+                user_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a single [`Role`] from a [`Member`] in a [`Guild`].
     ///
     /// **Note**: Requires the [Manage Roles] permission and respect of role
@@ -3885,6 +4594,24 @@ impl Http {
         .await
     }
 
+    /// Syncs a guild template to the current state of its source guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate {
+                guild_id,
+                code,
+            },
+        })
+        .await
+    }
+
     /// Starts syncing an integration with a guild.
     pub async fn start_integration_sync(&self, guild_id: u64, integration_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -4007,7 +4734,9 @@ impl Http {
         response.json::<T>().await.map_err(From::from)
     }
 
-    /// Performs a request, ratelimiting it if necessary.
+    /// Performs a request, ratelimiting it if necessary. Requests failing due to
+    /// a transient error are retried according to the [`RetryPolicy`], unless
+    /// they upload files, which can only be read once.
     ///
     /// Returns the raw reqwest Response. Use [`Self::fire`] to deserialize the response
     /// into some type.
@@ -4043,14 +4772,26 @@ impl Http {
     /// # }
     /// ```
     #[instrument]
-    pub async fn request(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
-        let response = if self.ratelimiter_disabled {
-            let request =
-                req.build(&self.client, &self.token, self.proxy.as_ref()).await?.build()?;
-            self.client.execute(request).await?
-        } else {
-            let ratelimiting_req = RatelimitedRequest::from(req);
-            self.ratelimiter.perform(ratelimiting_req).await?
+    pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        if let Some(ref request_callback) = self.request_callback {
+            request_callback(&req);
+        }
+
+        let (method, ..) = req.route.deconstruct();
+        let retryable = req.multipart.is_none();
+        let mut attempt = 1;
+
+        let response = loop {
+            let result = self.perform(req.clone()).await;
+
+            match self.retry_policy.retry_delay(method, attempt, &result).filter(|_| retryable) {
+                Some(delay) => {
+                    debug!("Retrying request in {:?} (attempt {})", delay, attempt);
+                    sleep(delay).await;
+                    attempt += 1;
+                },
+                None => break result?,
+            }
         };
 
         if response.status().is_success() {
@@ -4060,6 +4801,18 @@ impl Http {
         }
     }
 
+    /// Performs a single attempt of a request, ratelimiting it if necessary.
+    async fn perform(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+        if self.ratelimiter_disabled {
+            let request =
+                req.build(&self.client, &self.token, self.proxy.as_ref()).await?.build()?;
+            Ok(self.client.execute(request).await?)
+        } else {
+            let ratelimiting_req = RatelimitedRequest::from(req);
+            self.ratelimiter.perform(ratelimiting_req).await
+        }
+    }
+
     /// Performs a request and then verifies that the response status code is equal
     /// to the expected value.
     ///
@@ -1,3 +1,4 @@
+use std::convert::TryFrom;
 use std::error::Error as StdError;
 use std::fmt;
 
@@ -20,6 +21,17 @@ pub struct DiscordJsonError {
     pub errors: Vec<DiscordJsonSingleError>,
 }
 
+impl DiscordJsonError {
+    /// Returns the typed [`DiscordJsonErrorCode`] of this error.
+    ///
+    /// Returns [`None`] if the raw [`Self::code`] is not a valid Discord error
+    /// code, such as when the library could not decode the error response.
+    #[must_use]
+    pub fn kind(&self) -> Option<DiscordJsonErrorCode> {
+        u32::try_from(self.code).ok().map(DiscordJsonErrorCode::from)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
 pub struct DiscordJsonSingleError {
     /// The error code.
@@ -30,6 +42,433 @@ pub struct DiscordJsonSingleError {
     pub path: String,
 }
 
+/// Generates [`DiscordJsonErrorCode`] and its conversions from a list of
+/// variants and their numeric codes.
+macro_rules! json_error_codes {
+    ($($(#[$attr:meta])* $variant:ident = $code:literal,)*) => {
+        /// A typed representation of the error codes returned by Discord in
+        /// [`DiscordJsonError::code`].
+        ///
+        /// Codes which are not known to the library are returned as
+        /// [`Self::Other`].
+        ///
+        /// [Discord docs](https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes).
+        #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+        #[non_exhaustive]
+        pub enum DiscordJsonErrorCode {
+            $($(#[$attr])* $variant,)*
+            /// An error code unknown to the library.
+            ///
+            /// This is only produced by the `From<u32>` conversion for codes
+            /// without a named variant. Constructing it with a known code
+            /// yields a value unequal to the named variant, so convert raw
+            /// codes with [`DiscordJsonErrorCode::from`] instead.
+            Other(u32),
+        }
+
+        impl From<u32> for DiscordJsonErrorCode {
+            fn from(value: u32) -> Self {
+                match value {
+                    $($code => Self::$variant,)*
+                    _ => Self::Other(value),
+                }
+            }
+        }
+
+        impl From<DiscordJsonErrorCode> for u32 {
+            fn from(value: DiscordJsonErrorCode) -> Self {
+                match value {
+                    $(DiscordJsonErrorCode::$variant => $code,)*
+                    DiscordJsonErrorCode::Other(other) => other,
+                }
+            }
+        }
+    };
+}
+
+json_error_codes! {
+    /// General error, such as a malformed request body.
+    GeneralError = 0,
+    /// Unknown account.
+    UnknownAccount = 10001,
+    /// Unknown application.
+    UnknownApplication = 10002,
+    /// Unknown channel.
+    UnknownChannel = 10003,
+    /// Unknown guild.
+    UnknownGuild = 10004,
+    /// Unknown integration.
+    UnknownIntegration = 10005,
+    /// Unknown invite.
+    UnknownInvite = 10006,
+    /// Unknown member.
+    UnknownMember = 10007,
+    /// Unknown message.
+    UnknownMessage = 10008,
+    /// Unknown permission overwrite.
+    UnknownPermissionOverwrite = 10009,
+    /// Unknown provider.
+    UnknownProvider = 10010,
+    /// Unknown role.
+    UnknownRole = 10011,
+    /// Unknown token.
+    UnknownToken = 10012,
+    /// Unknown user.
+    UnknownUser = 10013,
+    /// Unknown emoji.
+    UnknownEmoji = 10014,
+    /// Unknown webhook.
+    UnknownWebhook = 10015,
+    /// Unknown webhook service.
+    UnknownWebhookService = 10016,
+    /// Unknown session.
+    UnknownSession = 10020,
+    /// Unknown ban.
+    UnknownBan = 10026,
+    /// Unknown SKU.
+    UnknownSku = 10027,
+    /// Unknown store listing.
+    UnknownStoreListing = 10028,
+    /// Unknown entitlement.
+    UnknownEntitlement = 10029,
+    /// Unknown build.
+    UnknownBuild = 10030,
+    /// Unknown lobby.
+    UnknownLobby = 10031,
+    /// Unknown branch.
+    UnknownBranch = 10032,
+    /// Unknown store directory layout.
+    UnknownStoreDirectoryLayout = 10033,
+    /// Unknown redistributable.
+    UnknownRedistributable = 10036,
+    /// Unknown gift code.
+    UnknownGiftCode = 10038,
+    /// Unknown stream.
+    UnknownStream = 10049,
+    /// Unknown premium server subscribe cooldown.
+    UnknownPremiumServerSubscribeCooldown = 10050,
+    /// Unknown guild template.
+    UnknownGuildTemplate = 10057,
+    /// Unknown discoverable server category.
+    UnknownDiscoverableServerCategory = 10059,
+    /// Unknown sticker.
+    UnknownSticker = 10060,
+    /// Unknown interaction, usually because its token expired.
+    UnknownInteraction = 10062,
+    /// Unknown application command.
+    UnknownApplicationCommand = 10063,
+    /// Unknown voice state.
+    UnknownVoiceState = 10065,
+    /// Unknown application command permissions.
+    UnknownApplicationCommandPermissions = 10066,
+    /// Unknown stage instance.
+    UnknownStageInstance = 10067,
+    /// Unknown guild member verification form.
+    UnknownGuildMemberVerificationForm = 10068,
+    /// Unknown guild welcome screen.
+    UnknownGuildWelcomeScreen = 10069,
+    /// Unknown guild scheduled event.
+    UnknownGuildScheduledEvent = 10070,
+    /// Unknown guild scheduled event user.
+    UnknownGuildScheduledEventUser = 10071,
+    /// Unknown tag.
+    UnknownTag = 10087,
+    /// Bots cannot use this endpoint.
+    BotsCannotUseEndpoint = 20001,
+    /// Only bots can use this endpoint.
+    OnlyBotsCanUseEndpoint = 20002,
+    /// Explicit content cannot be sent to the desired recipient(s).
+    ExplicitContentCannotBeSent = 20009,
+    /// Not authorized to perform this action on this application.
+    NotAuthorizedForApplication = 20012,
+    /// This action cannot be performed due to slowmode rate limit.
+    SlowmodeRateLimit = 20016,
+    /// Only the owner of this account can perform this action.
+    OnlyAccountOwner = 20018,
+    /// This message cannot be edited due to announcement rate limits.
+    AnnouncementEditRateLimit = 20022,
+    /// Under minimum age.
+    UnderMinimumAge = 20024,
+    /// The channel has hit the write rate limit.
+    ChannelWriteRateLimit = 20028,
+    /// The server has hit the write rate limit.
+    GuildWriteRateLimit = 20029,
+    /// The stage topic, server name, server description, or channel names contain words that are not allowed.
+    DisallowedWords = 20031,
+    /// Guild premium subscription level too low.
+    PremiumSubscriptionLevelTooLow = 20035,
+    /// Maximum number of guilds reached (100).
+    MaximumGuilds = 30001,
+    /// Maximum number of friends reached (1000).
+    MaximumFriends = 30002,
+    /// Maximum number of pins reached for the channel (50).
+    MaximumPins = 30003,
+    /// Maximum number of recipients reached (10).
+    MaximumRecipients = 30004,
+    /// Maximum number of guild roles reached (250).
+    MaximumGuildRoles = 30005,
+    /// Maximum number of webhooks reached (15).
+    MaximumWebhooks = 30007,
+    /// Maximum number of emojis reached.
+    MaximumEmojis = 30008,
+    /// Maximum number of reactions reached (20).
+    MaximumReactions = 30010,
+    /// Maximum number of group DMs reached (10).
+    MaximumGroupDms = 30011,
+    /// Maximum number of guild channels reached (500).
+    MaximumGuildChannels = 30013,
+    /// Maximum number of attachments in a message reached (10).
+    MaximumAttachments = 30015,
+    /// Maximum number of invites reached (1000).
+    MaximumInvites = 30016,
+    /// Maximum number of animated emojis reached.
+    MaximumAnimatedEmojis = 30018,
+    /// Maximum number of server members reached.
+    MaximumServerMembers = 30019,
+    /// Maximum number of server categories has been reached (5).
+    MaximumServerCategories = 30030,
+    /// Guild already has a template.
+    GuildAlreadyHasTemplate = 30031,
+    /// Maximum number of application commands reached.
+    MaximumApplicationCommands = 30032,
+    /// Maximum number of thread participants has been reached (1000).
+    MaximumThreadParticipants = 30033,
+    /// Maximum number of daily application command creates has been reached (200).
+    MaximumDailyApplicationCommandCreates = 30034,
+    /// Maximum number of bans for non-guild members have been exceeded.
+    MaximumNonMemberBans = 30035,
+    /// Maximum number of bans fetches has been reached.
+    MaximumBanFetches = 30037,
+    /// Maximum number of uncompleted guild scheduled events reached (100).
+    MaximumUncompletedScheduledEvents = 30038,
+    /// Maximum number of stickers reached.
+    MaximumStickers = 30039,
+    /// Maximum number of prune requests has been reached.
+    MaximumPruneRequests = 30040,
+    /// Maximum number of guild widget settings updates has been reached.
+    MaximumWidgetSettingsUpdates = 30042,
+    /// Maximum number of edits to messages older than 1 hour reached.
+    MaximumOldMessageEdits = 30046,
+    /// Maximum number of pinned threads in a forum channel has been reached.
+    MaximumPinnedForumThreads = 30047,
+    /// Maximum number of tags in a forum channel has been reached.
+    MaximumForumTags = 30048,
+    /// Bitrate is too high for channel of this type.
+    BitrateTooHigh = 30052,
+    /// Maximum number of premium emojis reached (25).
+    MaximumPremiumEmojis = 30056,
+    /// Maximum number of webhooks per guild reached (1000).
+    MaximumGuildWebhooks = 30058,
+    /// Maximum number of channel permission overwrites reached (1000).
+    MaximumPermissionOverwrites = 30061,
+    /// The channels for this guild are too large.
+    GuildChannelsTooLarge = 30062,
+    /// Unauthorized; a valid token must be provided.
+    Unauthorized = 40001,
+    /// The account needs to be verified in order to perform this action.
+    AccountVerificationRequired = 40002,
+    /// Opening direct messages too fast.
+    OpeningDirectMessagesTooFast = 40003,
+    /// Sending messages has been temporarily disabled.
+    SendMessagesTemporarilyDisabled = 40004,
+    /// Request entity too large.
+    RequestEntityTooLarge = 40005,
+    /// This feature has been temporarily disabled server-side.
+    FeatureTemporarilyDisabled = 40006,
+    /// The user is banned from this guild.
+    UserBannedFromGuild = 40007,
+    /// Connection has been revoked.
+    ConnectionRevoked = 40012,
+    /// Target user is not connected to voice.
+    TargetUserNotInVoice = 40032,
+    /// This message has already been crossposted.
+    MessageAlreadyCrossposted = 40033,
+    /// An application command with that name already exists.
+    ApplicationCommandNameExists = 40041,
+    /// Application interaction failed to send.
+    InteractionFailedToSend = 40043,
+    /// Cannot send a message in a forum channel.
+    CannotSendInForumChannel = 40058,
+    /// Interaction has already been acknowledged.
+    InteractionAlreadyAcknowledged = 40060,
+    /// Tag names must be unique.
+    TagNamesMustBeUnique = 40061,
+    /// Service resource is being rate limited.
+    ServiceResourceRateLimited = 40062,
+    /// There are no tags available that can be set by non-moderators.
+    NoTagsAvailableForNonModerators = 40066,
+    /// A tag is required to create a forum post in this channel.
+    TagRequiredForForumPost = 40067,
+    /// Missing access.
+    MissingAccess = 50001,
+    /// Invalid account type.
+    InvalidAccountType = 50002,
+    /// Cannot execute action on a DM channel.
+    CannotExecuteOnDmChannel = 50003,
+    /// Guild widget disabled.
+    GuildWidgetDisabled = 50004,
+    /// Cannot edit a message authored by another user.
+    CannotEditOtherUsersMessage = 50005,
+    /// Cannot send an empty message.
+    CannotSendEmptyMessage = 50006,
+    /// Cannot send messages to this user.
+    CannotMessageUser = 50007,
+    /// Cannot send messages in a non-text channel.
+    CannotSendInNonTextChannel = 50008,
+    /// Channel verification level is too high to gain access.
+    ChannelVerificationLevelTooHigh = 50009,
+    /// OAuth2 application does not have a bot.
+    OAuth2ApplicationHasNoBot = 50010,
+    /// OAuth2 application limit reached.
+    OAuth2ApplicationLimitReached = 50011,
+    /// Invalid OAuth2 state.
+    InvalidOAuth2State = 50012,
+    /// Lacking permissions to perform that action.
+    MissingPermissions = 50013,
+    /// Invalid authentication token provided.
+    InvalidAuthenticationToken = 50014,
+    /// Note was too long.
+    NoteTooLong = 50015,
+    /// Provided too few or too many messages to delete.
+    InvalidBulkDeleteAmount = 50016,
+    /// Invalid MFA level.
+    InvalidMfaLevel = 50017,
+    /// A message can only be pinned to the channel it was sent in.
+    PinInWrongChannel = 50019,
+    /// Invite code was either invalid or taken.
+    InvalidInviteCode = 50020,
+    /// Cannot execute action on a system message.
+    CannotExecuteOnSystemMessage = 50021,
+    /// Cannot execute action on this channel type.
+    CannotExecuteOnChannelType = 50024,
+    /// Invalid OAuth2 access token provided.
+    InvalidOAuth2AccessToken = 50025,
+    /// Missing required OAuth2 scope.
+    MissingOAuth2Scope = 50026,
+    /// Invalid webhook token provided.
+    InvalidWebhookToken = 50027,
+    /// Invalid role.
+    InvalidRole = 50028,
+    /// Invalid recipient(s).
+    InvalidRecipients = 50033,
+    /// A message provided was too old to bulk delete.
+    MessageTooOldToBulkDelete = 50034,
+    /// Invalid form body or invalid `Content-Type` provided.
+    InvalidFormBody = 50035,
+    /// An invite was accepted to a guild the application's bot is not in.
+    InviteAcceptedToGuildWithoutBot = 50036,
+    /// Invalid activity action.
+    InvalidActivityAction = 50039,
+    /// Invalid API version provided.
+    InvalidApiVersion = 50041,
+    /// File uploaded exceeds the maximum size.
+    FileTooLarge = 50045,
+    /// Invalid file uploaded.
+    InvalidFile = 50046,
+    /// Cannot self-redeem this gift.
+    CannotSelfRedeemGift = 50054,
+    /// Invalid guild.
+    InvalidGuild = 50055,
+    /// Invalid SKU.
+    InvalidSku = 50057,
+    /// Invalid request origin.
+    InvalidRequestOrigin = 50067,
+    /// Invalid message type.
+    InvalidMessageType = 50068,
+    /// Payment source required to redeem gift.
+    PaymentSourceRequired = 50070,
+    /// Cannot modify a system webhook.
+    CannotModifySystemWebhook = 50073,
+    /// Cannot delete a channel required for community guilds.
+    CannotDeleteCommunityChannel = 50074,
+    /// Cannot edit stickers within a message.
+    CannotEditMessageStickers = 50080,
+    /// Invalid sticker sent.
+    InvalidSticker = 50081,
+    /// Tried to perform an operation on an archived thread.
+    ThreadArchived = 50083,
+    /// Invalid thread notification settings.
+    InvalidThreadNotificationSettings = 50084,
+    /// The `before` value is earlier than the thread creation date.
+    BeforeEarlierThanThreadCreation = 50085,
+    /// Community server channels must be text channels.
+    CommunityChannelMustBeText = 50086,
+    /// The entity type of the event is different from the entity the event is being started for.
+    ScheduledEventEntityTypeMismatch = 50091,
+    /// This server is not available in your location.
+    ServerUnavailableInLocation = 50095,
+    /// This server needs monetization enabled in order to perform this action.
+    MonetizationRequired = 50097,
+    /// This server needs more boosts to perform this action.
+    MoreBoostsRequired = 50101,
+    /// The request body contains invalid JSON.
+    InvalidJson = 50109,
+    /// Owner cannot be pending member.
+    OwnerCannotBePending = 50131,
+    /// Ownership cannot be transferred to a bot user.
+    CannotTransferOwnershipToBot = 50132,
+    /// Failed to resize asset below the maximum size.
+    AssetResizeFailed = 50138,
+    /// Uploaded file not found.
+    UploadedFileNotFound = 50146,
+    /// No permission to send this sticker.
+    MissingStickerPermission = 50600,
+    /// Two factor authentication is required for this operation.
+    TwoFactorRequired = 60003,
+    /// No users with that DiscordTag exist.
+    NoUsersWithDiscordTag = 80004,
+    /// Reaction was blocked.
+    ReactionBlocked = 90001,
+    /// Application not yet available.
+    ApplicationNotAvailable = 110001,
+    /// API resource is currently overloaded.
+    ApiResourceOverloaded = 130000,
+    /// The stage is already open.
+    StageAlreadyOpen = 150006,
+    /// Cannot reply without permission to read message history.
+    ReplyWithoutReadMessageHistory = 160002,
+    /// A thread has already been created for this message.
+    ThreadAlreadyCreated = 160004,
+    /// Thread is locked.
+    ThreadLocked = 160005,
+    /// Maximum number of active threads reached.
+    MaximumActiveThreads = 160006,
+    /// Maximum number of active announcement threads reached.
+    MaximumActiveAnnouncementThreads = 160007,
+    /// Invalid JSON for uploaded Lottie file.
+    InvalidLottieJson = 170001,
+    /// Uploaded Lotties cannot contain rasterized images such as PNG or JPEG.
+    LottieContainsRasterImages = 170002,
+    /// Sticker maximum framerate exceeded.
+    StickerFramerateExceeded = 170003,
+    /// Sticker frame count exceeds maximum of 1000 frames.
+    StickerFrameCountExceeded = 170004,
+    /// Lottie animation maximum dimensions exceeded.
+    LottieDimensionsExceeded = 170005,
+    /// Sticker frame rate is either too small or too large.
+    InvalidStickerFrameRate = 170006,
+    /// Sticker animation duration exceeds maximum of 5 seconds.
+    StickerDurationExceeded = 170007,
+    /// Cannot update a finished event.
+    CannotUpdateFinishedEvent = 180000,
+    /// Failed to create stage needed for stage event.
+    StageCreationFailed = 180002,
+    /// Message was blocked by automatic moderation.
+    BlockedByAutoModeration = 200000,
+    /// Title was blocked by automatic moderation.
+    TitleBlockedByAutoModeration = 200001,
+    /// Webhooks posted to forum channels must have a `thread_name` or `thread_id`.
+    ForumWebhookMissingThread = 220001,
+    /// Webhooks posted to forum channels cannot have both a `thread_name` and `thread_id`.
+    ForumWebhookThreadConflict = 220002,
+    /// Webhooks can only create threads in forum channels.
+    WebhookThreadsOnlyInForums = 220003,
+    /// Webhook services cannot be used in forum channels.
+    WebhookServicesInForum = 220004,
+    /// Message was blocked by the harmful links filter.
+    BlockedByHarmfulLinksFilter = 240000,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ErrorResponse {
     pub status_code: StatusCode,
@@ -105,14 +544,73 @@ impl Error {
         matches!(self, Self::InvalidHeader(_))
     }
 
-    /// Returns the status code if the error is an unsuccessful request
+    /// Returns true when the error is caused by a request or connecting to
+    /// Discord taking longer than its configured timeout
+    ///
+    /// Unlike most other errors, the request may succeed when retried.
+    ///
+    /// Refer to [`HttpBuilder::request_timeout`] and
+    /// [`HttpBuilder::connect_timeout`].
+    ///
+    /// [`HttpBuilder::request_timeout`]: super::HttpBuilder::request_timeout
+    /// [`HttpBuilder::connect_timeout`]: super::HttpBuilder::connect_timeout
     #[must_use]
-    pub fn status_code(&self) -> Option<StatusCode> {
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Request(error) if error.is_timeout())
+    }
+
+    /// Returns the error response if the error is an unsuccessful request
+    #[must_use]
+    pub fn error_response(&self) -> Option<&ErrorResponse> {
         match self {
-            Self::UnsuccessfulRequest(res) => Some(res.status_code),
+            Self::UnsuccessfulRequest(res) => Some(res),
             _ => None,
         }
     }
+
+    /// Returns the status code if the error is an unsuccessful request
+    #[must_use]
+    pub fn status_code(&self) -> Option<StatusCode> {
+        self.error_response().map(|res| res.status_code)
+    }
+
+    /// Returns the Discord error code if the error is an unsuccessful request
+    ///
+    /// The raw code is available via [`Self::error_response`].
+    #[must_use]
+    pub fn discord_error_code(&self) -> Option<DiscordJsonErrorCode> {
+        self.error_response().and_then(|res| res.error.kind())
+    }
+
+    /// Returns true when the interaction a request was made for is unknown to
+    /// Discord.
+    ///
+    /// This is usually caused by the interaction token having expired: the
+    /// initial response must be sent within 3 seconds, and the token remains
+    /// valid for followups for 15 minutes.
+    #[must_use]
+    pub fn is_unknown_interaction(&self) -> bool {
+        self.discord_error_code() == Some(DiscordJsonErrorCode::UnknownInteraction)
+    }
+
+    /// Returns true when attempting to create the initial response to an
+    /// interaction that has already been responded to or deferred.
+    ///
+    /// A followup message can be sent instead.
+    #[must_use]
+    pub fn is_already_acknowledged(&self) -> bool {
+        self.discord_error_code() == Some(DiscordJsonErrorCode::InteractionAlreadyAcknowledged)
+    }
+
+    /// Returns true when a direct message could not be sent to a user, usually
+    /// because they disabled direct messages from members of the guilds they
+    /// share with the current user.
+    ///
+    /// This only affects the one user, so messaging other users can carry on.
+    #[must_use]
+    pub fn is_cannot_message_user(&self) -> bool {
+        self.discord_error_code() == Some(DiscordJsonErrorCode::CannotMessageUser)
+    }
 }
 
 impl From<ErrorResponse> for Error {
@@ -219,4 +717,68 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[tokio::test]
+    async fn test_timeout() {
+        // Accepts the connection without ever responding.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let client = reqwest::Client::builder()
+            .timeout(std::time::Duration::from_millis(50))
+            .build()
+            .unwrap();
+        let error = Error::from(client.get(url).send().await.unwrap_err());
+
+        assert!(matches!(error, Error::Request(_)));
+        assert!(error.is_timeout());
+    }
+
+    #[test]
+    fn test_interaction_error_codes() {
+        let error_response = |code| ErrorResponse {
+            status_code: reqwest::StatusCode::NOT_FOUND,
+            url: String::from("https://ferris.crab").parse().unwrap(),
+            error: DiscordJsonError {
+                code,
+                message: String::new(),
+                errors: vec![],
+            },
+        };
+
+        let unknown = Error::from(error_response(10062));
+        assert!(matches!(unknown, Error::UnsuccessfulRequest(_)));
+        assert!(unknown.is_unknown_interaction());
+        assert!(!unknown.is_already_acknowledged());
+
+        let acknowledged = Error::from(error_response(40060));
+        assert!(matches!(acknowledged, Error::UnsuccessfulRequest(_)));
+        assert!(acknowledged.is_already_acknowledged());
+        assert!(!acknowledged.is_unknown_interaction());
+
+        let other = Error::from(error_response(10003));
+        assert!(!other.is_unknown_interaction());
+        assert!(!other.is_already_acknowledged());
+        assert!(!other.is_cannot_message_user());
+
+        let cannot_message = Error::from(error_response(50007));
+        assert!(cannot_message.is_cannot_message_user());
+        assert!(!cannot_message.is_unknown_interaction());
+    }
+
+    #[test]
+    fn test_json_error_code_conversion() {
+        assert_eq!(DiscordJsonErrorCode::from(50013), DiscordJsonErrorCode::MissingPermissions);
+        assert_eq!(u32::from(DiscordJsonErrorCode::MaximumWebhooks), 30007);
+        assert_eq!(DiscordJsonErrorCode::from(1), DiscordJsonErrorCode::Other(1));
+        assert_eq!(u32::from(DiscordJsonErrorCode::Other(1)), 1);
+        assert_ne!(DiscordJsonErrorCode::from(50013), DiscordJsonErrorCode::Other(50013));
+
+        let error = DiscordJsonError {
+            code: -1,
+            message: String::new(),
+            errors: vec![],
+        };
+        assert_eq!(error.kind(), None);
+    }
 }
@@ -14,7 +14,8 @@
 //! The former require a [`Client`] to have logged in, while the latter may be
 //! made regardless of any other usage of the library.
 //!
-//! If a request spuriously fails, it will be retried once.
+//! If a request fails due to a transient error, it may be retried according
+//! to the client's [`RetryPolicy`]. By default, requests are not retried.
 //!
 //! Note that you may want to perform requests through a [model]s'
 //! instance methods where possible, as they each offer different
@@ -28,6 +29,7 @@ pub mod error;
 pub mod multipart;
 pub mod ratelimiting;
 pub mod request;
+pub mod retry;
 pub mod routing;
 pub mod typing;
 mod utils;
@@ -40,6 +42,7 @@ pub use reqwest::StatusCode;
 pub use self::client::*;
 pub use self::error::Error as HttpError;
 use self::request::Request;
+pub use self::retry::RetryPolicy;
 pub use self::typing::*;
 #[cfg(feature = "cache")]
 use crate::cache::Cache;
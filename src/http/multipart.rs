@@ -21,16 +21,75 @@ pub struct Multipart<'a> {
     pub payload_json: Option<Value>,
 }
 
+/// A single part of a [`Multipart`] form, as returned by [`Multipart::parts`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct MultipartPart {
+    /// The name of the part, e.g. `file`, `file1` or `payload_json`.
+    pub name: String,
+    /// The filename of a file part.
+    pub filename: Option<String>,
+    /// The content type of a file part, guessed from its filename.
+    pub content_type: Option<String>,
+    /// The value of a text part, such as the stringified `payload_json`.
+    pub value: Option<String>,
+}
+
 impl<'a> Multipart<'a> {
+    /// Describes the parts of the form that is sent, without reading the
+    /// contents of the files.
+    ///
+    /// This is useful to debug why Discord rejects an upload, e.g. when an
+    /// embed references an `attachment://` filename that is not uploaded. It
+    /// is best used from a callback set via [`HttpBuilder::request_callback`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Url`] if the filename of an image URL cannot be
+    /// determined, or an [`Error::Json`] if the `payload_json` cannot be
+    /// serialized.
+    ///
+    /// [`HttpBuilder::request_callback`]: super::HttpBuilder::request_callback
+    pub fn parts(&self) -> Result<Vec<MultipartPart>> {
+        let mut parts = Vec::with_capacity(self.files.len() + self.fields.len() + 1);
+
+        for (file_num, file) in self.files.iter().enumerate() {
+            let filename = file.filename()?;
+
+            parts.push(MultipartPart {
+                name: part_name(file_num),
+                content_type: filename.as_deref().map(|f| guess_mime(f).essence_str().to_string()),
+                filename,
+                value: None,
+            });
+        }
+
+        for (name, value) in &self.fields {
+            parts.push(MultipartPart {
+                name: name.to_string(),
+                filename: None,
+                content_type: None,
+                value: Some(value.to_string()),
+            });
+        }
+
+        if let Some(ref payload_json) = self.payload_json {
+            parts.push(MultipartPart {
+                name: "payload_json".to_string(),
+                filename: None,
+                content_type: None,
+                value: Some(json::to_string(payload_json)?),
+            });
+        }
+
+        Ok(parts)
+    }
+
     pub(crate) async fn build_form(&mut self, client: &Client) -> Result<Form> {
         let mut multipart = Form::new();
 
         for (file_num, file) in self.files.iter_mut().enumerate() {
-            // For endpoints that require a single file (e.g. create sticker),
-            // it will error if the part name is not `file`.
-            // https://github.com/discord/discord-api-docs/issues/2064#issuecomment-691650970
-            let part_name =
-                if file_num == 0 { "file".to_string() } else { format!("file{}", file_num) };
+            let part_name = part_name(file_num);
 
             let data = file.data(client).await?;
             let filename = file.filename()?;
@@ -64,6 +123,21 @@ impl<'a> Multipart<'a> {
     }
 }
 
+fn part_name(file_num: usize) -> String {
+    // For endpoints that require a single file (e.g. create sticker),
+    // it will error if the part name is not `file`.
+    // https://github.com/discord/discord-api-docs/issues/2064#issuecomment-691650970
+    if file_num == 0 {
+        "file".to_string()
+    } else {
+        format!("file{}", file_num)
+    }
+}
+
+fn guess_mime(filename: &str) -> mime_guess::Mime {
+    mime_guess::from_path(filename).first_or_octet_stream()
+}
+
 fn guess_mime_str(part: Part, filename: &str) -> Result<Part> {
     // This is required for certain endpoints like create sticker, otherwise
     // the Discord API will respond with a 500 Internal Server Error.
@@ -71,6 +145,35 @@ fn guess_mime_str(part: Part, filename: &str) -> Result<Part> {
     // using Part::file(), but it is not done for any of the other methods we
     // use.
     // https://datatracker.ietf.org/doc/html/rfc7578#section-4.4
-    let mime_type = mime_guess::from_path(filename).first_or_octet_stream();
+    let mime_type = guess_mime(filename);
     part.mime_str(mime_type.essence_str()).map_err(Into::into)
 }
+
+#[cfg(test)]
+mod test {
+    use super::Multipart;
+    use crate::json::json;
+    use crate::model::channel::AttachmentType;
+
+    #[test]
+    fn test_multipart_parts() {
+        let multipart = Multipart {
+            files: vec![
+                AttachmentType::from(("data".as_bytes(), "image.png")),
+                AttachmentType::from(("data".as_bytes(), "notes.txt")),
+            ],
+            fields: vec![("name".into(), "sticker".into())],
+            payload_json: Some(json!({"content": "hello"})),
+        };
+
+        let parts = multipart.parts().unwrap();
+        let names = parts.iter().map(|p| p.name.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["file", "file1", "name", "payload_json"]);
+
+        assert_eq!(parts[0].filename.as_deref(), Some("image.png"));
+        assert_eq!(parts[0].content_type.as_deref(), Some("image/png"));
+        assert_eq!(parts[1].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(parts[2].value.as_deref(), Some("sticker"));
+        assert_eq!(parts[3].value.as_deref(), Some(r#"{"content":"hello"}"#));
+    }
+}
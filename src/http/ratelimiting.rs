@@ -46,7 +46,7 @@ use std::sync::Arc;
 use std::time::SystemTime;
 
 use reqwest::header::HeaderMap;
-use reqwest::{Client, Response, StatusCode};
+use reqwest::{Client, Response, StatusCode, Url};
 use tokio::sync::{Mutex, RwLock};
 use tokio::time::{sleep, Duration};
 use tracing::{debug, instrument};
@@ -94,6 +94,7 @@ pub struct Ratelimiter {
     // when the 'reset' passes.
     routes: Arc<RwLock<HashMap<Route, Arc<Mutex<Ratelimit>>>>>,
     token: String,
+    proxy: Option<Url>,
     ratelimit_callback: Box<dyn Fn(RatelimitInfo) + Send + Sync>,
 }
 
@@ -103,6 +104,7 @@ impl fmt::Debug for Ratelimiter {
             .field("client", &self.client)
             .field("global", &self.global)
             .field("routes", &self.routes)
+            .field("proxy", &self.proxy)
             .finish()
     }
 }
@@ -123,10 +125,25 @@ impl Ratelimiter {
             global: Arc::default(),
             routes: Arc::default(),
             token,
+            proxy: None,
             ratelimit_callback: Box::new(|_| {}),
         }
     }
 
+    /// Sets the proxy that requests are sent to instead of Discord, as
+    /// described in [`HttpBuilder::proxy`].
+    ///
+    /// The ratelimiter still tracks Discord's rate limits, as proxies forward
+    /// the rate limit headers. It can be disabled via
+    /// [`HttpBuilder::ratelimiter_disabled`] if the proxy handles rate limits
+    /// itself.
+    ///
+    /// [`HttpBuilder::proxy`]: super::HttpBuilder::proxy
+    /// [`HttpBuilder::ratelimiter_disabled`]: super::HttpBuilder::ratelimiter_disabled
+    pub fn set_proxy(&mut self, proxy: Option<Url>) {
+        self.proxy = proxy;
+    }
+
     /// Sets a callback to be called when a route is rate limited.
     pub fn set_ratelimit_callback(
         &mut self,
@@ -204,7 +221,8 @@ impl Ratelimiter {
 
             bucket.lock().await.pre_hook(&req.route, &self.ratelimit_callback).await;
 
-            let request = req.build(&self.client, &self.token, None).await?.build()?;
+            let request =
+                req.build(&self.client, &self.token, self.proxy.as_ref()).await?.build()?;
 
             let response = self.client.execute(request).await?;
 
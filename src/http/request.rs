@@ -108,7 +108,9 @@ impl<'a> Request<'a> {
         let (method, _, mut path) = route_info.deconstruct();
 
         if let Some(proxy) = proxy {
-            path = Cow::Owned(path.to_mut().replace("https://discord.com/", proxy.as_str()));
+            if let Some(rest) = path.strip_prefix("https://discord.com") {
+                path = Cow::Owned(format!("{}{}", proxy.as_str().trim_end_matches('/'), rest));
+            }
         }
 
         let mut builder =
@@ -157,6 +159,11 @@ impl<'a> Request<'a> {
         &mut self.body
     }
 
+    #[must_use]
+    pub fn multipart_ref(&self) -> &Option<Multipart<'a>> {
+        &self.multipart
+    }
+
     #[must_use]
     pub fn headers_ref(&self) -> &Option<Headers> {
         &self.headers
@@ -177,3 +184,27 @@ impl<'a> Request<'a> {
         &mut self.route
     }
 }
+
+#[cfg(test)]
+mod test {
+    use reqwest::{Client, Url};
+
+    use super::RequestBuilder;
+    use crate::http::routing::RouteInfo;
+
+    #[tokio::test]
+    async fn test_proxy_base_url() {
+        let client = Client::new();
+
+        for (proxy, expected) in [
+            ("http://127.0.0.1:3000", "http://127.0.0.1:3000/api/v10/users/@me"),
+            ("http://127.0.0.1:3000/discord/", "http://127.0.0.1:3000/discord/api/v10/users/@me"),
+        ] {
+            let proxy = Url::parse(proxy).unwrap();
+            let mut request = RequestBuilder::new(RouteInfo::GetCurrentUser).build();
+            let request = request.build(&client, "token", Some(&proxy)).await.unwrap();
+
+            assert_eq!(request.build().unwrap().url().as_str(), expected);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::time::Duration;
+
+use reqwest::{Response, StatusCode};
+
+use super::{HttpError, LightMethod};
+use crate::internal::prelude::*;
+
+/// A policy deciding whether a request that failed due to a transient error
+/// is retried, and how long to wait before doing so.
+///
+/// Idempotent requests (`GET`, `PUT` and `DELETE`) are retried when Discord
+/// responds with a `500`, `502`, `503` or `504` status code, or when no
+/// response was received at all, e.g. because the request timed out. Other
+/// requests are only retried when the connection could not be established, as
+/// Discord may already have performed the request otherwise.
+///
+/// Requests uploading files are never retried, as their files can only be
+/// read once.
+///
+/// By default, [`Http`] does not retry requests. Use
+/// [`HttpBuilder::retry_policy`] to enable retrying.
+///
+/// The delay between attempts doubles with every retry, starting at
+/// [`Self::base_delay`] and capped at [`Self::max_delay`].
+///
+/// # Examples
+///
+/// Retry requests up to 5 times in total, starting with a delay of a second:
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use serenity::http::{HttpBuilder, RetryPolicy};
+/// let policy = RetryPolicy::new().max_attempts(5).base_delay(Duration::from_secs(1));
+///
+/// let http = HttpBuilder::new("token").retry_policy(policy).build();
+/// ```
+///
+/// [`Http`]: super::Http
+/// [`HttpBuilder::retry_policy`]: super::HttpBuilder::retry_policy
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u8,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Creates a retry policy which attempts a request up to 3 times, starting
+    /// with a delay of 500 milliseconds and waiting at most 5 seconds between
+    /// attempts.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+
+    /// Creates a retry policy that never retries requests, which is used by
+    /// [`Http`] by default and returned by [`RetryPolicy::default`].
+    ///
+    /// [`Http`]: super::Http
+    #[must_use]
+    pub fn disabled() -> Self {
+        Self::new().max_attempts(1)
+    }
+
+    /// Sets the maximum number of times a request is attempted, including the
+    /// first attempt. A value of `0` or `1` disables retrying.
+    #[must_use]
+    pub fn max_attempts(mut self, max_attempts: u8) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Sets the delay before the first retry.
+    #[must_use]
+    pub fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+
+        self
+    }
+
+    /// Sets the maximum delay between two attempts.
+    #[must_use]
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Whether the policy retries any requests at all.
+    #[must_use]
+    pub fn is_enabled(&self) -> bool {
+        self.max_attempts > 1
+    }
+
+    /// Returns the delay to wait before the given retry, starting at `1` for
+    /// the first retry.
+    #[must_use]
+    pub fn delay(&self, retry: u8) -> Duration {
+        let factor = 1_u32.checked_shl(u32::from(retry.saturating_sub(1))).unwrap_or(u32::MAX);
+
+        self.base_delay.saturating_mul(factor).min(self.max_delay)
+    }
+
+    /// Whether a request using the given method should be retried, where
+    /// `status` is the status code of the response, or [`None`] if no response
+    /// was received.
+    ///
+    /// Regardless of this, requests that failed to connect are retried, as
+    /// they were never sent.
+    #[must_use]
+    pub fn is_retryable(&self, method: LightMethod, status: Option<StatusCode>) -> bool {
+        is_idempotent(method) && status.map_or(true, is_transient)
+    }
+
+    /// Returns the delay to wait before attempting a request again, or [`None`]
+    /// if the result of the given attempt should be returned as is.
+    pub(crate) fn retry_delay(
+        &self,
+        method: LightMethod,
+        attempt: u8,
+        result: &Result<Response>,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let status = match result {
+            Ok(response) => Some(response.status()),
+            Err(Error::Http(error)) => match &**error {
+                HttpError::Request(error) if error.is_connect() => {
+                    return Some(self.delay(attempt));
+                },
+                HttpError::Request(error) if error.is_timeout() || error.is_request() => None,
+                _ => return None,
+            },
+            Err(_) => return None,
+        };
+
+        self.is_retryable(method, status).then(|| self.delay(attempt))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+fn is_idempotent(method: LightMethod) -> bool {
+    matches!(method, LightMethod::Get | LightMethod::Put | LightMethod::Delete)
+}
+
+fn is_transient(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use reqwest::StatusCode;
+
+    use super::RetryPolicy;
+    use crate::http::LightMethod;
+
+    #[test]
+    fn test_retryable() {
+        let policy = RetryPolicy::new();
+
+        for method in [LightMethod::Get, LightMethod::Put, LightMethod::Delete] {
+            assert!(policy.is_retryable(method, Some(StatusCode::BAD_GATEWAY)));
+            assert!(policy.is_retryable(method, None));
+            assert!(!policy.is_retryable(method, Some(StatusCode::NOT_IMPLEMENTED)));
+            assert!(!policy.is_retryable(method, Some(StatusCode::BAD_REQUEST)));
+        }
+
+        for method in [LightMethod::Post, LightMethod::Patch] {
+            assert!(!policy.is_retryable(method, Some(StatusCode::SERVICE_UNAVAILABLE)));
+            assert!(!policy.is_retryable(method, None));
+        }
+    }
+
+    #[test]
+    fn test_delay() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .max_delay(Duration::from_millis(1000));
+
+        assert_eq!(policy.delay(1), Duration::from_millis(100));
+        assert_eq!(policy.delay(2), Duration::from_millis(200));
+        assert_eq!(policy.delay(4), Duration::from_millis(800));
+        assert_eq!(policy.delay(5), Duration::from_millis(1000));
+        assert_eq!(policy.delay(u8::MAX), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_disabled() {
+        assert!(RetryPolicy::new().is_enabled());
+        assert!(!RetryPolicy::disabled().is_enabled());
+        assert_eq!(RetryPolicy::default(), RetryPolicy::disabled());
+        assert!(!RetryPolicy::new().max_attempts(0).is_enabled());
+    }
+}
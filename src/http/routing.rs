@@ -87,6 +87,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsIdPinsMessageId(u64),
+    /// Route for the `/channels/:channel_id/recipients/:user_id` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdRecipientsId(u64),
     /// Route for the `/channels/:channel_id/message/:message_id/crosspost` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -337,6 +343,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplatesCode(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -373,6 +391,8 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdThreadsActive,
+    /// Route for the `/guilds/templates/:code` path.
+    GuildsTemplatesCode,
     /// Route for the `/invites/:code` path.
     InvitesCode,
     /// Route for the `/sticker-packs` path.
@@ -449,6 +469,13 @@ pub enum Route {
     ///
     /// [`ApplicationId`]: crate::model::id::ApplicationId
     ApplicationsIdGuildsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/role-connections/metadata`
+    /// path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdRoleConnectionsMetadata(u64),
     /// Route for the `/stage-instances` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -550,6 +577,22 @@ impl Route {
         url
     }
 
+    #[must_use]
+    pub fn channel_message_reactions_list_of_kind(
+        channel_id: u64,
+        message_id: u64,
+        reaction: &str,
+        kind: u8,
+        limit: u8,
+        after: Option<u64>,
+    ) -> String {
+        let mut url =
+            Route::channel_message_reactions_list(channel_id, message_id, reaction, limit, after);
+        write!(url, "&type={}", kind).unwrap();
+
+        url
+    }
+
     #[must_use]
     pub fn channel_messages(channel_id: u64, query: Option<&str>) -> String {
         api!("/channels/{}/messages{}", channel_id, query.unwrap_or(""))
@@ -580,6 +623,11 @@ impl Route {
         api!("/channels/{}/pins", channel_id)
     }
 
+    #[must_use]
+    pub fn channel_recipient(channel_id: u64, user_id: u64) -> String {
+        api!("/channels/{}/recipients/{}", channel_id, user_id)
+    }
+
     #[must_use]
     pub fn channel_typing(channel_id: u64) -> String {
         api!("/channels/{}/typing", channel_id)
@@ -615,6 +663,30 @@ impl Route {
         api!("/channels/{}/thread-members", channel_id)
     }
 
+    #[must_use]
+    pub fn channel_thread_members_optioned(
+        channel_id: u64,
+        with_member: bool,
+        after: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = api!("/channels/{}/thread-members?", channel_id);
+
+        if with_member {
+            s.push_str("&with_member=true");
+        }
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
     #[must_use]
     pub fn channel_archived_public_threads(
         channel_id: u64,
@@ -924,6 +996,16 @@ impl Route {
         api!("/guilds/{}/stickers", guild_id)
     }
 
+    #[must_use]
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        api!("/guilds/{}/templates/{}", guild_id, code)
+    }
+
+    #[must_use]
+    pub fn guild_templates(guild_id: u64) -> String {
+        api!("/guilds/{}/templates", guild_id)
+    }
+
     #[must_use]
     pub fn guild_vanity_url(guild_id: u64) -> String {
         api!("/guilds/{}/vanity-url", guild_id)
@@ -959,6 +1041,11 @@ impl Route {
         api!("/guilds")
     }
 
+    #[must_use]
+    pub fn template(code: &str) -> String {
+        api!("/guilds/templates/{}", code)
+    }
+
     #[must_use]
     pub fn invite(code: &str) -> String {
         api!("/invites/{}", code)
@@ -983,6 +1070,16 @@ impl Route {
         )
     }
 
+    #[must_use]
+    pub fn application_current() -> &'static str {
+        api!("/applications/@me")
+    }
+
+    #[must_use]
+    pub fn application_role_connection_metadata(application_id: u64) -> String {
+        api!("/applications/{}/role-connections/metadata", application_id)
+    }
+
     #[must_use]
     pub fn oauth2_application_current() -> &'static str {
         api!("/oauth2/applications/@me")
@@ -1067,6 +1164,19 @@ impl Route {
         s
     }
 
+    #[must_use]
+    pub fn user_guilds_with_counts_optioned<D: Display>(
+        target: D,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = Route::user_guilds_optioned(target, after, before, limit);
+        s.push_str("&with_counts=true");
+
+        s
+    }
+
     #[must_use]
     pub fn voice_regions() -> &'static str {
         api!("/voice/regions")
@@ -1185,6 +1295,10 @@ impl Route {
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum RouteInfo<'a> {
+    AddGroupRecipient {
+        group_id: u64,
+        user_id: u64,
+    },
     AddGuildMember {
         guild_id: u64,
         user_id: u64,
@@ -1238,10 +1352,16 @@ pub enum RouteInfo<'a> {
         application_id: u64,
         guild_id: u64,
     },
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
     },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
     CreateInteractionResponse {
         interaction_id: u64,
         interaction_token: &'a str,
@@ -1309,6 +1429,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -1373,6 +1497,10 @@ pub enum RouteInfo<'a> {
     EditChannel {
         channel_id: u64,
     },
+    EditApplicationRoleConnectionMetadata {
+        application_id: u64,
+    },
+    EditCurrentApplicationInfo,
     EditStageInstance {
         channel_id: u64,
     },
@@ -1502,6 +1630,9 @@ pub enum RouteInfo<'a> {
         user_id: u64,
     },
     GetActiveMaintenance,
+    GetApplicationRoleConnectionMetadata {
+        application_id: u64,
+    },
     GetAuditLogs {
         action_type: Option<u8>,
         before: Option<u64>,
@@ -1537,6 +1668,9 @@ pub enum RouteInfo<'a> {
     },
     GetChannelThreadMembers {
         channel_id: u64,
+        with_member: bool,
+        after: Option<u64>,
+        limit: Option<u64>,
     },
     GetChannelArchivedPublicThreads {
         channel_id: u64,
@@ -1652,6 +1786,9 @@ pub enum RouteInfo<'a> {
     GetGuildStickers {
         guild_id: u64,
     },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
     GetGuildVanityUrl {
         guild_id: u64,
     },
@@ -1663,12 +1800,20 @@ pub enum RouteInfo<'a> {
         before: Option<u64>,
         limit: Option<u64>,
     },
+    GetGuildsWithCounts {
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    },
     GetInvite {
         code: &'a str,
         member_counts: bool,
         expiration: bool,
         event_id: Option<u64>,
     },
+    GetTemplate {
+        code: &'a str,
+    },
     GetMember {
         guild_id: u64,
         user_id: u64,
@@ -1691,6 +1836,14 @@ pub enum RouteInfo<'a> {
         message_id: u64,
         reaction: String,
     },
+    GetReactionUsersOfKind {
+        after: Option<u64>,
+        channel_id: u64,
+        kind: u8,
+        limit: u8,
+        message_id: u64,
+        reaction: String,
+    },
     GetSticker {
         sticker_id: u64,
     },
@@ -1737,6 +1890,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         user_id: u64,
     },
+    RemoveGroupRecipient {
+        group_id: u64,
+        user_id: u64,
+    },
     RemoveMemberRole {
         guild_id: u64,
         role_id: u64,
@@ -1758,6 +1915,10 @@ pub enum RouteInfo<'a> {
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
+    SyncGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     UnpinMessage {
         channel_id: u64,
         message_id: u64,
@@ -1768,6 +1929,14 @@ impl<'a> RouteInfo<'a> {
     #[must_use]
     pub fn deconstruct(&self) -> (LightMethod, Route, Cow<'_, str>) {
         match *self {
+            RouteInfo::AddGroupRecipient {
+                group_id,
+                user_id,
+            } => (
+                LightMethod::Put,
+                Route::ChannelsIdRecipientsId(group_id),
+                Cow::from(Route::channel_recipient(group_id, user_id)),
+            ),
             RouteInfo::AddGuildMember {
                 guild_id,
                 user_id,
@@ -1886,6 +2055,9 @@ impl<'a> RouteInfo<'a> {
                 Route::ApplicationsIdGuildsIdCommands(application_id),
                 Cow::from(Route::application_guild_commands(application_id, guild_id)),
             ),
+            RouteInfo::CreateGuildFromTemplate {
+                code,
+            } => (LightMethod::Post, Route::GuildsTemplatesCode, Cow::from(Route::template(code))),
             RouteInfo::CreateGuildIntegration {
                 guild_id,
                 integration_id,
@@ -1894,6 +2066,13 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::CreateGuildTemplate {
+                guild_id,
+            } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::CreateInteractionResponse {
                 interaction_id,
                 interaction_token,
@@ -2047,6 +2226,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate {
+                guild_id,
+                code,
+            } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::DeleteInvite {
                 code,
             } => (LightMethod::Delete, Route::InvitesCode, Cow::from(Route::invite(code))),
@@ -2327,6 +2514,16 @@ impl<'a> RouteInfo<'a> {
                     interaction_token,
                 )),
             ),
+            RouteInfo::EditApplicationRoleConnectionMetadata {
+                application_id,
+            } => (
+                LightMethod::Put,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connection_metadata(application_id)),
+            ),
+            RouteInfo::EditCurrentApplicationInfo => {
+                (LightMethod::Patch, Route::None, Cow::from(Route::application_current()))
+            },
             RouteInfo::EditProfile => {
                 (LightMethod::Patch, Route::UsersMe, Cow::from(Route::user("@me")))
             },
@@ -2417,6 +2614,13 @@ impl<'a> RouteInfo<'a> {
                 Route::FollowNewsChannel(channel_id),
                 Cow::from(Route::channel_follow_news(channel_id)),
             ),
+            RouteInfo::GetApplicationRoleConnectionMetadata {
+                application_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connection_metadata(application_id)),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -2490,10 +2694,18 @@ impl<'a> RouteInfo<'a> {
             ),
             RouteInfo::GetChannelThreadMembers {
                 channel_id,
+                with_member,
+                after,
+                limit,
             } => (
                 LightMethod::Get,
                 Route::ChannelsIdThreadMembers(channel_id),
-                Cow::from(Route::channel_thread_members(channel_id)),
+                Cow::from(Route::channel_thread_members_optioned(
+                    channel_id,
+                    with_member,
+                    after,
+                    limit,
+                )),
             ),
             RouteInfo::GetChannelArchivedPublicThreads {
                 channel_id,
@@ -2740,6 +2952,13 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdStickers(guild_id),
                 Cow::from(Route::guild_stickers(guild_id)),
             ),
+            RouteInfo::GetGuildTemplates {
+                guild_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
             RouteInfo::GetGuildVanityUrl {
                 guild_id,
             } => (
@@ -2763,6 +2982,15 @@ impl<'a> RouteInfo<'a> {
                 Route::UsersMeGuilds,
                 Cow::from(Route::user_guilds_optioned("@me", after, before, limit)),
             ),
+            RouteInfo::GetGuildsWithCounts {
+                after,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::UsersMeGuilds,
+                Cow::from(Route::user_guilds_with_counts_optioned("@me", after, before, limit)),
+            ),
             RouteInfo::GetInvite {
                 code,
                 member_counts,
@@ -2773,6 +3001,9 @@ impl<'a> RouteInfo<'a> {
                 Route::InvitesCode,
                 Cow::from(Route::invite_optioned(code, member_counts, expiration, event_id)),
             ),
+            RouteInfo::GetTemplate {
+                code,
+            } => (LightMethod::Get, Route::GuildsTemplatesCode, Cow::from(Route::template(code))),
             RouteInfo::GetMember {
                 guild_id,
                 user_id,
@@ -2817,6 +3048,20 @@ impl<'a> RouteInfo<'a> {
                     channel_id, message_id, reaction, limit, after,
                 )),
             ),
+            RouteInfo::GetReactionUsersOfKind {
+                after,
+                channel_id,
+                kind,
+                limit,
+                message_id,
+                ref reaction,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdMessagesIdReactions(channel_id),
+                Cow::from(Route::channel_message_reactions_list_of_kind(
+                    channel_id, message_id, reaction, kind, limit, after,
+                )),
+            ),
             RouteInfo::GetScheduledEvent {
                 guild_id,
                 event_id,
@@ -2928,6 +3173,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdBansUserId(guild_id),
                 Cow::from(Route::guild_ban(guild_id, user_id)),
             ),
+            RouteInfo::RemoveGroupRecipient {
+                group_id,
+                user_id,
+            } => (
+                LightMethod::Delete,
+                Route::ChannelsIdRecipientsId(group_id),
+                Cow::from(Route::channel_recipient(group_id, user_id)),
+            ),
             RouteInfo::RemoveMemberRole {
                 guild_id,
                 role_id,
@@ -2971,6 +3224,14 @@ impl<'a> RouteInfo<'a> {
             RouteInfo::GetUpcomingMaintenances | RouteInfo::StatusMaintenancesUpcoming => {
                 (LightMethod::Get, Route::None, Cow::from(Route::status_maintenances_upcoming()))
             },
+            RouteInfo::SyncGuildTemplate {
+                guild_id,
+                code,
+            } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::UnpinMessage {
                 channel_id,
                 message_id,
@@ -1,4 +1,4 @@
-use std::io::Read;
+use std::io::{Error as IoError, ErrorKind as IoErrorKind, Read};
 
 use async_trait::async_trait;
 use async_tungstenite::tungstenite::Message;
@@ -14,7 +14,9 @@ use crate::json::{from_str, to_string};
 
 #[async_trait]
 pub trait ReceiverExt {
-    async fn recv_json(&mut self) -> Result<Option<Value>>;
+    /// Receives a payload, skipping it if it exceeds the given size after
+    /// decompression.
+    async fn recv_json(&mut self, max_size: Option<usize>) -> Result<Option<Value>>;
 }
 
 #[async_trait]
@@ -24,7 +26,7 @@ pub trait SenderExt {
 
 #[async_trait]
 impl ReceiverExt for WsStream {
-    async fn recv_json(&mut self) -> Result<Option<Value>> {
+    async fn recv_json(&mut self, max_size: Option<usize>) -> Result<Option<Value>> {
         const TIMEOUT: tokio::time::Duration = tokio::time::Duration::from_millis(500);
 
         let ws_message = match timeout(TIMEOUT, self.next()).await {
@@ -33,7 +35,7 @@ impl ReceiverExt for WsStream {
             Ok(None) | Err(_) => None,
         };
 
-        convert_ws_message(ws_message)
+        convert_ws_message(ws_message, max_size)
     }
 }
 
@@ -45,17 +47,42 @@ impl SenderExt for WsStream {
 }
 
 #[inline]
-pub(crate) fn convert_ws_message(message: Option<Message>) -> Result<Option<Value>> {
+pub(crate) fn convert_ws_message(
+    message: Option<Message>,
+    max_size: Option<usize>,
+) -> Result<Option<Value>> {
     const DECOMPRESSION_MULTIPLIER: usize = 3;
 
     Ok(match message {
         Some(Message::Binary(bytes)) => {
-            let mut decompressed = String::with_capacity(bytes.len() * DECOMPRESSION_MULTIPLIER);
+            let capacity = bytes.len() * DECOMPRESSION_MULTIPLIER;
+            let mut decompressed =
+                Vec::with_capacity(max_size.map_or(capacity, |max| capacity.min(max)));
 
-            ZlibDecoder::new(&bytes[..]).read_to_string(&mut decompressed).map_err(|why| {
+            // Only decompress up to one byte more than the maximum size, to
+            // detect oversized payloads without decompressing them entirely.
+            // The bytes are only checked to be valid UTF-8 afterwards, as the
+            // limit may cut a multibyte character in half.
+            let limit = max_size.map_or(u64::MAX, |max| max as u64 + 1);
+
+            ZlibDecoder::new(&bytes[..]).take(limit).read_to_end(&mut decompressed).map_err(
+                |why| {
+                    warn!("Err decompressing bytes: {:?}; bytes: {:?}", why, bytes);
+
+                    why
+                },
+            )?;
+
+            if let Some(max) = max_size.filter(|max| decompressed.len() > *max) {
+                warn!("Skipping payload exceeding the maximum size of {} bytes", max);
+
+                return Ok(None);
+            }
+
+            let mut decompressed = String::from_utf8(decompressed).map_err(|why| {
                 warn!("Err decompressing bytes: {:?}; bytes: {:?}", why, bytes);
 
-                why
+                IoError::new(IoErrorKind::InvalidData, why)
             })?;
 
             from_str(decompressed.as_mut_str()).map(Some).map_err(|why| {
@@ -64,6 +91,11 @@ pub(crate) fn convert_ws_message(message: Option<Message>) -> Result<Option<Valu
                 why
             })?
         },
+        Some(Message::Text(payload)) if max_size.map_or(false, |max| payload.len() > max) => {
+            warn!("Skipping payload of {} bytes exceeding the maximum size", payload.len());
+
+            None
+        },
         Some(Message::Text(mut payload)) => from_str(&mut payload).map(Some).map_err(|why| {
             warn!("Err deserializing text: {:?}; text: {}", why, payload,);
 
@@ -90,3 +122,49 @@ pub(crate) async fn create_client(url: Url) -> Result<WsStream> {
 
     Ok(stream)
 }
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use async_tungstenite::tungstenite::Message;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+
+    use super::convert_ws_message;
+
+    #[test]
+    fn test_convert_ws_message_max_size() {
+        let payload = r#"{"op":11,"d":null}"#;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(payload.as_bytes()).unwrap();
+        let bytes = encoder.finish().unwrap();
+
+        let binary = || Some(Message::Binary(bytes.clone()));
+        assert!(convert_ws_message(binary(), None).unwrap().is_some());
+        assert!(convert_ws_message(binary(), Some(payload.len())).unwrap().is_some());
+        assert!(convert_ws_message(binary(), Some(payload.len() - 1)).unwrap().is_none());
+
+        let text = || Some(Message::Text(payload.to_string()));
+        assert!(convert_ws_message(text(), Some(payload.len())).unwrap().is_some());
+        assert!(convert_ws_message(text(), Some(payload.len() - 1)).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_convert_ws_message_max_size_multibyte() {
+        let payload = r#"{"op":0,"d":"ééé"}"#;
+
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(payload.as_bytes()).unwrap();
+        let bytes = encoder.finish().unwrap();
+
+        // The limit of one byte more than the maximum size cuts the first `é`
+        // in half, which must still be skipped rather than fail to decode.
+        let max_size = payload.find('é').unwrap();
+
+        let binary = || Some(Message::Binary(bytes.clone()));
+        assert!(convert_ws_message(binary(), Some(max_size)).unwrap().is_none());
+        assert!(convert_ws_message(binary(), Some(payload.len())).unwrap().is_some());
+    }
+}
@@ -4,11 +4,27 @@ pub mod command;
 pub mod component;
 pub mod interaction;
 pub mod oauth;
+pub mod role_connection;
+
+#[cfg(feature = "model")]
+use std::collections::HashMap;
 
 use self::oauth::Scope;
 use super::id::{snowflake, ApplicationId, GuildId, SkuId, UserId};
 use super::user::User;
 use super::Permissions;
+#[cfg(feature = "model")]
+use crate::builder::EditApplication;
+#[cfg(feature = "model")]
+use crate::constants;
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::json;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
 
 /// Partial information about the given application.
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -57,6 +73,77 @@ pub struct CurrentApplicationInfo {
     pub install_params: Option<InstallParams>,
     #[serde(default)]
     pub custom_install_url: Option<String>,
+    /// The URL Discord sends interactions to, if they are not received over
+    /// the gateway.
+    #[serde(default)]
+    pub interactions_endpoint_url: Option<String>,
+    /// The URL used to verify linked roles of the application.
+    #[serde(default)]
+    pub role_connections_verification_url: Option<String>,
+}
+
+#[cfg(feature = "model")]
+impl CurrentApplicationInfo {
+    /// Edits the current application's information.
+    ///
+    /// Refer to [`EditApplication`]'s documentation for its methods.
+    ///
+    /// # Examples
+    ///
+    /// Change the description and tags:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::new("token");
+    /// let mut info = http.get_current_application_info().await?;
+    ///
+    /// info.edit(&http, |a| a.description("A friendly bot.").tags(["utility", "fun"])).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ApplicationTagAmount`] if more than 5 tags are
+    /// set, or a [`ModelError::ApplicationDescriptionTooLong`] if the
+    /// description is over 400 characters.
+    ///
+    /// Otherwise returns an [`Error::Http`] if an invalid value is set.
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditApplication) -> &mut EditApplication,
+    {
+        let mut edit_application = EditApplication(HashMap::new());
+        f(&mut edit_application);
+        let map = json::hashmap_to_json_map(edit_application.0);
+
+        Self::check_edit(&map)?;
+
+        *self = http.as_ref().edit_current_application_info(&map).await?;
+
+        Ok(())
+    }
+
+    fn check_edit(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(tags)) = map.get("tags") {
+            if tags.len() > constants::APPLICATION_TAG_MAX_COUNT {
+                return Err(Error::Model(ModelError::ApplicationTagAmount));
+            }
+        }
+
+        if let Some(Value::String(description)) = map.get("description") {
+            let length = description.chars().count();
+
+            if length > constants::APPLICATION_DESCRIPTION_MAX_LENGTH {
+                let overflow = length - constants::APPLICATION_DESCRIPTION_MAX_LENGTH;
+                return Err(Error::Model(ModelError::ApplicationDescriptionTooLong(overflow)));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 /// Information about the Team group of the application.
@@ -133,3 +220,42 @@ pub struct InstallParams {
     pub scopes: Vec<Scope>,
     pub permissions: Permissions,
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::CurrentApplicationInfo;
+    use crate::json::{json, JsonMap, Value};
+    use crate::model::ModelError;
+    use crate::Error;
+
+    fn map(value: Value) -> JsonMap {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_edit_tag_amount() {
+        let five = map(json!({ "tags": ["a", "b", "c", "d", "e"] }));
+        assert!(CurrentApplicationInfo::check_edit(&five).is_ok());
+
+        let six = map(json!({ "tags": ["a", "b", "c", "d", "e", "f"] }));
+        assert!(matches!(
+            CurrentApplicationInfo::check_edit(&six),
+            Err(Error::Model(ModelError::ApplicationTagAmount))
+        ));
+    }
+
+    #[test]
+    fn test_edit_description_length() {
+        let limit = map(json!({ "description": "é".repeat(400) }));
+        assert!(CurrentApplicationInfo::check_edit(&limit).is_ok());
+
+        let over = map(json!({ "description": "é".repeat(402) }));
+        assert!(matches!(
+            CurrentApplicationInfo::check_edit(&over),
+            Err(Error::Model(ModelError::ApplicationDescriptionTooLong(2)))
+        ));
+    }
+}
@@ -1,5 +1,7 @@
 use std::collections::HashMap;
 
+#[cfg(feature = "http")]
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "http")]
@@ -14,6 +16,7 @@ use crate::json::{self, JsonMap};
 use crate::model::channel::ChannelType;
 use crate::model::id::{
     ApplicationId,
+    ChannelId,
     CommandId,
     CommandPermissionId,
     CommandVersionId,
@@ -77,6 +80,11 @@ pub struct Command {
     /// By default, commands are visible.
     #[serde(default)]
     pub dm_permission: Option<bool>,
+    /// Whether the command is age-restricted.
+    ///
+    /// Age-restricted commands are only shown in NSFW channels.
+    #[serde(default)]
+    pub nsfw: bool,
     /// Whether the command is enabled by default when
     /// the application is added to a guild.
     #[serde(default = "default_permission_value")]
@@ -171,6 +179,10 @@ impl Command {
 
     /// Overrides all global application commands.
     ///
+    /// All commands are registered in a single request, which is much faster
+    /// than calling [`create_global_application_command`] for each of them.
+    /// Global commands not included are deleted.
+    ///
     /// [`create_global_application_command`]: Self::create_global_application_command
     ///
     /// # Errors
@@ -190,6 +202,63 @@ impl Command {
         http.as_ref().create_global_application_commands(&Value::from(array.0)).await
     }
 
+    /// Overrides all application commands of each of the given guilds with the
+    /// same set of commands, such as for rolling out commands to a few guilds
+    /// before registering them globally.
+    ///
+    /// At most `concurrency` guilds are updated at once, and each request
+    /// waits for the ratelimiter. The result for each guild is returned in the
+    /// order the guilds were given in, so that a failure for one guild doesn't
+    /// prevent the others from being updated.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::model::application::command::Command;
+    /// use serenity::model::id::GuildId;
+    ///
+    /// let guild_ids = [GuildId(381880193251409931), GuildId(381880193700069377)];
+    /// let results = Command::set_guild_application_commands(&http, guild_ids, 4, |commands| {
+    ///     commands.create_application_command(|command| {
+    ///         command.name("ping").description("A simple ping command")
+    ///     })
+    /// })
+    /// .await;
+    ///
+    /// for (guild_id, result) in results {
+    ///     if let Err(why) = result {
+    ///         println!("Failed to register the commands in {}: {:?}", guild_id, why);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn set_guild_application_commands<F>(
+        http: impl AsRef<Http>,
+        guild_ids: impl IntoIterator<Item = impl Into<GuildId>>,
+        concurrency: usize,
+        f: F,
+    ) -> Vec<(GuildId, Result<Vec<Command>>)>
+    where
+        F: FnOnce(&mut CreateApplicationCommands) -> &mut CreateApplicationCommands,
+    {
+        let mut array = CreateApplicationCommands::default();
+
+        f(&mut array);
+
+        let http = http.as_ref();
+        let commands = Value::from(array.0);
+        let commands = &commands;
+
+        let requests = guild_ids.into_iter().map(Into::into).map(|guild_id: GuildId| async move {
+            (guild_id, http.create_guild_application_commands(guild_id.0, commands).await)
+        });
+
+        stream::iter(requests).buffered(concurrency.max(1)).collect().await
+    }
+
     /// Edits a global command by its Id.
     ///
     /// # Errors
@@ -398,13 +467,28 @@ pub struct CommandPermission {
     pub permissions: Vec<CommandPermissionData>,
 }
 
+impl CommandPermission {
+    /// Whether these permissions apply to all commands of the application,
+    /// rather than a single command.
+    ///
+    /// Discord signals this by setting [`Self::id`] to the application's id.
+    #[must_use]
+    pub fn applies_to_all_commands(&self) -> bool {
+        self.id.0 == self.application_id.0
+    }
+}
+
 /// The [`CommandPermission`] data.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/application-commands#application-command-permissions-object-application-command-permissions-structure).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct CommandPermissionData {
-    /// The [`RoleId`] or [`UserId`], depends on `kind` value.
+    /// The [`RoleId`], [`UserId`] or [`ChannelId`], depends on `kind` value.
+    ///
+    /// Refer to [`CommandPermissionId::everyone`] and
+    /// [`CommandPermissionId::all_channels`] for the ids targeting all members
+    /// and channels of the guild.
     pub id: CommandPermissionId,
     /// The type of data this permissions applies to.
     #[serde(rename = "type")]
@@ -444,6 +528,37 @@ impl CommandPermissionId {
     pub fn to_role_id(self) -> RoleId {
         self.0.into()
     }
+
+    /// Converts this [`CommandPermissionId`] to [`ChannelId`].
+    #[must_use]
+    pub fn to_channel_id(self) -> ChannelId {
+        self.0.into()
+    }
+
+    /// The id targeting the `@everyone` role of a guild, and thus all of its
+    /// members.
+    #[must_use]
+    pub fn everyone(guild_id: GuildId) -> Self {
+        Self(guild_id.0)
+    }
+
+    /// The id targeting all channels of a guild.
+    #[must_use]
+    pub fn all_channels(guild_id: GuildId) -> Self {
+        Self(guild_id.0 - 1)
+    }
+}
+
+impl From<ChannelId> for CommandPermissionId {
+    fn from(id: ChannelId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl<'a> From<&'a ChannelId> for CommandPermissionId {
+    fn from(id: &ChannelId) -> Self {
+        Self(id.0)
+    }
 }
 
 impl From<RoleId> for CommandPermissionId {
@@ -481,3 +596,39 @@ impl From<CommandPermissionId> for UserId {
         Self(id.0)
     }
 }
+
+impl From<CommandPermissionId> for ChannelId {
+    fn from(id: CommandPermissionId) -> Self {
+        Self(id.0)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{CommandPermission, CommandPermissionId, CommandPermissionType};
+    use crate::json::{from_value, json};
+    use crate::model::id::{ChannelId, GuildId};
+
+    #[test]
+    fn test_command_permission() {
+        let permission: CommandPermission = from_value(json!({
+            "id": "2",
+            "application_id": "2",
+            "guild_id": "10",
+            "permissions": [
+                {"id": "10", "type": 1, "permission": false},
+                {"id": "9", "type": 3, "permission": false},
+                {"id": "5", "type": 3, "permission": true},
+            ],
+        }))
+        .unwrap();
+
+        assert!(permission.applies_to_all_commands());
+
+        let data = &permission.permissions;
+        assert_eq!(data[0].id, CommandPermissionId::everyone(GuildId(10)));
+        assert_eq!(data[1].id, CommandPermissionId::all_channels(GuildId(10)));
+        assert_eq!(data[2].kind, CommandPermissionType::Channel);
+        assert_eq!(data[2].id.to_channel_id(), ChannelId(5));
+    }
+}
@@ -3,6 +3,7 @@ use serde::ser::{Serialize, Serializer};
 
 use crate::json::{from_value, JsonMap, Value};
 use crate::model::channel::ReactionType;
+use crate::model::id::{ChannelId, RoleId, UserId};
 
 /// The type of a component
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
@@ -13,6 +14,10 @@ pub enum ComponentType {
     Button = 2,
     SelectMenu = 3,
     InputText = 4,
+    UserSelect = 5,
+    RoleSelect = 6,
+    MentionableSelect = 7,
+    ChannelSelect = 8,
     Unknown = !0,
 }
 
@@ -20,7 +25,11 @@ enum_number!(ComponentType {
     ActionRow,
     Button,
     SelectMenu,
-    InputText
+    InputText,
+    UserSelect,
+    RoleSelect,
+    MentionableSelect,
+    ChannelSelect
 });
 
 /// An action row.
@@ -57,7 +66,11 @@ impl<'de> Deserialize<'de> for ActionRowComponent {
             ComponentType::Button => from_value::<Button>(Value::from(map))
                 .map(ActionRowComponent::Button)
                 .map_err(DeError::custom),
-            ComponentType::SelectMenu => from_value::<SelectMenu>(Value::from(map))
+            ComponentType::SelectMenu
+            | ComponentType::UserSelect
+            | ComponentType::RoleSelect
+            | ComponentType::MentionableSelect
+            | ComponentType::ChannelSelect => from_value::<SelectMenu>(Value::from(map))
                 .map(ActionRowComponent::SelectMenu)
                 .map_err(DeError::custom),
             ComponentType::InputText => from_value::<InputText>(Value::from(map))
@@ -136,9 +149,15 @@ enum_number!(ButtonStyle {
 });
 
 /// A select menu component.
+///
+/// Besides select menus with developer-defined [`options`], Discord provides
+/// select menus auto-populated with users, roles, mentionables or channels.
+///
+/// [`options`]: Self::options
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SelectMenu {
-    /// The component type, it will always be [`ComponentType::SelectMenu`].
+    /// The component type, either [`ComponentType::SelectMenu`] or the type of
+    /// an auto-populated select menu, such as [`ComponentType::UserSelect`].
     #[serde(rename = "type")]
     pub kind: ComponentType,
     /// The placeholder shown when nothing is selected.
@@ -173,6 +192,60 @@ pub struct SelectMenuOption {
     pub default: bool,
 }
 
+/// A pre-selected value of an auto-populated select menu.
+///
+/// [Discord docs](https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-default-value-structure).
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SelectMenuDefaultValue {
+    User { id: UserId },
+    Role { id: RoleId },
+    Channel { id: ChannelId },
+}
+
+impl SelectMenuDefaultValue {
+    /// Whether this value can be pre-selected in a select menu of the given
+    /// type.
+    ///
+    /// Users can be pre-selected in user and mentionable select menus, roles
+    /// in role and mentionable select menus, and channels in channel select
+    /// menus.
+    #[must_use]
+    pub fn is_valid_for(&self, kind: ComponentType) -> bool {
+        matches!(
+            (self, kind),
+            (Self::User { .. }, ComponentType::UserSelect | ComponentType::MentionableSelect)
+                | (Self::Role { .. }, ComponentType::RoleSelect | ComponentType::MentionableSelect)
+                | (Self::Channel { .. }, ComponentType::ChannelSelect)
+        )
+    }
+}
+
+impl From<UserId> for SelectMenuDefaultValue {
+    fn from(id: UserId) -> Self {
+        Self::User {
+            id,
+        }
+    }
+}
+
+impl From<RoleId> for SelectMenuDefaultValue {
+    fn from(id: RoleId) -> Self {
+        Self::Role {
+            id,
+        }
+    }
+}
+
+impl From<ChannelId> for SelectMenuDefaultValue {
+    fn from(id: ChannelId) -> Self {
+        Self::Channel {
+            id,
+        }
+    }
+}
+
 /// An input text component for modal interactions
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct InputText {
@@ -78,6 +78,16 @@ pub struct ApplicationCommandInteraction {
 
 #[cfg(feature = "http")]
 impl ApplicationCommandInteraction {
+    /// Permissions the app or bot has within the channel the interaction was
+    /// sent from.
+    ///
+    /// Unlike the `app_permissions` field, this returns empty permissions
+    /// if Discord did not send any, so that no permission is assumed.
+    #[must_use]
+    pub fn app_permissions(&self) -> Permissions {
+        self.app_permissions.unwrap_or_else(Permissions::empty)
+    }
+
     /// Gets the interaction response.
     ///
     /// # Errors
@@ -179,6 +189,39 @@ impl ApplicationCommandInteraction {
         http.as_ref().delete_original_interaction_response(&self.token).await
     }
 
+    /// Replies to the interaction with a message holding just the given
+    /// content.
+    ///
+    /// The reply is sent as the initial response if the interaction has not
+    /// been responded to yet, and as a followup message otherwise. Use
+    /// [`Self::get_interaction_response`] to get the initial response.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content is too long.
+    /// May also return [`Error::Http`] if the API returns an error.
+    ///
+    /// [`ModelError::MessageTooLong`]: crate::model::ModelError::MessageTooLong
+    pub async fn reply(&self, http: impl AsRef<Http>, content: impl Into<String>) -> Result<()> {
+        let http = http.as_ref();
+        let content = content.into();
+
+        let result = self
+            .create_interaction_response(http, |r| {
+                r.interaction_response_data(|d| d.content(&content))
+            })
+            .await;
+
+        match result {
+            Err(Error::Http(error)) if error.is_already_acknowledged() => {
+                self.create_followup_message(http, |f| f.content(content)).await.map(|_| ())
+            },
+            result => result,
+        }
+    }
+
     /// Creates a followup response to the response sent.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -286,8 +329,12 @@ impl ApplicationCommandInteraction {
     ///
     /// # Errors
     ///
-    /// May return [`Error::Http`] if the API returns an error.
-    /// Such as if the response was deleted.
+    /// May return [`Error::Http`] if the API returns an error. If the followup
+    /// message was deleted, its [`discord_error_code`] is
+    /// [`DiscordJsonErrorCode::UnknownMessage`].
+    ///
+    /// [`discord_error_code`]: crate::http::HttpError::discord_error_code
+    /// [`DiscordJsonErrorCode::UnknownMessage`]: crate::http::error::DiscordJsonErrorCode::UnknownMessage
     pub async fn get_followup_message<M: Into<MessageId>>(
         &self,
         http: impl AsRef<Http>,
@@ -489,6 +536,64 @@ impl CommandData {
             _ => None,
         }
     }
+
+    /// The names of the subcommand group and subcommand that were invoked, in
+    /// that order. Empty if the command has no subcommands.
+    ///
+    /// # Examples
+    ///
+    /// Handling `/settings roles add` and `/settings reset`:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::interaction::application_command::CommandData;
+    /// # fn run(data: &CommandData) {
+    /// match data.subcommand_path().as_slice() {
+    ///     ["roles", "add"] => {},
+    ///     ["reset"] => {},
+    ///     _ => {},
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn subcommand_path(&self) -> Vec<&str> {
+        self.invoked_subcommand().0
+    }
+
+    /// The resolved values of the options passed to the invoked command or
+    /// subcommand, by option name.
+    ///
+    /// Options of subcommand groups and subcommands are looked up in the
+    /// invoked subcommand, see [`Self::subcommand_path`]. Options without a
+    /// resolved value are omitted.
+    #[must_use]
+    pub fn options_map(&self) -> HashMap<&str, &CommandDataOptionValue> {
+        self.invoked_subcommand()
+            .1
+            .iter()
+            .filter_map(|option| Some((option.name.as_str(), option.resolved.as_ref()?)))
+            .collect()
+    }
+
+    /// Descends into the subcommand group and subcommand that were invoked,
+    /// returning their names and the options of the innermost one.
+    fn invoked_subcommand(&self) -> (Vec<&str>, &[CommandDataOption]) {
+        let mut path = Vec::new();
+        let mut options = self.options.as_slice();
+
+        while let [option] = options {
+            if !matches!(
+                option.kind,
+                CommandOptionType::SubCommandGroup | CommandOptionType::SubCommand
+            ) {
+                break;
+            }
+
+            path.push(option.name.as_str());
+            options = &option.options;
+        }
+
+        (path, options)
+    }
 }
 
 impl<'de> Deserialize<'de> for CommandData {
@@ -723,3 +828,51 @@ impl From<TargetId> for UserId {
         Self(id.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CommandData, CommandDataOptionValue};
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn test_invoked_subcommand() {
+        let data: CommandData = from_value(json!({
+            "id": "1",
+            "name": "settings",
+            "type": 1,
+            "options": [{
+                "name": "roles",
+                "type": 2,
+                "options": [{
+                    "name": "add",
+                    "type": 1,
+                    "options": [
+                        { "name": "name", "type": 3, "value": "moderator" },
+                        { "name": "hoist", "type": 5, "value": true },
+                    ],
+                }],
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(data.subcommand_path(), vec!["roles", "add"]);
+
+        let options = data.options_map();
+        assert_eq!(options.len(), 2);
+        assert!(
+            matches!(options["name"], CommandDataOptionValue::String(name) if name == "moderator")
+        );
+        assert!(matches!(options["hoist"], CommandDataOptionValue::Boolean(true)));
+
+        let data: CommandData = from_value(json!({
+            "id": "1",
+            "name": "ping",
+            "type": 1,
+            "options": [{ "name": "times", "type": 4, "value": 3 }],
+        }))
+        .unwrap();
+
+        assert!(data.subcommand_path().is_empty());
+        assert!(matches!(data.options_map()["times"], CommandDataOptionValue::Integer(3)));
+    }
+}
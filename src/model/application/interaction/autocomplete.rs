@@ -4,6 +4,8 @@ use serde::{Deserialize, Deserializer};
 #[cfg(feature = "http")]
 use crate::builder::CreateAutocompleteResponse;
 #[cfg(feature = "http")]
+use crate::constants;
+#[cfg(feature = "http")]
 use crate::http::Http;
 use crate::internal::prelude::*;
 #[cfg(feature = "http")]
@@ -16,6 +18,8 @@ use crate::model::application::interaction::InteractionType;
 use crate::model::guild::Member;
 use crate::model::id::{ApplicationId, ChannelId, GuildId, InteractionId};
 use crate::model::user::User;
+#[cfg(feature = "http")]
+use crate::model::ModelError;
 use crate::model::Permissions;
 
 /// An interaction received when the user fills in an autocomplete option
@@ -59,11 +63,22 @@ pub struct AutocompleteInteraction {
 
 #[cfg(feature = "http")]
 impl AutocompleteInteraction {
+    /// Permissions the app or bot has within the channel the interaction was
+    /// sent from.
+    ///
+    /// Unlike the `app_permissions` field, this returns empty permissions
+    /// if Discord did not send any, so that no permission is assumed.
+    #[must_use]
+    pub fn app_permissions(&self) -> Permissions {
+        self.app_permissions.unwrap_or_else(Permissions::empty)
+    }
+
     /// Creates a response to an autocomplete interaction.
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Http`] if the API returns an error.
+    /// Returns an [`Error::Model`] if more than 25 choices are set, or an
+    /// [`Error::Http`] if the API returns an error.
     pub async fn create_autocomplete_response<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
     where
         F: FnOnce(&mut CreateAutocompleteResponse) -> &mut CreateAutocompleteResponse,
@@ -71,6 +86,7 @@ impl AutocompleteInteraction {
         let mut response = CreateAutocompleteResponse::default();
         f(&mut response);
         let data = json::hashmap_to_json_map(response.0);
+        Self::check_choices(&data)?;
 
         let map = json!({
             "type": InteractionResponseType::Autocomplete as u8,
@@ -79,6 +95,16 @@ impl AutocompleteInteraction {
 
         http.as_ref().create_interaction_response(self.id.0, &self.token, &map).await
     }
+
+    fn check_choices(data: &JsonMap) -> Result<()> {
+        let choices = data.get("choices").and_then(Value::as_array).map_or(0, Vec::len);
+
+        if choices > constants::AUTOCOMPLETE_CHOICE_MAX_COUNT {
+            return Err(Error::Model(ModelError::AutocompleteChoiceAmount));
+        }
+
+        Ok(())
+    }
 }
 
 impl<'de> Deserialize<'de> for AutocompleteInteraction {
@@ -201,3 +227,27 @@ impl<'de> Deserialize<'de> for AutocompleteInteraction {
         })
     }
 }
+
+#[cfg(all(test, feature = "http"))]
+mod test {
+    use super::AutocompleteInteraction;
+    use crate::builder::CreateAutocompleteResponse;
+    use crate::json;
+
+    #[test]
+    fn test_choice_amount() {
+        let mut response = CreateAutocompleteResponse::default();
+
+        for i in 0..25 {
+            response.add_int_choice(i, i);
+        }
+
+        let data = json::hashmap_to_json_map(response.0.clone());
+        assert!(AutocompleteInteraction::check_choices(&data).is_ok());
+
+        response.add_int_choice(25, 25);
+
+        let data = json::hashmap_to_json_map(response.0);
+        assert!(AutocompleteInteraction::check_choices(&data).is_err());
+    }
+}
@@ -69,6 +69,16 @@ pub struct MessageComponentInteraction {
 
 #[cfg(feature = "http")]
 impl MessageComponentInteraction {
+    /// Permissions the app or bot has within the channel the interaction was
+    /// sent from.
+    ///
+    /// Unlike the `app_permissions` field, this returns empty permissions
+    /// if Discord did not send any, so that no permission is assumed.
+    #[must_use]
+    pub fn app_permissions(&self) -> Permissions {
+        self.app_permissions.unwrap_or_else(Permissions::empty)
+    }
+
     /// Gets the interaction response.
     ///
     /// # Errors
@@ -151,6 +161,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref().edit_original_interaction_response(&self.token, &Value::from(map)).await
     }
@@ -167,6 +178,39 @@ impl MessageComponentInteraction {
         http.as_ref().delete_original_interaction_response(&self.token).await
     }
 
+    /// Replies to the interaction with a message holding just the given
+    /// content.
+    ///
+    /// The reply is sent as the initial response if the interaction has not
+    /// been responded to yet, and as a followup message otherwise. Use
+    /// [`Self::get_interaction_response`] to get the initial response.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content is too long.
+    /// May also return [`Error::Http`] if the API returns an error.
+    ///
+    /// [`ModelError::MessageTooLong`]: crate::model::ModelError::MessageTooLong
+    pub async fn reply(&self, http: impl AsRef<Http>, content: impl Into<String>) -> Result<()> {
+        let http = http.as_ref();
+        let content = content.into();
+
+        let result = self
+            .create_interaction_response(http, |r| {
+                r.interaction_response_data(|d| d.content(&content))
+            })
+            .await;
+
+        match result {
+            Err(Error::Http(error)) if error.is_already_acknowledged() => {
+                self.create_followup_message(http, |f| f.content(content)).await.map(|_| ())
+            },
+            result => result,
+        }
+    }
+
     /// Creates a followup response to the response sent.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -193,6 +237,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref().create_followup_message(&self.token, &Value::from(map)).await
     }
@@ -224,6 +269,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref()
             .edit_followup_message(&self.token, message_id.into().into(), &Value::from(map))
@@ -248,8 +294,12 @@ impl MessageComponentInteraction {
     ///
     /// # Errors
     ///
-    /// May return [`Error::Http`] if the API returns an error.
-    /// Such as if the response was deleted.
+    /// May return [`Error::Http`] if the API returns an error. If the followup
+    /// message was deleted, its [`discord_error_code`] is
+    /// [`DiscordJsonErrorCode::UnknownMessage`].
+    ///
+    /// [`discord_error_code`]: crate::http::HttpError::discord_error_code
+    /// [`DiscordJsonErrorCode::UnknownMessage`]: crate::http::error::DiscordJsonErrorCode::UnknownMessage
     pub async fn get_followup_message<M: Into<MessageId>>(
         &self,
         http: impl AsRef<Http>,
@@ -4,6 +4,8 @@ pub mod message_component;
 pub mod modal;
 pub mod ping;
 
+use std::collections::HashMap;
+
 use serde::de::{Deserialize, Deserializer, Error as DeError};
 use serde::ser::{Serialize, Serializer};
 
@@ -13,7 +15,7 @@ use self::message_component::MessageComponentInteraction;
 use self::modal::ModalSubmitInteraction;
 use self::ping::PingInteraction;
 use crate::json::{from_value, JsonMap, Value};
-use crate::model::id::{ApplicationId, InteractionId};
+use crate::model::id::{ApplicationId, InteractionId, MessageId};
 use crate::model::user::User;
 use crate::model::Permissions;
 
@@ -53,6 +55,8 @@ impl Interaction {
     }
 
     /// Permissions the app or bot has within the channel the interaction was sent from.
+    ///
+    /// Returns [`None`] for pings, or if Discord did not send the permissions.
     #[must_use]
     pub fn app_permissions(&self) -> Option<Permissions> {
         match self {
@@ -230,6 +234,65 @@ bitflags! {
         /// Interaction message will only be visible to sender and will
         /// be quickly deleted.
         const EPHEMERAL = 1 << 6;
+        /// Interaction message will not trigger push and desktop
+        /// notifications.
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+    }
+}
+
+impl MessageFlags {
+    /// Sets or removes the [`SUPPRESS_EMBEDS`] flag.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: Self::SUPPRESS_EMBEDS
+    #[must_use]
+    pub fn suppress_embeds(mut self, suppress: bool) -> Self {
+        self.set(Self::SUPPRESS_EMBEDS, suppress);
+        self
+    }
+
+    /// Sets or removes the [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: Self::EPHEMERAL
+    #[must_use]
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.set(Self::EPHEMERAL, ephemeral);
+        self
+    }
+
+    /// Sets or removes the [`SUPPRESS_NOTIFICATIONS`] flag.
+    ///
+    /// [`SUPPRESS_NOTIFICATIONS`]: Self::SUPPRESS_NOTIFICATIONS
+    #[must_use]
+    pub fn suppress_notifications(mut self, suppress: bool) -> Self {
+        self.set(Self::SUPPRESS_NOTIFICATIONS, suppress);
+        self
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`SUPPRESS_EMBEDS`] flag.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: Self::SUPPRESS_EMBEDS
+    #[must_use]
+    pub fn is_suppressing_embeds(self) -> bool {
+        self.contains(Self::SUPPRESS_EMBEDS)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: Self::EPHEMERAL
+    #[must_use]
+    pub fn is_ephemeral(self) -> bool {
+        self.contains(Self::EPHEMERAL)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`SUPPRESS_NOTIFICATIONS`] flag.
+    ///
+    /// [`SUPPRESS_NOTIFICATIONS`]: Self::SUPPRESS_NOTIFICATIONS
+    #[must_use]
+    pub fn is_suppressing_notifications(self) -> bool {
+        self.contains(Self::SUPPRESS_NOTIFICATIONS)
     }
 }
 
@@ -253,6 +316,51 @@ pub struct MessageInteraction {
     pub user: User,
 }
 
+/// Metadata about the [`Interaction`] a [`Message`] was sent in response to.
+///
+/// Supersedes [`MessageInteraction`].
+///
+/// [`Message`]: crate::model::channel::Message
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-interaction-metadata-object).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct MessageInteractionMetadata {
+    /// The id of the interaction.
+    pub id: InteractionId,
+    /// The type of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// The user who invoked the interaction.
+    pub user: User,
+    /// The ids of the guild or user the application was installed to, keyed by
+    /// the installation type: `"0"` for guild installs and `"1"` for user
+    /// installs.
+    #[serde(default)]
+    pub authorizing_integration_owners: HashMap<String, String>,
+    /// The id of the original response message.
+    ///
+    /// **Note**: This is only present on followup messages.
+    pub original_response_message_id: Option<MessageId>,
+    /// The user the command was run on.
+    ///
+    /// **Note**: This is only present on user command interactions.
+    pub target_user: Option<User>,
+    /// The id of the message the command was run on.
+    ///
+    /// **Note**: This is only present on message command interactions.
+    pub target_message_id: Option<MessageId>,
+    /// The id of the message that contained the interacted component.
+    ///
+    /// **Note**: This is only present on message component interactions.
+    pub interacted_message_id: Option<MessageId>,
+    /// The metadata of the interaction that opened the modal.
+    ///
+    /// **Note**: This is only present on modal submit interactions.
+    // Boxed to avoid recursion
+    pub triggering_interaction_metadata: Option<Box<MessageInteractionMetadata>>,
+}
+
 /// The available responses types for an interaction response.
 ///
 /// [Discord docs](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-interaction-callback-type).
@@ -268,3 +376,44 @@ pub enum InteractionResponseType {
     Autocomplete = 8,
     Modal = 9,
 }
+
+#[cfg(test)]
+mod test {
+    use super::{InteractionType, MessageInteractionMetadata};
+    use crate::json::{from_value, json};
+    use crate::model::id::MessageId;
+
+    #[test]
+    fn test_nested_interaction_metadata() {
+        let user = json!({
+            "id": "1",
+            "username": "user",
+            "discriminator": "0001",
+            "avatar": null,
+        });
+
+        let value = json!({
+            "id": "3",
+            "type": 5,
+            "user": user,
+            "authorizing_integration_owners": { "0": "4" },
+            "original_response_message_id": "5",
+            "triggering_interaction_metadata": {
+                "id": "2",
+                "type": 3,
+                "user": user,
+                "interacted_message_id": "6",
+            },
+        });
+
+        let metadata: MessageInteractionMetadata = from_value(value).unwrap();
+        assert_eq!(metadata.kind, InteractionType::ModalSubmit);
+        assert_eq!(metadata.original_response_message_id, Some(MessageId(5)));
+        assert_eq!(metadata.authorizing_integration_owners.get("0").map(String::as_str), Some("4"));
+
+        let triggering = metadata.triggering_interaction_metadata.unwrap();
+        assert_eq!(triggering.kind, InteractionType::MessageComponent);
+        assert_eq!(triggering.interacted_message_id, Some(MessageId(6)));
+        assert!(triggering.original_response_message_id.is_none());
+    }
+}
@@ -23,6 +23,8 @@ use crate::model::guild::Member;
 use crate::model::id::MessageId;
 use crate::model::id::{ApplicationId, ChannelId, GuildId, InteractionId};
 use crate::model::user::User;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
 use crate::model::Permissions;
 
 /// An interaction triggered by a modal submit.
@@ -72,6 +74,16 @@ pub struct ModalSubmitInteraction {
 
 #[cfg(feature = "model")]
 impl ModalSubmitInteraction {
+    /// Permissions the app or bot has within the channel the interaction was
+    /// sent from.
+    ///
+    /// Unlike the `app_permissions` field, this returns empty permissions
+    /// if Discord did not send any, so that no permission is assumed.
+    #[must_use]
+    pub fn app_permissions(&self) -> Permissions {
+        self.app_permissions.unwrap_or_else(Permissions::empty)
+    }
+
     /// Gets the interaction response.
     ///
     /// # Errors
@@ -84,10 +96,13 @@ impl ModalSubmitInteraction {
     /// Creates a response to the interaction received.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
+    /// A modal submit cannot be responded to with another modal.
     ///
     /// # Errors
     ///
-    /// Returns an [`Error::Model`] if the message content is too long.
+    /// Returns an [`Error::Model`] if the message content is too long, or a
+    /// [`ModelError::InvalidInteractionResponseType`] if the response is a
+    /// modal.
     /// May also return an [`Error::Http`] if the API returns an error,
     /// or an [`Error::Json`] if there is an error in deserializing the
     /// API response.
@@ -105,6 +120,12 @@ impl ModalSubmitInteraction {
 
         let map = json::hashmap_to_json_map(interaction_response.0);
 
+        if map.get("type").and_then(Value::as_u64) == Some(InteractionResponseType::Modal as u64) {
+            return Err(Error::Model(ModelError::InvalidInteractionResponseType(
+                InteractionResponseType::Modal,
+            )));
+        }
+
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
 
@@ -154,6 +175,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref().edit_original_interaction_response(&self.token, &Value::from(map)).await
     }
@@ -170,6 +192,37 @@ impl ModalSubmitInteraction {
         http.as_ref().delete_original_interaction_response(&self.token).await
     }
 
+    /// Replies to the interaction with a message holding just the given
+    /// content.
+    ///
+    /// The reply is sent as the initial response if the interaction has not
+    /// been responded to yet, and as a followup message otherwise. Use
+    /// [`Self::get_interaction_response`] to get the initial response.
+    ///
+    /// **Note**: Message contents must be under 2000 unicode code points.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MessageTooLong`] if the content is too long.
+    /// May also return [`Error::Http`] if the API returns an error.
+    pub async fn reply(&self, http: impl AsRef<Http>, content: impl Into<String>) -> Result<()> {
+        let http = http.as_ref();
+        let content = content.into();
+
+        let result = self
+            .create_interaction_response(http, |r| {
+                r.interaction_response_data(|d| d.content(&content))
+            })
+            .await;
+
+        match result {
+            Err(Error::Http(error)) if error.is_already_acknowledged() => {
+                self.create_followup_message(http, |f| f.content(content)).await.map(|_| ())
+            },
+            result => result,
+        }
+    }
+
     /// Creates a followup response to the response sent.
     ///
     /// **Note**: Message contents must be under 2000 unicode code points.
@@ -196,6 +249,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref().create_followup_message(&self.token, &Value::from(map)).await
     }
@@ -227,6 +281,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components(&map)?;
 
         http.as_ref()
             .edit_followup_message(&self.token, message_id.into().into(), &Value::from(map))
@@ -246,6 +301,25 @@ impl ModalSubmitInteraction {
     ) -> Result<()> {
         http.as_ref().delete_followup_message(&self.token, message_id.into().into()).await
     }
+
+    /// Gets a followup message.
+    ///
+    /// # Errors
+    ///
+    /// May return [`Error::Http`] if the API returns an error. If the followup
+    /// message was deleted, its [`discord_error_code`] is
+    /// [`DiscordJsonErrorCode::UnknownMessage`].
+    ///
+    /// [`discord_error_code`]: crate::http::HttpError::discord_error_code
+    /// [`DiscordJsonErrorCode::UnknownMessage`]: crate::http::error::DiscordJsonErrorCode::UnknownMessage
+    pub async fn get_followup_message<M: Into<MessageId>>(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: M,
+    ) -> Result<Message> {
+        http.as_ref().get_followup_message(&self.token, message_id.into().into()).await
+    }
+
     /// Helper function to defer an interaction
     ///
     /// # Errors
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "http")]
+use crate::builder::EditApplicationRoleConnectionMetadata;
+#[cfg(feature = "http")]
+use crate::constants;
+#[cfg(feature = "http")]
+use crate::http::Http;
+#[cfg(feature = "http")]
+use crate::internal::prelude::*;
+#[cfg(feature = "http")]
+use crate::model::ModelError;
+
+/// A metadata record of an application's role connection, describing a value
+/// that the guild's linked roles can require of the user's connection.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnectionMetadata {
+    /// The type of the metadata value.
+    #[serde(rename = "type")]
+    pub kind: ApplicationRoleConnectionMetadataType,
+    /// The key of the metadata value in the user's role connection.
+    pub key: String,
+    /// The name of the metadata record.
+    pub name: String,
+    /// Localizations of the name with locale as the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The description of the metadata record.
+    pub description: String,
+    /// Localizations of the description with locale as the key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+}
+
+#[cfg(feature = "http")]
+impl ApplicationRoleConnectionMetadata {
+    /// Gets the role connection metadata records of the current application.
+    ///
+    /// # Errors
+    ///
+    /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
+    pub async fn get_records(http: impl AsRef<Http>) -> Result<Vec<Self>> {
+        http.as_ref().get_application_role_connection_metadata().await
+    }
+
+    /// Overrides the role connection metadata records of the current
+    /// application.
+    ///
+    /// # Examples
+    ///
+    /// Allow linked roles to require a verified email:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// use serenity::model::application::role_connection::{
+    ///     ApplicationRoleConnectionMetadata,
+    ///     ApplicationRoleConnectionMetadataType,
+    /// };
+    ///
+    /// ApplicationRoleConnectionMetadata::set_records(&http, |m| {
+    ///     m.create_record(|r| {
+    ///         r.kind(ApplicationRoleConnectionMetadataType::BooleanEqual)
+    ///             .key("email_verified")
+    ///             .name("Verified email")
+    ///             .description("Whether the email address has been verified")
+    ///     })
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::RoleConnectionMetadataAmount`] if more than 5
+    /// records are set, or a [`ModelError::InvalidRoleConnectionMetadataKey`]
+    /// if a key is not made up of 1 to 50 lowercase letters, digits or
+    /// underscores.
+    ///
+    /// Otherwise returns an [`Error::Http`] if a record is invalid, or an
+    /// [`Error::Json`] if there is an error in deserializing the response.
+    pub async fn set_records<F>(http: impl AsRef<Http>, f: F) -> Result<Vec<Self>>
+    where
+        F: FnOnce(
+            &mut EditApplicationRoleConnectionMetadata,
+        ) -> &mut EditApplicationRoleConnectionMetadata,
+    {
+        let mut records = EditApplicationRoleConnectionMetadata::default();
+        f(&mut records);
+
+        Self::check_records(&records.0)?;
+
+        http.as_ref().edit_application_role_connection_metadata(&Value::from(records.0)).await
+    }
+
+    fn check_records(records: &[Value]) -> Result<()> {
+        if records.len() > constants::ROLE_CONNECTION_METADATA_MAX_COUNT {
+            return Err(Error::Model(ModelError::RoleConnectionMetadataAmount));
+        }
+
+        for record in records {
+            let key = record.get("key").and_then(Value::as_str).unwrap_or_default();
+
+            if !is_valid_key(key) {
+                return Err(Error::Model(ModelError::InvalidRoleConnectionMetadataKey(
+                    key.to_string(),
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "http")]
+fn is_valid_key(key: &str) -> bool {
+    (1..=constants::ROLE_CONNECTION_METADATA_KEY_MAX_LENGTH).contains(&key.len())
+        && key.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+}
+
+/// The type of a role connection metadata value, deciding how it is compared
+/// with the value required by a linked role.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-type).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum ApplicationRoleConnectionMetadataType {
+    /// The value is an integer less than or equal to the guild's value.
+    IntegerLessThanOrEqual = 1,
+    /// The value is an integer greater than or equal to the guild's value.
+    IntegerGreaterThanOrEqual = 2,
+    /// The value is an integer equal to the guild's value.
+    IntegerEqual = 3,
+    /// The value is an integer not equal to the guild's value.
+    IntegerNotEqual = 4,
+    /// The value is an ISO8601 timestamp at most the guild's value of days
+    /// before now.
+    DatetimeLessThanOrEqual = 5,
+    /// The value is an ISO8601 timestamp at least the guild's value of days
+    /// before now.
+    DatetimeGreaterThanOrEqual = 6,
+    /// The value is a boolean equal to the guild's value.
+    BooleanEqual = 7,
+    /// The value is a boolean not equal to the guild's value.
+    BooleanNotEqual = 8,
+    Unknown = !0,
+}
+
+enum_number!(ApplicationRoleConnectionMetadataType {
+    IntegerLessThanOrEqual,
+    IntegerGreaterThanOrEqual,
+    IntegerEqual,
+    IntegerNotEqual,
+    DatetimeLessThanOrEqual,
+    DatetimeGreaterThanOrEqual,
+    BooleanEqual,
+    BooleanNotEqual
+});
+
+#[cfg(all(test, feature = "http"))]
+mod test {
+    use super::ApplicationRoleConnectionMetadata;
+    use crate::json::json;
+
+    #[test]
+    fn test_check_records() {
+        let record = |key: &str| json!({ "type": 7, "key": key, "name": "n", "description": "d" });
+        let check = ApplicationRoleConnectionMetadata::check_records;
+
+        assert!(check(&[record("email_verified")]).is_ok());
+        assert!(check(&[record("Verified")]).is_err());
+        assert!(check(&[record("")]).is_err());
+        assert!(check(&[record(&"a".repeat(51))]).is_err());
+        assert!(check(&vec![record("a"); 6]).is_err());
+    }
+}
@@ -1,5 +1,7 @@
 #[cfg(feature = "model")]
-use reqwest::Client as ReqwestClient;
+use futures::stream::{Stream, StreamExt};
+#[cfg(feature = "model")]
+use reqwest::{Client as ReqwestClient, Response};
 
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
@@ -128,12 +130,60 @@ impl Attachment {
     /// of the HTTP response.
     ///
     /// Returns an [`Error::Http`] when there is a problem retrieving the
-    /// attachment.
+    /// attachment. If the CDN responded with an unsuccessful status code, this
+    /// is an [`HttpError::Request`] holding the status code, as opposed to the
+    /// [`HttpError::UnsuccessfulRequest`] returned by Discord's API.
     ///
     /// [`Message`]: super::Message
+    /// [`HttpError::Request`]: crate::http::HttpError::Request
+    /// [`HttpError::UnsuccessfulRequest`]: crate::http::HttpError::UnsuccessfulRequest
     pub async fn download(&self) -> Result<Vec<u8>> {
-        let reqwest = ReqwestClient::new();
-        let bytes = reqwest.get(&self.url).send().await?.bytes().await?;
+        let bytes = self.request().await?.bytes().await?;
         Ok(bytes.to_vec())
     }
+
+    /// Downloads the attachment, returning a stream of the chunks of its
+    /// contents as they are received.
+    ///
+    /// Unlike [`Self::download`], this doesn't hold the entire attachment in
+    /// memory, which is preferable for large attachments. The total number of
+    /// bytes is available via [`Self::size`].
+    ///
+    /// # Examples
+    ///
+    /// Save an attachment to a file:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::Attachment;
+    /// # async fn run(attachment: Attachment) -> Result<(), Box<dyn std::error::Error>> {
+    /// use serenity::futures::StreamExt;
+    /// use tokio::fs::File;
+    /// use tokio::io::AsyncWriteExt;
+    ///
+    /// let mut file = File::create(&attachment.filename).await?;
+    /// let mut chunks = attachment.download_stream().await?.boxed();
+    ///
+    /// while let Some(chunk) = chunks.next().await {
+    ///     file.write_all(&chunk?).await?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] when there is a problem retrieving the
+    /// attachment, either immediately or as an item of the stream. Refer to
+    /// [`Self::download`] for how errors of the CDN are reported.
+    pub async fn download_stream(&self) -> Result<impl Stream<Item = Result<Vec<u8>>>> {
+        let response = self.request().await?;
+
+        Ok(response.bytes_stream().map(|chunk| Ok(chunk?.to_vec())))
+    }
+
+    async fn request(&self) -> Result<Response> {
+        let response = ReqwestClient::new().get(&self.url).send().await?;
+
+        Ok(response.error_for_status()?)
+    }
 }
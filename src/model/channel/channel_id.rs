@@ -30,6 +30,8 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
+use crate::constants;
+#[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, Typing};
 #[cfg(feature = "model")]
 use crate::json::{self, json};
@@ -229,6 +231,97 @@ impl ChannelId {
         }
     }
 
+    /// Deletes messages matching the given `filter` from the channel's most
+    /// recent `limit` messages, bulk deleting them in batches of up to 100.
+    ///
+    /// The message history is paginated from the newest message onwards, and
+    /// each message is passed to the `filter`. Matching messages that are older
+    /// than 2 weeks can't be bulk deleted, and are skipped instead. Those can
+    /// be deleted individually using [`Self::delete_message`].
+    ///
+    /// As all following messages are older as well, no more messages are
+    /// fetched after the first page containing a message older than 2 weeks.
+    ///
+    /// Requires the [Manage Messages] and [Read Message History] permissions.
+    ///
+    /// # Examples
+    ///
+    /// Delete the last 50 messages sent by a user:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, UserId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// # let channel_id = ChannelId(7);
+    /// let user_id = UserId(8);
+    ///
+    /// let summary = channel_id.purge(&http, 50, |message| message.author.id == user_id).await?;
+    ///
+    /// println!("Deleted {} messages, skipped {}", summary.deleted, summary.skipped);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to read
+    /// the message history or to delete messages. Messages deleted before the
+    /// error occurred are not restored.
+    ///
+    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn purge<F>(
+        self,
+        http: impl AsRef<Http>,
+        limit: u64,
+        mut filter: F,
+    ) -> Result<PurgeSummary>
+    where
+        F: FnMut(&Message) -> bool,
+    {
+        let http = http.as_ref();
+        let cutoff = Timestamp::now().unix_timestamp() - constants::BULK_DELETE_MAX_AGE;
+
+        let mut summary = PurgeSummary::default();
+        let mut batch = Vec::with_capacity(100);
+        let mut remaining = limit;
+        let mut before = None;
+
+        while remaining > 0 {
+            let page_limit = remaining.min(100);
+            let page = self
+                .messages(http, |b| {
+                    if let Some(before) = before {
+                        b.before(before);
+                    }
+
+                    b.limit(page_limit)
+                })
+                .await?;
+
+            remaining -= page.len() as u64;
+            before = page.last().map(|message| message.id);
+
+            let page_len = page.len() as u64;
+            let messages = page.iter().map(|message| (message.id, filter(message)));
+            let reached_cutoff = sort_purge_page(messages, cutoff, &mut batch, &mut summary);
+            let done = reached_cutoff || page_len < page_limit;
+
+            while let Some(ids) = next_purge_batch(&mut batch, done) {
+                self.delete_messages(http, &ids).await?;
+                summary.deleted += ids.len() as u64;
+            }
+
+            if done {
+                break;
+            }
+        }
+
+        Ok(summary)
+    }
+
     /// Deletes all permission overrides in the channel from a member or role.
     ///
     /// **Note**: Requires the [Manage Channel] permission.
@@ -325,6 +418,9 @@ impl ChannelId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::RateLimitPerUserTooLong`] if a slowmode is over
+    /// the limit.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission,
     /// or if an invalid value is set.
     ///
@@ -339,6 +435,8 @@ impl ChannelId {
 
         let map = json::hashmap_to_json_map(channel.0);
 
+        GuildChannel::check_rate_limit_per_user(&map)?;
+
         http.as_ref().edit_channel(self.0, &map, None).await
     }
 
@@ -478,6 +576,67 @@ impl ChannelId {
         })
     }
 
+    /// First attempts to find a [`Message`] by its Id in the cache, upon
+    /// failure requests it via the REST API.
+    ///
+    /// This is useful for events only carrying the Ids of a message, such as
+    /// [`EventHandler::reaction_add`].
+    ///
+    /// Requires the [Read Message History] permission if the message is not
+    /// cached.
+    ///
+    /// # Examples
+    ///
+    /// Check whether a message has been deleted:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, MessageId};
+    /// # async fn run(http: Http) {
+    /// use serenity::http::error::DiscordJsonErrorCode;
+    /// use serenity::Error;
+    ///
+    /// match ChannelId(7).to_message(&http, MessageId(8)).await {
+    ///     Ok(message) => println!("{}", message.content),
+    ///     Err(Error::Http(why))
+    ///         if why.discord_error_code() == Some(DiscordJsonErrorCode::UnknownMessage) =>
+    ///     {
+    ///         println!("The message was deleted");
+    ///     },
+    ///     Err(why) => println!("Error fetching the message: {:?}", why),
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the
+    /// message does not exist, in which case its
+    /// [`discord_error_code`] is [`DiscordJsonErrorCode::UnknownMessage`].
+    ///
+    /// [`EventHandler::reaction_add`]: crate::client::EventHandler::reaction_add
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    /// [`discord_error_code`]: crate::http::HttpError::discord_error_code
+    /// [`DiscordJsonErrorCode::UnknownMessage`]: crate::http::error::DiscordJsonErrorCode::UnknownMessage
+    pub async fn to_message(
+        self,
+        cache_http: impl CacheHttp,
+        message_id: impl Into<MessageId>,
+    ) -> Result<Message> {
+        let message_id = message_id.into();
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(message) = cache.message(self, message_id) {
+                    return Ok(message);
+                }
+            }
+        }
+
+        self.message(cache_http.http(), message_id).await
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to [`GetMessages`] for more information on how to use `builder`.
@@ -637,6 +796,7 @@ impl ChannelId {
     /// to read messages in the channel.
     ///
     /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    #[inline]
     pub async fn reaction_users(
         self,
         http: impl AsRef<Http>,
@@ -644,14 +804,49 @@ impl ChannelId {
         reaction_type: impl Into<ReactionType>,
         limit: Option<u8>,
         after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.reaction_users_of_kind(
+            http,
+            message_id,
+            reaction_type,
+            ReactionKind::Normal,
+            limit,
+            after,
+        )
+        .await
+    }
+
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`], considering only reactions of the given kind, e.g.
+    /// [`ReactionKind::Burst`] for super reactions.
+    ///
+    /// Refer to [`Self::reaction_users`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission
+    /// to read messages in the channel.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn reaction_users_of_kind(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionKind,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
     ) -> Result<Vec<User>> {
         let limit = limit.map_or(50, |x| if x > 100 { 100 } else { x });
 
         http.as_ref()
-            .get_reaction_users(
+            .get_reaction_users_of_kind(
                 self.0,
                 message_id.into().0,
                 &reaction_type.into(),
+                kind,
                 limit,
                 after.into().map(|x| x.0),
             )
@@ -765,6 +960,68 @@ impl ChannelId {
         http.as_ref().send_files(self.0, files, &map).await
     }
 
+    /// Sends a single file, along with the given message content if any.
+    ///
+    /// This is a shorthand for [`Self::send_files`], refer to it for the
+    /// required permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// use serenity::model::id::ChannelId;
+    ///
+    /// let channel_id = ChannelId(7);
+    ///
+    /// channel_id.send_file(&http, "./log.txt", Some("Today's log")).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::send_files`].
+    #[inline]
+    pub async fn send_file<'a>(
+        self,
+        http: impl AsRef<Http>,
+        file: impl Into<AttachmentType<'a>>,
+        content: Option<&str>,
+    ) -> Result<Message> {
+        self.send_attachments(http, vec![file], content).await
+    }
+
+    /// Sends multiple files, along with the given message content if any.
+    ///
+    /// This is a shorthand for [`Self::send_files`], refer to it for the
+    /// required permissions.
+    ///
+    /// # Errors
+    ///
+    /// See [`Self::send_files`].
+    pub async fn send_attachments<'a, T, It>(
+        self,
+        http: impl AsRef<Http>,
+        files: It,
+        content: Option<&str>,
+    ) -> Result<Message>
+    where
+        T: Into<AttachmentType<'a>>,
+        It: IntoIterator<Item = T>,
+    {
+        self.send_files(http, files, |m| {
+            if let Some(content) = content {
+                m.content(content);
+            }
+
+            m
+        })
+        .await
+    }
+
     /// Sends a message to the channel.
     ///
     /// Refer to the documentation for [`CreateMessage`] for more information
@@ -794,8 +1051,9 @@ impl ChannelId {
     }
 
     async fn _send_message<'a>(self, http: &Http, msg: CreateMessage<'a>) -> Result<Message> {
-        let map = json::hashmap_to_json_map(msg.0);
+        let mut map = json::hashmap_to_json_map(msg.0);
 
+        Message::resolve_message_reference(self, &mut map, !msg.2.is_empty())?;
         Message::check_lengths(&map)?;
 
         let message = if msg.2.is_empty() {
@@ -1015,6 +1273,12 @@ impl ChannelId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::ForumTagAmount`] if more than 5 tags are
+    /// applied to the thread.
+    ///
+    /// Returns a [`ModelError::RateLimitPerUserTooLong`] if the slowmode is
+    /// over 21600 seconds.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     pub async fn edit_thread<F>(&self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
     where
@@ -1024,6 +1288,8 @@ impl ChannelId {
         f(&mut instance);
 
         let map = json::hashmap_to_json_map(instance.0);
+        GuildChannel::check_applied_tags(&map, None)?;
+        GuildChannel::check_rate_limit_per_user(&map)?;
 
         http.as_ref().edit_thread(self.0, &map).await
     }
@@ -1082,6 +1348,45 @@ impl ChannelId {
         http.as_ref().create_private_thread(self.0, &map).await
     }
 
+    /// Creates a thread that is not connected to a message.
+    ///
+    /// The `kind` can be [`ChannelType::PublicThread`] or
+    /// [`ChannelType::PrivateThread`] in text channels, or
+    /// [`ChannelType::NewsThread`] in news channels. Use
+    /// [`Self::create_public_thread`] to create a thread from a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if `kind` is not a thread
+    /// type.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the
+    /// thread type is not allowed in this channel.
+    pub async fn create_thread<F>(
+        &self,
+        http: impl AsRef<Http>,
+        kind: ChannelType,
+        f: F,
+    ) -> Result<GuildChannel>
+    where
+        F: FnOnce(&mut CreateThread) -> &mut CreateThread,
+    {
+        if !matches!(
+            kind,
+            ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread
+        ) {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        let mut instance = CreateThread::default();
+        f(&mut instance);
+        instance.kind(kind);
+
+        let map = json::hashmap_to_json_map(instance.0);
+
+        http.as_ref().create_private_thread(self.0, &map).await
+    }
+
     /// Gets the thread members, if this channel is a thread.
     ///
     /// # Errors
@@ -1091,6 +1396,45 @@ impl ChannelId {
         http.as_ref().get_channel_thread_members(self.0).await
     }
 
+    /// Streams over all the members of a thread, if this channel is a thread.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Http::get_channel_thread_members_paginated`]. A buffer of at most 100
+    /// thread members is used to reduce the number of calls necessary.
+    ///
+    /// The guild member data of each thread member is included in
+    /// [`ThreadMember::member`], as Discord only paginates the thread members
+    /// when requesting it.
+    ///
+    /// The stream ends after yielding an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::ChannelId;
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let thread_id = ChannelId::default();
+    /// # let ctx = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut members = thread_id.thread_members_iter(&ctx).boxed();
+    /// while let Some(member_result) = members.next().await {
+    ///     match member_result {
+    ///         Ok(member) => println!("{:?} is in the thread", member.user_id),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn thread_members_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+    ) -> impl Stream<Item = Result<ThreadMember>> {
+        ThreadMembersIter::<H>::stream(http, self)
+    }
+
     /// Joins the thread, if this channel is a thread.
     ///
     /// # Errors
@@ -1221,6 +1565,150 @@ impl<'a> From<&'a GuildChannel> for ChannelId {
     }
 }
 
+/// Adds the messages of a page matching the filter to the `batch` of messages
+/// to delete, or counts them as skipped if they are too old to be bulk
+/// deleted.
+///
+/// The messages are given from newest to oldest, along with whether they
+/// match the filter. Returns whether the page contains a message created at or
+/// before the `cutoff`, in which case all following pages do as well.
+#[cfg(feature = "model")]
+fn sort_purge_page(
+    messages: impl IntoIterator<Item = (MessageId, bool)>,
+    cutoff: i64,
+    batch: &mut Vec<MessageId>,
+    summary: &mut PurgeSummary,
+) -> bool {
+    let mut reached_cutoff = false;
+
+    for (id, matches) in messages {
+        let too_old = id.created_at().unix_timestamp() <= cutoff;
+        reached_cutoff |= too_old;
+
+        if !matches {
+            continue;
+        }
+
+        if too_old {
+            summary.skipped += 1;
+        } else {
+            batch.push(id);
+        }
+    }
+
+    reached_cutoff
+}
+
+/// Takes the next batch of at most 100 messages to bulk delete.
+///
+/// Only full batches are taken, unless `flush` is set, in which case the
+/// remaining messages are taken as well.
+#[cfg(feature = "model")]
+fn next_purge_batch(batch: &mut Vec<MessageId>, flush: bool) -> Option<Vec<MessageId>> {
+    if batch.len() >= 100 {
+        Some(batch.drain(..100).collect())
+    } else if flush && !batch.is_empty() {
+        Some(std::mem::take(batch))
+    } else {
+        None
+    }
+}
+
+/// The number of messages deleted and skipped by [`ChannelId::purge`].
+#[cfg(feature = "model")]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PurgeSummary {
+    /// The number of messages matching the filter that were deleted.
+    pub deleted: u64,
+    /// The number of messages matching the filter that were too old to be
+    /// bulk deleted.
+    pub skipped: u64,
+}
+
+/// A helper class returned by [`ChannelId::thread_members_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ThreadMembersIter<H: AsRef<Http>> {
+    http: H,
+    channel_id: ChannelId,
+    buffer: Vec<ThreadMember>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> ThreadMembersIter<H> {
+    fn new(http: H, channel_id: ChannelId) -> ThreadMembersIter<H> {
+        ThreadMembersIter {
+            http,
+            channel_id,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of thread members.
+    ///
+    /// This drops any thread members that were currently in the buffer, so it
+    /// should only be called when `self.buffer` is empty. Additionally, this
+    /// updates `self.after` so that the next call does not return duplicate
+    /// items. If there are no more thread members to be fetched, then this
+    /// marks `self.after` as None, indicating that no more calls ought to be
+    /// made.
+    async fn refresh(&mut self) -> Result<()> {
+        let grab_size = constants::THREAD_MEMBER_FETCH_LIMIT;
+
+        self.buffer = self
+            .http
+            .as_ref()
+            .get_channel_thread_members_paginated(
+                self.channel_id.0,
+                true,
+                self.after.map(|id| id.0),
+                Some(grab_size),
+            )
+            .await?;
+
+        // Get the last thread member. If shorter than the limit, there are no
+        // more results anyway.
+        self.after = self.buffer.get(grab_size as usize - 1).and_then(|member| member.user_id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the members of a thread.
+    ///
+    /// Refer to [`ChannelId::thread_members_iter`] for more information.
+    pub fn stream(
+        http: impl AsRef<Http>,
+        channel_id: ChannelId,
+    ) -> impl Stream<Item = Result<ThreadMember>> {
+        let init_state = ThreadMembersIter::new(http, channel_id);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    // End the stream after yielding the error, instead of
+                    // requesting the same page again.
+                    state.tried_fetch = true;
+                    state.after = None;
+
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
 /// A helper class returned by [`ChannelId::messages_iter`]
 #[derive(Clone, Debug)]
 #[cfg(feature = "model")]
@@ -1333,3 +1821,54 @@ impl<H: AsRef<Http>> MessagesIter<H> {
         })
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{next_purge_batch, sort_purge_page, PurgeSummary};
+    use crate::model::id::MessageId;
+
+    /// Creates a message ID of a message created at the given UNIX timestamp.
+    fn id_at(timestamp: i64) -> MessageId {
+        MessageId(((timestamp as u64) * 1000 - 1_420_070_400_000) << 22)
+    }
+
+    #[test]
+    fn test_sort_purge_page() {
+        let cutoff = 1_600_000_000;
+        let mut batch = Vec::new();
+        let mut summary = PurgeSummary::default();
+
+        let page =
+            vec![(id_at(cutoff + 3), true), (id_at(cutoff + 2), false), (id_at(cutoff + 1), true)];
+        assert!(!sort_purge_page(page, cutoff, &mut batch, &mut summary));
+        assert_eq!(batch, vec![id_at(cutoff + 3), id_at(cutoff + 1)]);
+        assert_eq!(summary.skipped, 0);
+
+        // A page with messages too old to bulk delete ends the purge, even if
+        // they don't match.
+        let page = vec![(id_at(cutoff + 1), false), (id_at(cutoff), false)];
+        assert!(sort_purge_page(page, cutoff, &mut batch, &mut summary));
+        assert_eq!(batch.len(), 2);
+        assert_eq!(summary.skipped, 0);
+
+        let page =
+            vec![(id_at(cutoff + 1), true), (id_at(cutoff), true), (id_at(cutoff - 1), true)];
+        assert!(sort_purge_page(page, cutoff, &mut batch, &mut summary));
+        assert_eq!(batch.len(), 3);
+        assert_eq!(summary.skipped, 2);
+    }
+
+    #[test]
+    fn test_next_purge_batch() {
+        let mut batch = (0..250).map(MessageId).collect::<Vec<_>>();
+
+        assert_eq!(next_purge_batch(&mut batch, false), Some((0..100).map(MessageId).collect()));
+        assert_eq!(next_purge_batch(&mut batch, false).map(|ids| ids.len()), Some(100));
+        assert_eq!(next_purge_batch(&mut batch, false), None);
+        assert_eq!(batch.len(), 50);
+
+        assert_eq!(next_purge_batch(&mut batch, true), Some((200..250).map(MessageId).collect()));
+        assert!(batch.is_empty());
+        assert_eq!(next_purge_batch(&mut batch, true), None);
+    }
+}
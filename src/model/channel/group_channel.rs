@@ -0,0 +1,114 @@
+#[cfg(feature = "http")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+use crate::model::prelude::*;
+use crate::model::Timestamp;
+
+/// A group DM channel between multiple users.
+///
+/// **Note**: Group DMs can only be created and managed by bots on behalf of
+/// users who authorized the application via OAuth2 with the `gdm.join` scope.
+/// Refer to [`Http::create_group_dm`] for more information.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GroupChannel {
+    /// The unique Id of the group DM.
+    pub id: ChannelId,
+    /// Indicator of the type of channel this is.
+    ///
+    /// This should always be [`ChannelType::GroupDm`].
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the group DM, if one was set.
+    pub name: Option<String>,
+    /// The icon hash of the group DM, if one was set.
+    pub icon: Option<String>,
+    /// The Id of the user who created the group DM.
+    pub owner_id: UserId,
+    /// The Id of the application which created the group DM, if it was
+    /// created by a bot.
+    pub application_id: Option<ApplicationId>,
+    /// The Id of the last message sent.
+    pub last_message_id: Option<MessageId>,
+    /// Timestamp of the last time a [`Message`] was pinned.
+    pub last_pin_timestamp: Option<Timestamp>,
+    /// The recipients of the group DM, excluding the current user.
+    #[serde(default)]
+    pub recipients: Vec<User>,
+}
+
+#[cfg(feature = "model")]
+impl GroupChannel {
+    /// Adds a user to the group DM, using an OAuth2 access token of that user
+    /// with the `gdm.join` scope.
+    ///
+    /// Optionally sets the nickname of the user within the group DM.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the access token is invalid or lacks the
+    /// `gdm.join` scope, or if the group DM was not created by the current
+    /// application.
+    #[inline]
+    pub async fn add_recipient(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        access_token: &str,
+        nick: Option<&str>,
+    ) -> Result<()> {
+        http.as_ref().add_group_recipient(self.id.0, user_id.into().0, access_token, nick).await
+    }
+
+    /// Removes a user from the group DM.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the group DM was not created by the
+    /// current application.
+    #[inline]
+    pub async fn remove_recipient(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+    ) -> Result<()> {
+        http.as_ref().remove_group_recipient(self.id.0, user_id.into().0).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GroupChannel;
+    use crate::json::{from_value, json};
+    use crate::model::channel::ChannelType;
+    use crate::model::id::{ApplicationId, UserId};
+
+    #[test]
+    fn test_group_channel() {
+        let channel: GroupChannel = from_value(json!({
+            "id": "1",
+            "type": 3,
+            "name": "group",
+            "icon": null,
+            "owner_id": "2",
+            "application_id": "3",
+            "last_message_id": null,
+            "recipients": [{
+                "id": "4",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            }],
+        }))
+        .unwrap();
+
+        assert_eq!(channel.kind, ChannelType::GroupDm);
+        assert_eq!(channel.owner_id, UserId(2));
+        assert_eq!(channel.application_id, Some(ApplicationId(3)));
+        assert_eq!(channel.recipients[0].id, UserId(4));
+        assert!(channel.last_pin_timestamp.is_none());
+    }
+}
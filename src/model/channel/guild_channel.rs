@@ -32,6 +32,8 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
+use crate::constants;
+#[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, Typing};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::internal::prelude::*;
@@ -126,15 +128,28 @@ pub struct GuildChannel {
     pub rtc_region: Option<String>,
     /// The video quality mode for a voice channel.
     pub video_quality_mode: Option<VideoQualityMode>,
-    /// An approximate count of messages in the thread.
+    /// An approximate count of messages in the thread, not counting the
+    /// initial message or deleted messages.
     ///
-    /// This is currently saturated at 255 to prevent breaking.
+    /// This is currently saturated at 255 to prevent breaking. Refer to
+    /// [`Self::total_message_sent`] for larger counts.
     ///
     /// **Note**: This is only available on thread channels.
     #[serde(default, deserialize_with = "message_count_patch")]
     pub message_count: Option<u8>,
+    /// The number of messages ever sent in the thread, including deleted
+    /// messages.
+    ///
+    /// Unlike [`Self::message_count`], this is not saturated.
+    ///
+    /// **Note**: This is only available on thread channels.
+    #[serde(default)]
+    pub total_message_sent: Option<u64>,
     /// An approximate count of users in a thread, stops counting at 50.
     ///
+    /// As the count is capped, a thread with 50 members may have more. Use
+    /// [`ChannelId::get_thread_members`] to get all of them.
+    ///
     /// **Note**: This is only available on thread channels.
     pub member_count: Option<u8>,
     /// The thread metadata.
@@ -149,6 +164,22 @@ pub struct GuildChannel {
     ///
     /// **Note**: It can currently only be set to 60, 1440, 4320, 10080.
     pub default_auto_archive_duration: Option<u64>,
+    /// The slowmode set on threads created in this channel, in seconds.
+    ///
+    /// Unlike [`Self::rate_limit_per_user`], which applies to the messages of
+    /// this channel, this is only copied to new threads when they are created.
+    ///
+    /// **Note**: This is only available for text, news and forum channels.
+    pub default_thread_rate_limit_per_user: Option<u64>,
+    /// The tags that can be applied to the threads of a forum channel.
+    #[serde(default)]
+    pub available_tags: Vec<ForumTag>,
+    /// The ids of the tags applied to a thread of a forum channel.
+    #[serde(default)]
+    pub applied_tags: Vec<ForumTagId>,
+    /// The flags of the channel, e.g. whether a forum channel requires tags
+    /// on its threads, or whether a thread is pinned in its forum channel.
+    pub flags: Option<ChannelFlags>,
 }
 
 #[cfg(feature = "model")]
@@ -423,6 +454,9 @@ impl GuildChannel {
     /// If the `cache` is enabled, returns [ModelError::InvalidPermissions]
     /// if the current user lacks permission to edit the channel.
     ///
+    /// Returns a [`ModelError::RateLimitPerUserTooLong`] if a slowmode is over
+    /// the limit.
+    ///
     /// Otherwise returns [`Error::Http`] if the current user lacks permission.
     pub async fn edit<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
     where
@@ -444,6 +478,8 @@ impl GuildChannel {
         f(&mut edit_channel);
         let edited = json::hashmap_to_json_map(edit_channel.0);
 
+        Self::check_rate_limit_per_user(&edited)?;
+
         *self = cache_http.http().edit_channel(self.id.0, &edited, None).await?;
 
         Ok(())
@@ -482,6 +518,12 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::ForumTagAmount`] if more than 5 tags are
+    /// applied to the thread.
+    ///
+    /// Returns a [`ModelError::RateLimitPerUserTooLong`] if the slowmode is
+    /// over 21600 seconds.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
     pub async fn edit_thread<F>(&self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
     where
@@ -490,6 +532,78 @@ impl GuildChannel {
         self.id.edit_thread(http, f).await
     }
 
+    /// Edits a thread, like [`Self::edit_thread`], but first checks that the
+    /// applied tags are available in the thread's forum channel, if it is
+    /// in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidForumTag`] if an applied tag is not
+    /// available in the thread's forum channel.
+    ///
+    /// Otherwise returns the same errors as [`Self::edit_thread`].
+    #[cfg(feature = "cache")]
+    pub async fn edit_thread_checked<F>(
+        &self,
+        cache_http: impl CacheHttp,
+        f: F,
+    ) -> Result<GuildChannel>
+    where
+        F: FnOnce(&mut EditThread) -> &mut EditThread,
+    {
+        let mut edit_thread = EditThread::default();
+        f(&mut edit_thread);
+
+        let map = json::hashmap_to_json_map(edit_thread.0);
+
+        let forum = cache_http
+            .cache()
+            .and_then(|cache| self.parent_id.and_then(|parent_id| cache.guild_channel(parent_id)));
+
+        Self::check_applied_tags(&map, forum.as_ref())?;
+        Self::check_rate_limit_per_user(&map)?;
+
+        cache_http.http().edit_thread(self.id.0, &map).await
+    }
+
+    /// Checks the amount of tags applied to a thread, and whether they are
+    /// available in its forum channel if given.
+    pub(crate) fn check_applied_tags(map: &JsonMap, forum: Option<&GuildChannel>) -> Result<()> {
+        let tags = match map.get("applied_tags").and_then(Value::as_array) {
+            Some(tags) => tags,
+            None => return Ok(()),
+        };
+
+        if tags.len() > constants::FORUM_THREAD_TAG_MAX_COUNT {
+            return Err(Error::Model(ModelError::ForumTagAmount));
+        }
+
+        if let Some(forum) = forum {
+            for tag in tags.iter().filter_map(Value::as_u64).map(ForumTagId) {
+                if !forum.available_tags.iter().any(|available| available.id == tag) {
+                    return Err(Error::Model(ModelError::InvalidForumTag(tag)));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the slowmode, and the default slowmode of new threads, are
+    /// at most [`constants::RATE_LIMIT_PER_USER_MAX`] seconds.
+    pub(crate) fn check_rate_limit_per_user(map: &JsonMap) -> Result<()> {
+        for key in &["rate_limit_per_user", "default_thread_rate_limit_per_user"] {
+            match map.get(*key).and_then(Value::as_u64) {
+                Some(seconds) if seconds > constants::RATE_LIMIT_PER_USER_MAX => {
+                    return Err(Error::Model(ModelError::RateLimitPerUserTooLong(seconds)));
+                },
+                _ => {},
+            }
+        }
+
+        Ok(())
+    }
+
     /// Edits a voice state in a stage channel. Pass [`None`] for `user_id` to
     /// edit the current user's voice state.
     ///
@@ -591,6 +705,33 @@ impl GuildChannel {
         self._edit_voice_state(http, None::<u64>, f).await
     }
 
+    /// Moves a user in a stage channel to the speakers, or back to the
+    /// audience, by editing the [`suppress`] field of their voice state.
+    ///
+    /// **Note**: Requires the [Mute Members] permission, which all
+    /// [stage moderators] have. Moving the current user back to the audience
+    /// does not require it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if the channel type is not
+    /// stage.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or the
+    /// user is not connected to the channel.
+    ///
+    /// [`suppress`]: EditVoiceState::suppress
+    /// [Mute Members]: crate::model::permissions::Permissions::MUTE_MEMBERS
+    /// [stage moderators]: crate::model::permissions::STAGE_MODERATOR
+    pub async fn set_speaker(
+        &self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        speaker: bool,
+    ) -> Result<()> {
+        self.edit_voice_state(http, user_id, |v| v.suppress(!speaker)).await
+    }
+
     async fn _edit_voice_state<F>(
         &self,
         http: impl AsRef<Http>,
@@ -852,6 +993,44 @@ impl GuildChannel {
         guild.role_permissions_in(self, role)
     }
 
+    /// Whether the current user can send messages in the channel, based on
+    /// their [permissions] in it.
+    ///
+    /// Requires the [View Channel] and [Send Messages] permissions. In threads,
+    /// the [Send Messages in Threads] permission is required instead, which is
+    /// calculated from the parent channel's permission overwrites. Sending in a
+    /// locked thread additionally requires the [Manage Threads] permission.
+    ///
+    /// Returns `false` if the channel is not text-based, or if the guild, the
+    /// current member or a thread's parent channel could not be found in the
+    /// [`Cache`].
+    ///
+    /// [permissions]: Self::permissions_for_user
+    /// [View Channel]: Permissions::VIEW_CHANNEL
+    /// [Send Messages]: Permissions::SEND_MESSAGES
+    /// [Send Messages in Threads]: Permissions::SEND_MESSAGES_IN_THREADS
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    #[cfg(feature = "cache")]
+    pub fn can_send(&self, cache: impl AsRef<Cache>) -> bool {
+        let cache = cache.as_ref();
+        let user_id = cache.current_user_id();
+
+        let permissions = if is_thread_kind(self.kind) {
+            match self.parent_id.and_then(|parent_id| cache.guild_channel(parent_id)) {
+                Some(parent) => parent.permissions_for_user(cache, user_id),
+                None => return false,
+            }
+        } else {
+            self.permissions_for_user(cache, user_id)
+        };
+
+        permissions.map_or(false, |permissions| {
+            let locked = self.thread_metadata.map_or(false, |metadata| metadata.locked);
+
+            can_send_with(self.kind, locked, permissions)
+        })
+    }
+
     /// Pins a [`Message`] to the channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission.
@@ -918,6 +1097,32 @@ impl GuildChannel {
         self.id.reaction_users(&http, message_id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`], considering only reactions of the given kind, e.g.
+    /// [`ReactionKind::Burst`] for super reactions.
+    ///
+    /// Refer to [`Self::reaction_users`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    #[inline]
+    pub async fn reaction_users_of_kind(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionKind,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.id.reaction_users_of_kind(&http, message_id, reaction_type, kind, limit, after).await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -1087,7 +1292,8 @@ impl GuildChannel {
     /// Retrieves [`Member`]s from the current channel.
     ///
     /// [`ChannelType::Voice`] and [`ChannelType::Stage`] returns [`Member`]s
-    /// using the channel.
+    /// connected to the channel, see [`Guild::voice_channel_members`]. This can
+    /// be used to check whether a voice channel is empty.
     ///
     /// [`ChannelType::Text`] and [`ChannelType::News`] return [`Member`]s that
     /// can read the channel.
@@ -1103,19 +1309,9 @@ impl GuildChannel {
         let guild = cache.guild(self.guild_id).ok_or(ModelError::GuildNotFound)?;
 
         match self.kind {
-            ChannelType::Voice | ChannelType::Stage => Ok(guild
-                .voice_states
-                .values()
-                .filter_map(|v| {
-                    v.channel_id.and_then(|c| {
-                        if c == self.id {
-                            guild.members.get(&v.user_id).cloned()
-                        } else {
-                            None
-                        }
-                    })
-                })
-                .collect()),
+            ChannelType::Voice | ChannelType::Stage => {
+                Ok(guild.voice_channel_members(self.id).into_iter().cloned().collect())
+            },
             ChannelType::News | ChannelType::Text => {
                 Ok(futures::stream::iter(guild.members.iter())
                     .filter_map(|e| async move {
@@ -1136,6 +1332,20 @@ impl GuildChannel {
         }
     }
 
+    /// Streams over all the members of this thread, including their guild
+    /// member data.
+    ///
+    /// Refer to [`ChannelId::thread_members_iter`] for more information.
+    ///
+    /// **Note**: The stream yields an [`Error::Http`] if the channel is not a
+    /// thread.
+    pub fn thread_members_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+    ) -> impl futures::Stream<Item = Result<ThreadMember>> {
+        self.id.thread_members_iter(http)
+    }
+
     /// Returns a future that will await one message by this guild channel.
     #[cfg(feature = "collector")]
     pub fn await_reply(&self, shard_messenger: impl AsRef<ShardMessenger>) -> CollectReply {
@@ -1320,6 +1530,65 @@ impl GuildChannel {
     {
         self.id.create_private_thread(http, f).await
     }
+
+    /// Creates a thread that is not connected to a message.
+    ///
+    /// Refer to [`ChannelId::create_thread`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if a thread of the given
+    /// `kind` can't be created in this channel, i.e. a public or private
+    /// thread outside of a text channel, or a news thread outside of a news
+    /// channel.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn create_thread<F>(
+        &self,
+        http: impl AsRef<Http>,
+        kind: ChannelType,
+        f: F,
+    ) -> Result<GuildChannel>
+    where
+        F: FnOnce(&mut CreateThread) -> &mut CreateThread,
+    {
+        if !is_thread_kind_allowed(self.kind, kind) {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        self.id.create_thread(http, kind, f).await
+    }
+}
+
+#[cfg(feature = "model")]
+fn is_thread_kind_allowed(channel: ChannelType, thread: ChannelType) -> bool {
+    matches!(
+        (channel, thread),
+        (ChannelType::Text, ChannelType::PublicThread | ChannelType::PrivateThread)
+            | (ChannelType::News, ChannelType::NewsThread)
+    )
+}
+
+#[cfg(feature = "cache")]
+fn is_thread_kind(kind: ChannelType) -> bool {
+    matches!(kind, ChannelType::PublicThread | ChannelType::PrivateThread | ChannelType::NewsThread)
+}
+
+#[cfg(feature = "cache")]
+fn can_send_with(kind: ChannelType, locked: bool, permissions: Permissions) -> bool {
+    let required = match kind {
+        ChannelType::Text | ChannelType::News | ChannelType::Voice => Permissions::SEND_MESSAGES,
+        kind if is_thread_kind(kind) => {
+            if locked && !permissions.contains(Permissions::MANAGE_THREADS) {
+                return false;
+            }
+
+            Permissions::SEND_MESSAGES_IN_THREADS
+        },
+        _ => return false,
+    };
+
+    permissions.contains(Permissions::VIEW_CHANNEL | required)
 }
 
 impl fmt::Display for GuildChannel {
@@ -1345,3 +1614,164 @@ pub struct PartialGuildChannel {
     #[serde(rename = "type")]
     pub kind: ChannelType,
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::is_thread_kind_allowed;
+    use crate::model::channel::ChannelType;
+
+    #[test]
+    #[cfg(feature = "cache")]
+    fn test_can_send_with() {
+        use super::can_send_with;
+        use crate::model::Permissions;
+
+        let send = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+        let send_in_threads = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES_IN_THREADS;
+
+        assert!(can_send_with(ChannelType::Text, false, send));
+        assert!(!can_send_with(ChannelType::Text, false, Permissions::SEND_MESSAGES));
+        assert!(!can_send_with(ChannelType::Text, false, send_in_threads));
+        assert!(!can_send_with(ChannelType::Category, false, Permissions::all()));
+
+        assert!(can_send_with(ChannelType::PublicThread, false, send_in_threads));
+        assert!(!can_send_with(ChannelType::PublicThread, false, send));
+        assert!(!can_send_with(ChannelType::PrivateThread, true, send_in_threads));
+        assert!(can_send_with(
+            ChannelType::PrivateThread,
+            true,
+            send_in_threads | Permissions::MANAGE_THREADS
+        ));
+    }
+
+    #[test]
+    fn test_check_applied_tags() {
+        use super::GuildChannel;
+        use crate::builder::EditThread;
+        use crate::json::{self, from_value, json};
+        use crate::model::id::ForumTagId;
+        use crate::model::ModelError;
+        use crate::Error;
+
+        let forum: GuildChannel = from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "type": 15,
+            "name": "forum",
+            "position": 0,
+            "permission_overwrites": [],
+            "available_tags": [{
+                "id": "3",
+                "name": "solved",
+                "moderated": true,
+                "emoji_id": null,
+                "emoji_name": null,
+            }],
+        }))
+        .unwrap();
+
+        let check = |tags: Vec<u64>, forum: Option<&GuildChannel>| {
+            let mut edit_thread = EditThread::default();
+            edit_thread.applied_tags(tags.into_iter().map(ForumTagId));
+
+            GuildChannel::check_applied_tags(&json::hashmap_to_json_map(edit_thread.0), forum)
+        };
+
+        assert!(check(vec![3], Some(&forum)).is_ok());
+        assert!(check(vec![4], None).is_ok());
+        assert!(matches!(
+            check(vec![4], Some(&forum)),
+            Err(Error::Model(ModelError::InvalidForumTag(ForumTagId(4))))
+        ));
+        assert!(matches!(
+            check(vec![3; 6], Some(&forum)),
+            Err(Error::Model(ModelError::ForumTagAmount))
+        ));
+    }
+
+    #[test]
+    fn test_check_rate_limit_per_user() {
+        use super::GuildChannel;
+        use crate::builder::{EditChannel, EditThread};
+        use crate::model::ModelError;
+        use crate::{json, Error};
+
+        let check = |seconds: u64| {
+            let mut edit_thread = EditThread::default();
+            edit_thread.rate_limit_per_user(seconds);
+
+            GuildChannel::check_rate_limit_per_user(&json::hashmap_to_json_map(edit_thread.0))
+        };
+
+        assert!(check(0).is_ok());
+        assert!(check(21600).is_ok());
+        assert!(matches!(
+            check(21601),
+            Err(Error::Model(ModelError::RateLimitPerUserTooLong(21601)))
+        ));
+
+        let mut edit_channel = EditChannel::default();
+        edit_channel.default_thread_rate_limit_per_user(21601);
+        assert!(matches!(
+            GuildChannel::check_rate_limit_per_user(&json::hashmap_to_json_map(edit_channel.0)),
+            Err(Error::Model(ModelError::RateLimitPerUserTooLong(21601)))
+        ));
+    }
+
+    #[test]
+    fn test_channel_flags() {
+        use super::GuildChannel;
+        use crate::json::{from_value, json};
+        use crate::model::channel::ChannelFlags;
+
+        let mut value = json!({
+            "id": "1",
+            "guild_id": "2",
+            "type": 0,
+            "name": "general",
+            "position": 0,
+            "permission_overwrites": [],
+        });
+
+        let channel: GuildChannel = from_value(value.clone()).unwrap();
+        assert!(channel.flags.is_none());
+
+        value["flags"] = json!(16);
+
+        let channel: GuildChannel = from_value(value).unwrap();
+        assert_eq!(channel.flags, Some(ChannelFlags::REQUIRE_TAG));
+    }
+
+    #[test]
+    fn test_thread_counts() {
+        use super::GuildChannel;
+        use crate::json::{from_value, json};
+
+        let thread: GuildChannel = from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "type": 11,
+            "name": "thread",
+            "position": 0,
+            "permission_overwrites": [],
+            "message_count": 300,
+            "total_message_sent": 320,
+            "member_count": 50,
+        }))
+        .unwrap();
+
+        assert_eq!(thread.message_count, Some(255));
+        assert_eq!(thread.total_message_sent, Some(320));
+        assert_eq!(thread.member_count, Some(50));
+    }
+
+    #[test]
+    fn test_thread_kind_allowed() {
+        assert!(is_thread_kind_allowed(ChannelType::Text, ChannelType::PublicThread));
+        assert!(is_thread_kind_allowed(ChannelType::Text, ChannelType::PrivateThread));
+        assert!(is_thread_kind_allowed(ChannelType::News, ChannelType::NewsThread));
+        assert!(!is_thread_kind_allowed(ChannelType::News, ChannelType::PrivateThread));
+        assert!(!is_thread_kind_allowed(ChannelType::Text, ChannelType::NewsThread));
+        assert!(!is_thread_kind_allowed(ChannelType::Voice, ChannelType::PublicThread));
+    }
+}
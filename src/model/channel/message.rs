@@ -5,6 +5,11 @@ use std::fmt::Display;
 #[cfg(all(feature = "cache", feature = "model"))]
 use std::fmt::Write;
 
+use serde::de::Error as DeError;
+use serde::ser::{Serialize, Serializer};
+
+#[cfg(feature = "model")]
+use crate::builder::{embed_length, CreateThread};
 #[cfg(all(feature = "model", feature = "utils"))]
 use crate::builder::{CreateEmbed, EditMessage};
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -25,8 +30,8 @@ use crate::http::{CacheHttp, Http};
 #[cfg(feature = "model")]
 use crate::json;
 use crate::json::prelude::*;
-use crate::model::application::component::ActionRow;
-use crate::model::application::interaction::MessageInteraction;
+use crate::model::application::component::{ActionRow, ComponentType, SelectMenuDefaultValue};
+use crate::model::application::interaction::{MessageInteraction, MessageInteractionMetadata};
 use crate::model::prelude::*;
 #[cfg(feature = "model")]
 use crate::{
@@ -43,6 +48,7 @@ use crate::{
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(remote = "Self")]
 #[non_exhaustive]
 pub struct Message {
     /// The unique Id of the message. Can be used to calculate the creation date
@@ -122,14 +128,60 @@ pub struct Message {
     #[serde(default)]
     pub sticker_items: Vec<StickerItem>,
     /// The message that was replied to using this message.
+    ///
+    /// This is [`None`] both if the message was deleted and if Discord did not
+    /// attempt to load it. Refer to [`Self::referenced_message_state`] to
+    /// distinguish these states.
     pub referenced_message: Option<Box<Message>>, // Boxed to avoid recursion
+    /// Whether Discord sent `null` as the referenced message, which indicates
+    /// that it was deleted.
+    #[serde(skip)]
+    pub(crate) referenced_message_deleted: bool,
     /// Sent if the message is a response to an [`Interaction`].
     ///
+    /// **Note**: Deprecated by Discord in favor of
+    /// [`Self::interaction_metadata`].
+    ///
     /// [`Interaction`]: crate::model::application::interaction::Interaction
     pub interaction: Option<MessageInteraction>,
+    /// Sent if the message is a response to an [`Interaction`].
+    ///
+    /// [`Interaction`]: crate::model::application::interaction::Interaction
+    pub interaction_metadata: Option<MessageInteractionMetadata>,
     /// The components of this message
     #[serde(default)]
     pub components: Vec<ActionRow>,
+    /// The call associated with the message, if it is a message of type
+    /// [`MessageType::GroupCallCreation`].
+    pub call: Option<MessageCall>,
+    /// The thread that was started from this message, if any.
+    ///
+    /// The thread has the same Id as the message. Refer to
+    /// [`MessageFlags::HAS_THREAD`] to check whether a message has a thread,
+    /// as this field is not always present.
+    pub thread: Option<GuildChannel>,
+}
+
+impl<'de> Deserialize<'de> for Message {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
+        let map = JsonMap::deserialize(deserializer)?;
+
+        // Discord sends `null` for a deleted referenced message, and omits the
+        // field if it did not attempt to load the message.
+        let referenced_message_deleted =
+            map.get("referenced_message").map_or(false, Value::is_null);
+
+        let mut message = Message::deserialize(Value::from(map)).map_err(DeError::custom)?;
+        message.referenced_message_deleted = referenced_message_deleted;
+
+        Ok(message)
+    }
+}
+
+impl Serialize for Message {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        Message::serialize(self, serializer)
+    }
 }
 
 #[cfg(feature = "model")]
@@ -193,6 +245,23 @@ impl Message {
         self.channel_id.to_channel(cache_http).await
     }
 
+    /// Creates a thread from the message.
+    ///
+    /// The type of the thread depends on the channel the message was sent in,
+    /// a news thread in news channels and a public thread otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the
+    /// message already has a thread.
+    #[inline]
+    pub async fn create_thread<F>(&self, http: impl AsRef<Http>, f: F) -> Result<GuildChannel>
+    where
+        F: FnOnce(&mut CreateThread) -> &mut CreateThread,
+    {
+        self.channel_id.create_public_thread(http, self.id, f).await
+    }
+
     /// A util function for determining whether this message was sent by someone else, or the
     /// bot.
     #[cfg(feature = "cache")]
@@ -462,6 +531,33 @@ impl Message {
         self.channel_id.reaction_users(&http, self.id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`], considering only reactions of the given kind, e.g.
+    /// [`ReactionKind::Burst`] for super reactions.
+    ///
+    /// Refer to [`Self::reaction_users`] for more information.
+    ///
+    /// **Note**: Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    #[inline]
+    pub async fn reaction_users_of_kind(
+        &self,
+        http: impl AsRef<Http>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionKind,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.channel_id
+            .reaction_users_of_kind(&http, self.id, reaction_type, kind, limit, after)
+            .await
+    }
+
     /// Returns the associated [`Guild`] for the message if one is in the cache.
     ///
     /// Returns [`None`] if the guild's Id could not be found via [`Self::guild_id`] or
@@ -632,6 +728,259 @@ impl Message {
         })
     }
 
+    /// Reacts to the message with several reactions, in the given order.
+    ///
+    /// The reactions are added one after another, as Discord shows them in the
+    /// order they were added. The requests wait for the rate limit on reactions
+    /// in the channel, which is rather strict.
+    ///
+    /// Adding the reactions stops at the first one that fails. The returned
+    /// list contains the result of each reaction attempted, the error being
+    /// the last element if any.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// # Examples
+    ///
+    /// Add the reactions of a reaction menu:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// # async fn run(http: Http, message: Message) {
+    /// let results = message.react_all(&http, ['⬅', '➡', '❌']).await;
+    ///
+    /// if let Some(Err(why)) = results.last() {
+    ///     println!("Only added {} reactions: {:?}", results.len() - 1, why);
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn react_all<R, It>(
+        &self,
+        cache_http: impl CacheHttp,
+        reactions: It,
+    ) -> Vec<Result<Reaction>>
+    where
+        R: Into<ReactionType>,
+        It: IntoIterator<Item = R>,
+    {
+        let mut results = Vec::new();
+
+        for reaction_type in reactions {
+            let result = self.react(&cache_http, reaction_type).await;
+            let failed = result.is_err();
+
+            results.push(result);
+
+            if failed {
+                break;
+            }
+        }
+
+        results
+    }
+
+    /// Resolves the state of the message this message replied to, or of the
+    /// message that started its thread.
+    ///
+    /// Discord distinguishes between a [deleted] message and a message it did
+    /// not [load], in which case [`Self::fetch_referenced_message`] can be
+    /// used to request it.
+    ///
+    /// [deleted]: ReferencedMessage::Deleted
+    /// [load]: ReferencedMessage::NotLoaded
+    #[must_use]
+    pub fn referenced_message_state(&self) -> ReferencedMessage<'_> {
+        match &self.referenced_message {
+            Some(message) => ReferencedMessage::Loaded(message),
+            None if self.referenced_message_deleted => ReferencedMessage::Deleted,
+            None => {
+                let is_reference = matches!(
+                    self.kind,
+                    MessageType::InlineReply | MessageType::ThreadStarterMessage
+                );
+
+                match &self.message_reference {
+                    Some(reference) if is_reference && reference.message_id.is_some() => {
+                        ReferencedMessage::NotLoaded
+                    },
+                    _ => ReferencedMessage::None,
+                }
+            },
+        }
+    }
+
+    /// Returns the text Discord renders for a system message, or [`None`] if
+    /// the message is not a [system message].
+    ///
+    /// Mentioned users are referred to by their name. The name of the guild
+    /// is not part of the message, the text of [`MessageType::NitroTier1`] and
+    /// the following tiers refers to it as "the server".
+    ///
+    /// Types of system messages whose text can't be produced from the message
+    /// alone return the [`Self::content`] of the message.
+    ///
+    /// # Examples
+    ///
+    /// Log boosts of the guild:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::channel::{Message, MessageType};
+    /// #
+    /// # fn run(message: Message) {
+    /// if let MessageType::NitroBoost
+    /// | MessageType::NitroTier1
+    /// | MessageType::NitroTier2
+    /// | MessageType::NitroTier3 = message.kind
+    /// {
+    ///     if let Some(content) = message.system_content() {
+    ///         println!("{}", content);
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [system message]: MessageType::is_system
+    #[must_use]
+    pub fn system_content(&self) -> Option<String> {
+        if !self.kind.is_system() {
+            return None;
+        }
+
+        let author = &self.author.name;
+        let mentioned = self.mentions.first().map_or("someone", |user| &user.name);
+        let content = &self.content;
+
+        let text = match self.kind {
+            MessageType::GroupRecipientAddition => {
+                format!("{} added {} to the group.", author, mentioned)
+            },
+            MessageType::GroupRecipientRemoval => {
+                format!("{} removed {} from the group.", author, mentioned)
+            },
+            MessageType::GroupCallCreation => format!("{} started a call.", author),
+            MessageType::GroupNameUpdate => {
+                format!("{} changed the channel name: **{}**", author, content)
+            },
+            MessageType::GroupIconUpdate => format!("{} changed the group icon.", author),
+            MessageType::PinsAdd => format!("{} pinned a message to this channel.", author),
+            MessageType::MemberJoin => {
+                // Discord picks the welcome message based on the Unix timestamp
+                // in milliseconds the member joined at.
+                let timestamp = (self.id.0 >> 22) + 1_420_070_400_000;
+                let index = timestamp % MEMBER_JOIN_MESSAGES.len() as u64;
+                let format = MEMBER_JOIN_MESSAGES[index as usize];
+
+                format.replace("{}", author)
+            },
+            MessageType::NitroBoost if content.is_empty() => {
+                format!("{} just boosted the server!", author)
+            },
+            MessageType::NitroBoost => {
+                format!("{} just boosted the server **{}** times!", author, content)
+            },
+            MessageType::NitroTier1 | MessageType::NitroTier2 | MessageType::NitroTier3 => {
+                let level = match self.kind {
+                    MessageType::NitroTier1 => 1,
+                    MessageType::NitroTier2 => 2,
+                    _ => 3,
+                };
+
+                format!(
+                    "{} just boosted the server! The server has achieved **Level {}!**",
+                    author, level
+                )
+            },
+            MessageType::ChannelFollowAdd => format!(
+                "{} has added {} to this channel. Its most important updates will show up here.",
+                author, content
+            ),
+            MessageType::GuildDiscoveryDisqualified => "This server has been removed from Server \
+                Discovery because it no longer passes all the requirements. Check Server Settings \
+                for more details."
+                .to_string(),
+            MessageType::GuildDiscoveryRequalified => "This server is eligible for Server \
+                Discovery again and has been automatically relisted!"
+                .to_string(),
+            MessageType::GuildDiscoveryGracePeriodInitialWarning => "This server has failed \
+                Discovery activity requirements for 1 week. If this server fails for 4 weeks in a \
+                row, it will be automatically removed from Discovery."
+                .to_string(),
+            MessageType::GuildDiscoveryGracePeriodFinalWarning => "This server has failed \
+                Discovery activity requirements for 3 weeks in a row. If this server fails for 1 \
+                more week, it will be removed from Discovery."
+                .to_string(),
+            MessageType::ThreadCreated => {
+                format!("{} started a thread: **{}**. See all **threads**.", author, content)
+            },
+            MessageType::ThreadStarterMessage => match self.referenced_message_state() {
+                ReferencedMessage::Loaded(message) => message.content.clone(),
+                _ => "Sorry, we couldn't load the first message in this thread".to_string(),
+            },
+            MessageType::GuildInviteReminder => "Wondering who to invite?\nStart by inviting \
+                anyone who can help you build the server!"
+                .to_string(),
+            MessageType::StageStart => format!("{} started **{}**.", author, content),
+            MessageType::StageEnd => format!("{} ended **{}**.", author, content),
+            MessageType::StageSpeaker => format!("{} is now a speaker.", author),
+            MessageType::StageTopic => {
+                format!("{} changed the Stage topic: **{}**", author, content)
+            },
+            MessageType::GuildIncidentAlertModeEnabled => {
+                format!("{} enabled security actions.", author)
+            },
+            MessageType::GuildIncidentAlertModeDisabled => {
+                format!("{} disabled security actions.", author)
+            },
+            MessageType::GuildIncidentReportRaid => format!("{} reported a raid.", author),
+            MessageType::GuildIncidentReportFalseAlarm => {
+                format!("{} reported a false alarm.", author)
+            },
+            _ => content.clone(),
+        };
+
+        Some(text)
+    }
+
+    /// Gets the message this message replied to, or the message that started
+    /// its thread.
+    ///
+    /// If Discord did not load the referenced message, it is retrieved from
+    /// the cache or requested over the REST API via [`ChannelId::to_message`].
+    ///
+    /// Returns [`None`] if the referenced message was deleted, or if this
+    /// message does not refer to another message.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the referenced message had to be requested
+    /// and the current user lacks permission to read it, or if it was deleted
+    /// in the meantime.
+    pub async fn fetch_referenced_message(
+        &self,
+        cache_http: impl CacheHttp,
+    ) -> Result<Option<Message>> {
+        match self.referenced_message_state() {
+            ReferencedMessage::Loaded(message) => Ok(Some(message.clone())),
+            ReferencedMessage::NotLoaded => {
+                let reference = match &self.message_reference {
+                    Some(reference) => reference,
+                    None => return Ok(None),
+                };
+                let message_id = match reference.message_id {
+                    Some(message_id) => message_id,
+                    None => return Ok(None),
+                };
+
+                reference.channel_id.to_message(cache_http, message_id).await.map(Some)
+            },
+            ReferencedMessage::Deleted | ReferencedMessage::None => Ok(None),
+        }
+    }
+
     /// Uses Discord's inline reply to a user without pinging them.
     ///
     /// User mentions are generally around 20 or 21 characters long.
@@ -882,6 +1231,30 @@ impl Message {
     }
 
     /// Await a single reaction on this message.
+    ///
+    /// The returned future can be narrowed down further, e.g. to a specific
+    /// user via [`CollectReaction::author_id`] or to a specific emoji via
+    /// [`CollectReaction::emoji`].
+    ///
+    /// # Examples
+    ///
+    /// Wait up to a minute for the message's author to confirm with ✅:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::bridge::gateway::ShardMessenger;
+    /// # use serenity::model::channel::Message;
+    /// # async fn run(message: Message, shard: ShardMessenger) {
+    /// use std::time::Duration;
+    ///
+    /// let confirmed = message
+    ///     .await_reaction(&shard)
+    ///     .author_id(message.author.id)
+    ///     .emoji('✅')
+    ///     .timeout(Duration::from_secs(60))
+    ///     .await
+    ///     .is_some();
+    /// # }
+    /// ```
     #[cfg(feature = "collector")]
     pub fn await_reaction(&self, shard_messenger: impl AsRef<ShardMessenger>) -> CollectReaction {
         CollectReaction::new(shard_messenger).message_id(self.id.0)
@@ -938,10 +1311,52 @@ impl Message {
         cache.as_ref().channel_category_id(self.channel_id)
     }
 
+    /// Turns a reply to a message of another channel into a forward, as
+    /// replies must be sent in the channel of the referenced message.
+    ///
+    /// Forwards cannot have content of their own, so this errors if the
+    /// message has any.
+    pub(crate) fn resolve_message_reference(
+        channel_id: ChannelId,
+        map: &mut JsonMap,
+        has_files: bool,
+    ) -> Result<()> {
+        let mut reference: MessageReference = match map.get("message_reference") {
+            Some(value) => json::from_value(value.clone())?,
+            None => return Ok(()),
+        };
+
+        if reference.kind != MessageReferenceKind::Default || reference.channel_id == channel_id {
+            return Ok(());
+        }
+
+        let keys = ["content", "embeds", "sticker_ids", "components"];
+        let has_content = has_files || keys.iter().any(|key| map.contains_key(*key));
+        if has_content {
+            return Err(Error::Model(ModelError::CrossChannelReply));
+        }
+
+        reference.kind = MessageReferenceKind::Forward;
+        map.insert("message_reference".into(), json::to_value(reference)?);
+
+        Ok(())
+    }
+
     pub(crate) fn check_lengths(map: &JsonMap) -> Result<()> {
         Self::check_content_length(map)?;
         Self::check_embed_length(map)?;
         Self::check_sticker_ids_length(map)?;
+        Self::check_components(map)?;
+
+        Ok(())
+    }
+
+    /// Checks the number of action rows, the number of buttons in each row,
+    /// and the default values of select menus.
+    pub(crate) fn check_components(map: &JsonMap) -> Result<()> {
+        Self::check_action_row_count(map)?;
+        Self::check_button_count(map)?;
+        Self::check_select_menu_default_values(map)?;
 
         Ok(())
     }
@@ -956,66 +1371,121 @@ impl Message {
         Ok(())
     }
 
+    /// Checks the number of embeds and their combined textual length.
+    ///
+    /// Discord limits the combined length of the following fields, across all
+    /// embeds of a message, to [`constants::EMBED_MAX_LENGTH`] characters:
+    ///
+    /// - the title;
+    /// - the description;
+    /// - the name and value of each field;
+    /// - the footer text;
+    /// - the author name.
     pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
         let embeds = match map.get("embeds") {
             Some(&Value::Array(ref value)) => value,
             _ => return Ok(()),
         };
 
-        if embeds.len() > 10 {
+        if embeds.len() > constants::EMBED_MAX_COUNT {
             return Err(Error::Model(ModelError::EmbedAmount));
         }
 
-        for embed in embeds {
-            let mut total: usize = 0;
+        let total: usize = embeds.iter().map(|embed| embed_length(|key| embed.get(key))).sum();
 
-            if let Some(&Value::Object(ref author)) = embed.get("author") {
-                if let Some(&Value::Object(ref name)) = author.get("name") {
-                    total += name.len();
-                }
-            }
+        if total > constants::EMBED_MAX_LENGTH {
+            let overflow = total - constants::EMBED_MAX_LENGTH;
+            return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+        }
+
+        Ok(())
+    }
 
-            if let Some(&Value::String(ref description)) = embed.get("description") {
-                total += description.len();
+    pub(crate) fn check_sticker_ids_length(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
+            if sticker_ids.len() > constants::STICKER_MAX_COUNT {
+                return Err(Error::Model(ModelError::StickerAmount));
             }
+        }
 
-            if let Some(&Value::Array(ref fields)) = embed.get("fields") {
-                for field_as_value in fields {
-                    if let Value::Object(ref field) = *field_as_value {
-                        if let Some(&Value::String(ref field_name)) = field.get("name") {
-                            total += field_name.len();
-                        }
+        Ok(())
+    }
 
-                        if let Some(&Value::String(ref field_value)) = field.get("value") {
-                            total += field_value.len();
-                        }
-                    }
-                }
+    /// Checks that there are at most [`constants::ACTION_ROW_MAX_COUNT`] action
+    /// rows.
+    pub(crate) fn check_action_row_count(map: &JsonMap) -> Result<()> {
+        if let Some(Value::Array(rows)) = map.get("components") {
+            if rows.len() > constants::ACTION_ROW_MAX_COUNT {
+                return Err(Error::Model(ModelError::ActionRowAmount));
             }
+        }
 
-            if let Some(&Value::Object(ref footer)) = embed.get("footer") {
-                if let Some(&Value::String(ref text)) = footer.get("text") {
-                    total += text.len();
-                }
-            }
+        Ok(())
+    }
 
-            if let Some(&Value::String(ref title)) = embed.get("title") {
-                total += title.len();
-            }
+    /// Checks that no action row holds more than [`constants::BUTTON_MAX_COUNT`]
+    /// buttons.
+    pub(crate) fn check_button_count(map: &JsonMap) -> Result<()> {
+        let rows = match map.get("components") {
+            Some(Value::Array(rows)) => rows,
+            _ => return Ok(()),
+        };
+
+        for row in rows {
+            if let Some(Value::Array(components)) = row.get("components") {
+                let buttons = components
+                    .iter()
+                    .filter(|c| c.get("type").and_then(Value::as_u64) == Some(2))
+                    .count();
 
-            if total > constants::EMBED_MAX_LENGTH {
-                let overflow = total - constants::EMBED_MAX_LENGTH;
-                return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+                if buttons > constants::BUTTON_MAX_COUNT {
+                    return Err(Error::Model(ModelError::ButtonAmount));
+                }
             }
         }
 
         Ok(())
     }
 
-    pub(crate) fn check_sticker_ids_length(map: &JsonMap) -> Result<()> {
-        if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
-            if sticker_ids.len() > constants::STICKER_MAX_COUNT {
-                return Err(Error::Model(ModelError::StickerAmount));
+    /// Checks that the default values of auto-populated select menus match the
+    /// select menus' types, and don't exceed their maximum number of
+    /// selections.
+    pub(crate) fn check_select_menu_default_values(map: &JsonMap) -> Result<()> {
+        let rows = match map.get("components") {
+            Some(Value::Array(rows)) => rows,
+            _ => return Ok(()),
+        };
+
+        let components = rows
+            .iter()
+            .filter_map(|row| match row.get("components") {
+                Some(Value::Array(components)) => Some(components),
+                _ => None,
+            })
+            .flatten();
+
+        for component in components {
+            let values = match component.get("default_values") {
+                Some(Value::Array(values)) => values,
+                _ => continue,
+            };
+
+            let max_values = component.get("max_values").and_then(Value::as_u64).unwrap_or(1);
+
+            if values.len() as u64 > max_values {
+                return Err(Error::Model(ModelError::SelectMenuDefaultValueAmount));
+            }
+
+            let kind = component
+                .get("type")
+                .and_then(|kind| from_value::<ComponentType>(kind.clone()).ok())
+                .unwrap_or(ComponentType::Unknown);
+
+            for value in values {
+                match from_value::<SelectMenuDefaultValue>(value.clone()) {
+                    Ok(value) if value.is_valid_for(kind) => {},
+                    _ => return Err(Error::Model(ModelError::SelectMenuDefaultValueType)),
+                }
             }
         }
 
@@ -1118,6 +1588,34 @@ pub enum MessageType {
     ContextMenuCommand = 23,
     /// A message from an auto moderation action.
     AutoModerationAction = 24,
+    /// An indicator that a member purchased a role subscription.
+    RoleSubscriptionPurchase = 25,
+    /// An upsell of an application's premium subscription, sent in response to
+    /// an interaction.
+    InteractionPremiumUpsell = 26,
+    /// An indicator that a stage was started.
+    StageStart = 27,
+    /// An indicator that a stage was ended.
+    StageEnd = 28,
+    /// An indicator that a member became a stage speaker.
+    StageSpeaker = 29,
+    /// An indicator that the topic of a stage was changed.
+    StageTopic = 31,
+    /// An indicator that the guild subscribed to an application's premium
+    /// subscription.
+    GuildApplicationPremiumSubscription = 32,
+    /// An indicator that the guild enabled its raid alert mode.
+    GuildIncidentAlertModeEnabled = 36,
+    /// An indicator that the guild disabled its raid alert mode.
+    GuildIncidentAlertModeDisabled = 37,
+    /// An indicator that a raid was reported in the guild.
+    GuildIncidentReportRaid = 38,
+    /// An indicator that a reported raid was a false alarm.
+    GuildIncidentReportFalseAlarm = 39,
+    /// An indicator that a member purchased an item from the guild's shop.
+    PurchaseNotification = 44,
+    /// The result of a poll that ended.
+    PollResult = 46,
     /// An indicator that the message is of unknown type.
     Unknown = !0,
 }
@@ -1139,7 +1637,7 @@ enum_number!(MessageType {
     GuildDiscoveryDisqualified,
     GuildDiscoveryRequalified,
     GuildDiscoveryGracePeriodInitialWarning,
-    GuildDiscoveryGracePeriodFinalWarning
+    GuildDiscoveryGracePeriodFinalWarning,
     ThreadCreated,
     InlineReply,
     ChatInputCommand,
@@ -1147,8 +1645,40 @@ enum_number!(MessageType {
     GuildInviteReminder,
     ContextMenuCommand,
     AutoModerationAction,
+    RoleSubscriptionPurchase,
+    InteractionPremiumUpsell,
+    StageStart,
+    StageEnd,
+    StageSpeaker,
+    StageTopic,
+    GuildApplicationPremiumSubscription,
+    GuildIncidentAlertModeEnabled,
+    GuildIncidentAlertModeDisabled,
+    GuildIncidentReportRaid,
+    GuildIncidentReportFalseAlarm,
+    PurchaseNotification,
+    PollResult,
 });
 
+impl MessageType {
+    /// Whether messages of this type are system messages, which are sent by
+    /// Discord rather than written by their author.
+    ///
+    /// Refer to [`Message::system_content`] for the text Discord renders for
+    /// them.
+    #[must_use]
+    pub fn is_system(self) -> bool {
+        !matches!(
+            self,
+            Self::Regular
+                | Self::InlineReply
+                | Self::ChatInputCommand
+                | Self::ContextMenuCommand
+                | Self::Unknown
+        )
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-activity-types).
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
@@ -1202,6 +1732,36 @@ pub struct MessageActivity {
     pub party_id: Option<String>,
 }
 
+/// The state of the message referenced by a [`Message`], as resolved by
+/// [`Message::referenced_message_state`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum ReferencedMessage<'a> {
+    /// The message is neither a reply nor a thread starter message.
+    None,
+    /// The referenced message, as loaded by Discord.
+    Loaded(&'a Message),
+    /// The referenced message was deleted.
+    Deleted,
+    /// Discord did not attempt to load the referenced message, so its state is
+    /// unknown.
+    ///
+    /// Use [`Message::fetch_referenced_message`] to request it.
+    NotLoaded,
+}
+
+/// Information about a call in a private channel.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-call-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageCall {
+    /// The Ids of the users that participated in the call.
+    pub participants: Vec<UserId>,
+    /// When the call ended, if it has.
+    pub ended_timestamp: Option<Timestamp>,
+}
+
 /// Reference data sent with crossposted messages.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure).
@@ -1214,6 +1774,77 @@ pub struct MessageReference {
     pub channel_id: ChannelId,
     /// ID of the originating message's guild.
     pub guild_id: Option<GuildId>,
+    /// Whether sending a reply fails if the referenced message does not exist
+    /// anymore. Discord defaults to `true`.
+    ///
+    /// This is only used when sending messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fail_if_not_exists: Option<bool>,
+    /// Whether the message replies to or forwards the originating message.
+    #[serde(default, rename = "type")]
+    pub kind: MessageReferenceKind,
+}
+
+impl MessageReference {
+    /// Sets whether sending a reply fails if the referenced message was
+    /// deleted. If set to `false`, the message is sent without the reply
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// Reply to a message that may have been deleted in the meantime:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::{Message, MessageReference};
+    /// # async fn run(http: Http, message: Message) -> serenity::Result<()> {
+    /// let reference = MessageReference::from(&message).fail_if_not_exists(false);
+    ///
+    /// message
+    ///     .channel_id
+    ///     .send_message(&http, |m| m.content("Done!").reference_message(reference))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn fail_if_not_exists(mut self, fail_if_not_exists: bool) -> Self {
+        self.fail_if_not_exists = Some(fail_if_not_exists);
+        self
+    }
+
+    /// Sets whether the message replies to or forwards the originating
+    /// message.
+    #[must_use]
+    pub fn kind(mut self, kind: MessageReferenceKind) -> Self {
+        self.kind = kind;
+        self
+    }
+}
+
+/// Whether a [`MessageReference`] is a reply or a forward.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-types).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum MessageReferenceKind {
+    /// A reply to, or the crosspost of, a message in the same channel.
+    Default = 0,
+    /// A forward of a message, which may be in another channel.
+    Forward = 1,
+    /// An indicator that the reference type is unknown.
+    Unknown = !0,
+}
+
+enum_number!(MessageReferenceKind {
+    Default,
+    Forward
+});
+
+impl Default for MessageReferenceKind {
+    fn default() -> Self {
+        Self::Default
+    }
 }
 
 impl From<&Message> for MessageReference {
@@ -1222,6 +1853,8 @@ impl From<&Message> for MessageReference {
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
+            fail_if_not_exists: None,
+            kind: MessageReferenceKind::Default,
         }
     }
 }
@@ -1232,6 +1865,8 @@ impl From<(ChannelId, MessageId)> for MessageReference {
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
+            fail_if_not_exists: None,
+            kind: MessageReferenceKind::Default,
         }
     }
 }
@@ -1253,27 +1888,188 @@ pub struct ChannelMention {
 bitflags! {
     /// Describes extra features of the message.
     ///
+    /// Only [`SUPPRESS_EMBEDS`] and [`SUPPRESS_NOTIFICATIONS`] can be set by
+    /// bots when sending a message, and [`SUPPRESS_EMBEDS`] when editing one.
+    /// [`EPHEMERAL`] can only be set on interaction responses. The other flags
+    /// are set by Discord and read-only.
+    ///
     /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-flags).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::model::channel::MessageFlags;
+    ///
+    /// let flags = MessageFlags::empty().suppress_embeds(true).suppress_notifications(true);
+    ///
+    /// assert_eq!(flags, MessageFlags::SUPPRESS_EMBEDS | MessageFlags::SUPPRESS_NOTIFICATIONS);
+    /// assert!(flags.is_suppressing_notifications());
+    /// ```
+    ///
+    /// [`SUPPRESS_EMBEDS`]: Self::SUPPRESS_EMBEDS
+    /// [`SUPPRESS_NOTIFICATIONS`]: Self::SUPPRESS_NOTIFICATIONS
+    /// [`EPHEMERAL`]: Self::EPHEMERAL
     #[derive(Default)]
     pub struct MessageFlags: u64 {
         /// This message has been published to subscribed channels (via Channel Following).
+        ///
+        /// Read-only.
         const CROSSPOSTED = 1 << 0;
         /// This message originated from a message in another channel (via Channel Following).
+        ///
+        /// Read-only.
         const IS_CROSSPOST = 1 << 1;
         /// Do not include any embeds when serializing this message.
+        ///
+        /// Settable when sending or editing a message.
         const SUPPRESS_EMBEDS = 1 << 2;
         /// The source message for this crosspost has been deleted (via Channel Following).
+        ///
+        /// Read-only.
         const SOURCE_MESSAGE_DELETED = 1 << 3;
         /// This message came from the urgent message system.
+        ///
+        /// Read-only.
         const URGENT = 1 << 4;
         /// This message has an associated thread, with the same id as the message.
+        ///
+        /// Read-only.
         const HAS_THREAD = 1 << 5;
         /// This message is only visible to the user who invoked the Interaction.
+        ///
+        /// Settable on interaction responses only.
         const EPHEMERAL = 1 << 6;
         /// This message is an Interaction Response and the bot is "thinking".
+        ///
+        /// Read-only, set by deferring the interaction response.
         const LOADING = 1 << 7;
         /// This message failed to mention some roles and add their members to the thread.
+        ///
+        /// Read-only.
         const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
+        /// This message will not trigger push and desktop notifications.
+        ///
+        /// Settable when sending a message.
+        const SUPPRESS_NOTIFICATIONS = 1 << 12;
+    }
+}
+
+impl MessageFlags {
+    /// Sets or removes the [`SUPPRESS_EMBEDS`] flag.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: Self::SUPPRESS_EMBEDS
+    #[must_use]
+    pub fn suppress_embeds(mut self, suppress: bool) -> Self {
+        self.set(Self::SUPPRESS_EMBEDS, suppress);
+        self
+    }
+
+    /// Sets or removes the [`SUPPRESS_NOTIFICATIONS`] flag.
+    ///
+    /// [`SUPPRESS_NOTIFICATIONS`]: Self::SUPPRESS_NOTIFICATIONS
+    #[must_use]
+    pub fn suppress_notifications(mut self, suppress: bool) -> Self {
+        self.set(Self::SUPPRESS_NOTIFICATIONS, suppress);
+        self
+    }
+
+    /// Sets or removes the [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: Self::EPHEMERAL
+    #[must_use]
+    pub fn ephemeral(mut self, ephemeral: bool) -> Self {
+        self.set(Self::EPHEMERAL, ephemeral);
+        self
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`CROSSPOSTED`] flag.
+    ///
+    /// [`CROSSPOSTED`]: Self::CROSSPOSTED
+    #[must_use]
+    pub fn is_crossposted(self) -> bool {
+        self.contains(Self::CROSSPOSTED)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`IS_CROSSPOST`] flag.
+    ///
+    /// [`IS_CROSSPOST`]: Self::IS_CROSSPOST
+    #[must_use]
+    pub fn is_crosspost(self) -> bool {
+        self.contains(Self::IS_CROSSPOST)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`SUPPRESS_EMBEDS`] flag.
+    ///
+    /// [`SUPPRESS_EMBEDS`]: Self::SUPPRESS_EMBEDS
+    #[must_use]
+    pub fn is_suppressing_embeds(self) -> bool {
+        self.contains(Self::SUPPRESS_EMBEDS)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`SOURCE_MESSAGE_DELETED`] flag.
+    ///
+    /// [`SOURCE_MESSAGE_DELETED`]: Self::SOURCE_MESSAGE_DELETED
+    #[must_use]
+    pub fn is_source_message_deleted(self) -> bool {
+        self.contains(Self::SOURCE_MESSAGE_DELETED)
+    }
+
+    /// Shorthand for checking that the set of flags contains the [`URGENT`]
+    /// flag.
+    ///
+    /// [`URGENT`]: Self::URGENT
+    #[must_use]
+    pub fn is_urgent(self) -> bool {
+        self.contains(Self::URGENT)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`HAS_THREAD`] flag.
+    ///
+    /// [`HAS_THREAD`]: Self::HAS_THREAD
+    #[must_use]
+    pub fn has_thread(self) -> bool {
+        self.contains(Self::HAS_THREAD)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`EPHEMERAL`] flag.
+    ///
+    /// [`EPHEMERAL`]: Self::EPHEMERAL
+    #[must_use]
+    pub fn is_ephemeral(self) -> bool {
+        self.contains(Self::EPHEMERAL)
+    }
+
+    /// Shorthand for checking that the set of flags contains the [`LOADING`]
+    /// flag.
+    ///
+    /// [`LOADING`]: Self::LOADING
+    #[must_use]
+    pub fn is_loading(self) -> bool {
+        self.contains(Self::LOADING)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`FAILED_TO_MENTION_SOME_ROLES_IN_THREAD`] flag.
+    ///
+    /// [`FAILED_TO_MENTION_SOME_ROLES_IN_THREAD`]: Self::FAILED_TO_MENTION_SOME_ROLES_IN_THREAD
+    #[must_use]
+    pub fn has_failed_to_mention_some_roles_in_thread(self) -> bool {
+        self.contains(Self::FAILED_TO_MENTION_SOME_ROLES_IN_THREAD)
+    }
+
+    /// Shorthand for checking that the set of flags contains the
+    /// [`SUPPRESS_NOTIFICATIONS`] flag.
+    ///
+    /// [`SUPPRESS_NOTIFICATIONS`]: Self::SUPPRESS_NOTIFICATIONS
+    #[must_use]
+    pub fn is_suppressing_notifications(self) -> bool {
+        self.contains(Self::SUPPRESS_NOTIFICATIONS)
     }
 }
 
@@ -1311,3 +2107,309 @@ impl MessageId {
         self.link(channel_id, guild_id)
     }
 }
+
+/// The welcome messages Discord picks from for [`MessageType::MemberJoin`].
+#[cfg(feature = "model")]
+const MEMBER_JOIN_MESSAGES: [&str; 13] = [
+    "{} joined the party.",
+    "{} is here.",
+    "Welcome, {}. We hope you brought pizza.",
+    "A wild {} appeared.",
+    "{} just landed.",
+    "{} just slid into the server.",
+    "{} just showed up!",
+    "Welcome {}. Say hi!",
+    "{} hopped into the server.",
+    "Everyone welcome {}!",
+    "Glad you're here, {}.",
+    "Good to see you, {}.",
+    "Yay you made it, {}!",
+];
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{Message, MessageFlags, ReferencedMessage};
+    use crate::json::{from_value, json, JsonMap, Value};
+    use crate::model::ModelError;
+    use crate::Error;
+
+    fn map(value: Value) -> JsonMap {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_embed_length_is_combined() {
+        let embed = json!({
+            "title": "a".repeat(1000),
+            "description": "b".repeat(2000),
+        });
+
+        let two = map(json!({ "embeds": [embed.clone(), embed.clone()] }));
+        assert!(Message::check_embed_length(&two).is_ok());
+
+        let three = map(json!({ "embeds": [embed.clone(), embed.clone(), embed] }));
+        assert!(matches!(
+            Message::check_embed_length(&three),
+            Err(Error::Model(ModelError::EmbedTooLarge(3000)))
+        ));
+    }
+
+    #[test]
+    fn test_message_flags() {
+        let flags = MessageFlags::EPHEMERAL.suppress_embeds(true);
+        assert_eq!(flags, MessageFlags::EPHEMERAL | MessageFlags::SUPPRESS_EMBEDS);
+        assert!(flags.is_ephemeral());
+        assert!(flags.is_suppressing_embeds());
+        assert!(!flags.is_loading());
+
+        let flags = flags.ephemeral(false);
+        assert_eq!(flags, MessageFlags::SUPPRESS_EMBEDS);
+        assert!(!flags.is_ephemeral());
+    }
+
+    #[test]
+    fn test_embed_amount() {
+        let embeds = map(json!({ "embeds": vec![json!({}); 11] }));
+
+        assert!(matches!(
+            Message::check_embed_length(&embeds),
+            Err(Error::Model(ModelError::EmbedAmount))
+        ));
+    }
+
+    #[test]
+    fn test_action_row_count() {
+        let row = json!({ "type": 1, "components": [] });
+
+        let five = map(json!({ "components": vec![row.clone(); 5] }));
+        assert!(Message::check_components(&five).is_ok());
+
+        let six = map(json!({ "components": vec![row; 6] }));
+        assert!(matches!(
+            Message::check_components(&six),
+            Err(Error::Model(ModelError::ActionRowAmount))
+        ));
+    }
+
+    #[test]
+    fn test_button_count() {
+        let button = json!({ "type": 2, "style": 1, "custom_id": "id" });
+
+        let five = map(json!({
+            "components": [{ "type": 1, "components": vec![button.clone(); 5] }]
+        }));
+        assert!(Message::check_button_count(&five).is_ok());
+
+        let six = map(json!({
+            "components": [{ "type": 1, "components": vec![button; 6] }]
+        }));
+        assert!(matches!(
+            Message::check_button_count(&six),
+            Err(Error::Model(ModelError::ButtonAmount))
+        ));
+    }
+
+    #[test]
+    fn test_select_menu_default_values() {
+        let menu = |kind: u8, max_values: u8, default_values: Value| {
+            map(json!({
+                "components": [{ "type": 1, "components": [{
+                    "type": kind,
+                    "custom_id": "id",
+                    "max_values": max_values,
+                    "default_values": default_values,
+                }]}]
+            }))
+        };
+        let user = json!({ "type": "user", "id": "1" });
+        let role = json!({ "type": "role", "id": "2" });
+
+        let valid = menu(7, 2, json!([user.clone(), role.clone()]));
+        assert!(Message::check_select_menu_default_values(&valid).is_ok());
+
+        let too_many = menu(7, 1, json!([user.clone(), role.clone()]));
+        assert!(matches!(
+            Message::check_select_menu_default_values(&too_many),
+            Err(Error::Model(ModelError::SelectMenuDefaultValueAmount))
+        ));
+
+        let mismatched = menu(5, 2, json!([user, role]));
+        assert!(matches!(
+            Message::check_select_menu_default_values(&mismatched),
+            Err(Error::Model(ModelError::SelectMenuDefaultValueType))
+        ));
+    }
+
+    #[test]
+    fn test_fail_if_not_exists() {
+        use super::MessageReference;
+        use crate::json::to_value;
+        use crate::model::id::{ChannelId, MessageId};
+
+        let reference = MessageReference::from((ChannelId(1), MessageId(2)));
+        let value = map(to_value(&reference).unwrap());
+        assert!(value.get("fail_if_not_exists").is_none());
+
+        let value = map(to_value(&reference.fail_if_not_exists(false)).unwrap());
+        assert_eq!(value.get("fail_if_not_exists"), Some(&json!(false)));
+    }
+
+    #[test]
+    fn test_resolve_message_reference() {
+        use super::{MessageReference, MessageReferenceKind};
+        use crate::json::to_value;
+        use crate::model::id::{ChannelId, MessageId};
+
+        let reference = to_value(MessageReference::from((ChannelId(1), MessageId(2)))).unwrap();
+
+        let mut reply = map(json!({ "content": "a", "message_reference": reference }));
+        assert!(Message::resolve_message_reference(ChannelId(1), &mut reply, false).is_ok());
+        assert_eq!(reply["message_reference"]["type"], json!(MessageReferenceKind::Default));
+
+        assert!(matches!(
+            Message::resolve_message_reference(ChannelId(3), &mut reply, false),
+            Err(Error::Model(ModelError::CrossChannelReply))
+        ));
+
+        let mut forward = map(json!({ "message_reference": reference }));
+        assert!(matches!(
+            Message::resolve_message_reference(ChannelId(3), &mut forward.clone(), true),
+            Err(Error::Model(ModelError::CrossChannelReply))
+        ));
+        assert!(Message::resolve_message_reference(ChannelId(3), &mut forward, false).is_ok());
+        assert_eq!(forward["message_reference"]["type"], json!(MessageReferenceKind::Forward));
+    }
+
+    #[test]
+    fn test_referenced_message_state() {
+        let mut message = json!({
+            "id": "2",
+            "channel_id": "1",
+            "author": {
+                "id": "3",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "content": "",
+            "timestamp": "2022-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 19,
+            "message_reference": { "channel_id": "1", "message_id": "1" },
+        });
+
+        let not_loaded: Message = from_value(message.clone()).unwrap();
+        assert!(matches!(not_loaded.referenced_message_state(), ReferencedMessage::NotLoaded));
+
+        message["referenced_message"] = Value::Null;
+        let deleted: Message = from_value(message.clone()).unwrap();
+        assert!(matches!(deleted.referenced_message_state(), ReferencedMessage::Deleted));
+
+        message["referenced_message"] = message.clone();
+        let loaded: Message = from_value(message).unwrap();
+        assert!(matches!(
+            loaded.referenced_message_state(),
+            ReferencedMessage::Loaded(referenced) if referenced.id.0 == 2
+        ));
+    }
+
+    #[test]
+    fn test_thread() {
+        let message: Message = from_value(json!({
+            "id": "2",
+            "channel_id": "1",
+            "guild_id": "4",
+            "author": {
+                "id": "3",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "content": "",
+            "timestamp": "2022-01-01T00:00:00Z",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0,
+            "flags": 32,
+            "thread": {
+                "id": "2",
+                "guild_id": "4",
+                "parent_id": "1",
+                "type": 11,
+                "name": "thread",
+            },
+        }))
+        .unwrap();
+
+        let thread = message.thread.unwrap();
+        assert_eq!(thread.id.0, message.id.0);
+        assert_eq!(thread.parent_id.map(|id| id.0), Some(1));
+    }
+
+    #[test]
+    fn test_system_content() {
+        let message = |kind: u64, content: &str| -> Message {
+            from_value(json!({
+                "id": "2",
+                "channel_id": "1",
+                "author": {
+                    "id": "3",
+                    "username": "user",
+                    "discriminator": "0001",
+                    "avatar": null,
+                },
+                "content": content,
+                "timestamp": "2022-01-01T00:00:00Z",
+                "edited_timestamp": null,
+                "tts": false,
+                "mention_everyone": false,
+                "mentions": [],
+                "mention_roles": [],
+                "attachments": [],
+                "embeds": [],
+                "pinned": false,
+                "type": kind,
+            }))
+            .unwrap()
+        };
+
+        assert_eq!(message(0, "hello").system_content(), None);
+        assert_eq!(message(19, "hello").system_content(), None);
+        assert_eq!(
+            message(6, "").system_content().as_deref(),
+            Some("user pinned a message to this channel.")
+        );
+        assert_eq!(
+            message(7, "").system_content().as_deref(),
+            Some("user just slid into the server.")
+        );
+        assert_eq!(
+            message(8, "").system_content().as_deref(),
+            Some("user just boosted the server!")
+        );
+        assert_eq!(
+            message(8, "2").system_content().as_deref(),
+            Some("user just boosted the server **2** times!")
+        );
+        assert_eq!(
+            message(10, "").system_content().as_deref(),
+            Some("user just boosted the server! The server has achieved **Level 2!**")
+        );
+    }
+}
@@ -5,6 +5,7 @@ mod attachment_type;
 mod channel_category;
 mod channel_id;
 mod embed;
+mod group_channel;
 mod guild_channel;
 mod message;
 mod partial_channel;
@@ -23,6 +24,7 @@ pub use self::attachment_type::*;
 pub use self::channel_category::*;
 pub use self::channel_id::*;
 pub use self::embed::*;
+pub use self::group_channel::*;
 pub use self::guild_channel::*;
 pub use self::message::*;
 pub use self::partial_channel::*;
@@ -42,6 +44,7 @@ use crate::model::Timestamp;
 use crate::utils::parse_channel;
 
 /// A container for any channel.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub enum Channel {
@@ -296,6 +299,8 @@ pub enum ChannelType {
     Private = 1,
     /// An indicator that the channel is a voice [`GuildChannel`].
     Voice = 2,
+    /// An indicator that the channel is a [`GroupChannel`].
+    GroupDm = 3,
     /// An indicator that the channel is the channel of a [`ChannelCategory`].
     Category = 4,
     /// An indicator that the channel is a `NewsChannel`.
@@ -308,7 +313,8 @@ pub enum ChannelType {
     PublicThread = 11,
     /// An indicator that the channel is a private thread [`GuildChannel`].
     PrivateThread = 12,
-    /// An indicator that the channel is a stage [`GuildChannel`].
+    /// An indicator that the channel is a stage [`GuildChannel`], the
+    /// `GUILD_STAGE_VOICE` type.
     Stage = 13,
     /// An indicator that the channel is a directory [`GuildChannel`] in a [hub].
     ///
@@ -325,6 +331,7 @@ enum_number!(ChannelType {
     Text,
     Private,
     Voice,
+    GroupDm,
     Category,
     News,
     NewsThread,
@@ -344,6 +351,7 @@ impl ChannelType {
             Self::Private => "private",
             Self::Text => "text",
             Self::Voice => "voice",
+            Self::GroupDm => "group_dm",
             Self::Category => "category",
             Self::News => "news",
             Self::NewsThread => "news_thread",
@@ -433,6 +441,85 @@ pub enum PermissionOverwriteType {
     Role(RoleId),
 }
 
+/// The changes between two lists of [`PermissionOverwrite`]s, such as the ones
+/// of a channel before and after it was updated.
+///
+/// Overwrites are matched by their [`PermissionOverwriteType`], that is the
+/// member or role they apply to.
+///
+/// # Examples
+///
+/// Report the overwrites that changed when a channel is updated:
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "cache")]
+/// # mod example {
+/// use serenity::model::channel::{Channel, PermissionOverwriteChanges};
+/// use serenity::prelude::*;
+///
+/// struct Handler;
+///
+/// #[serenity::async_trait]
+/// impl EventHandler for Handler {
+///     async fn channel_update(&self, _: Context, old: Option<Channel>, new: Channel) {
+///         if let (Some(Channel::Guild(old)), Channel::Guild(new)) = (old, new) {
+///             let changes = PermissionOverwriteChanges::new(
+///                 &old.permission_overwrites,
+///                 &new.permission_overwrites,
+///             );
+///
+///             for overwrite in changes.added {
+///                 println!("Added overwrite for {:?}", overwrite.kind);
+///             }
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct PermissionOverwriteChanges {
+    /// The overwrites that were added.
+    pub added: Vec<PermissionOverwrite>,
+    /// The overwrites that were removed.
+    pub removed: Vec<PermissionOverwrite>,
+    /// The overwrites whose permissions were modified, as pairs of the old and
+    /// the new overwrite.
+    pub modified: Vec<(PermissionOverwrite, PermissionOverwrite)>,
+}
+
+impl PermissionOverwriteChanges {
+    /// Computes the changes from the `old` to the `new` overwrites.
+    #[must_use]
+    pub fn new(old: &[PermissionOverwrite], new: &[PermissionOverwrite]) -> Self {
+        let mut changes = Self::default();
+
+        for overwrite in new {
+            match old.iter().find(|old| old.kind == overwrite.kind) {
+                Some(old) if old != overwrite => {
+                    changes.modified.push((old.clone(), overwrite.clone()));
+                },
+                Some(_) => {},
+                None => changes.added.push(overwrite.clone()),
+            }
+        }
+
+        for overwrite in old {
+            if !new.iter().any(|new| new.kind == overwrite.kind) {
+                changes.removed.push(overwrite.clone());
+            }
+        }
+
+        changes
+    }
+
+    /// Whether no overwrites were added, removed or modified.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.modified.is_empty()
+    }
+}
+
 /// The video quality mode for a voice channel.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-object-video-quality-modes).
@@ -495,6 +582,43 @@ pub struct ThreadMetadata {
     pub invitable: bool,
 }
 
+/// A tag that can be applied to the threads of a forum channel.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#forum-tag-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForumTag {
+    /// The id of the tag.
+    pub id: ForumTagId,
+    /// The name of the tag.
+    pub name: String,
+    /// Whether the tag can only be applied or removed by members with the
+    /// [Manage Threads] permission.
+    ///
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    pub moderated: bool,
+    /// The id of the guild's custom emoji of the tag.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the tag's emoji.
+    pub emoji_name: Option<String>,
+}
+
+bitflags! {
+    /// Describes extra features of a channel.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-object-channel-flags).
+    #[derive(Default)]
+    pub struct ChannelFlags: u64 {
+        /// The thread is pinned to the top of its parent forum channel.
+        const PINNED = 1 << 1;
+        /// A tag is required to be applied to threads created in the forum
+        /// channel, see [`GuildChannel::available_tags`].
+        const REQUIRE_TAG = 1 << 4;
+        /// The download options for media in the channel are hidden.
+        const HIDE_MEDIA_DOWNLOAD_OPTIONS = 1 << 15;
+    }
+}
+
 /// A response to getting several threads channels.
 ///
 /// Discord docs: scattered, but e.g. [here](https://discord.com/developers/docs/resources/channel#list-public-archived-threads-response-body).
@@ -512,6 +636,42 @@ pub struct ThreadsData {
 
 #[cfg(test)]
 mod test {
+    use super::{PermissionOverwrite, PermissionOverwriteChanges, PermissionOverwriteType};
+    use crate::model::id::{RoleId, UserId};
+    use crate::model::Permissions;
+
+    #[test]
+    fn test_permission_overwrite_changes() {
+        let overwrite = |kind, allow| PermissionOverwrite {
+            allow,
+            deny: Permissions::empty(),
+            kind,
+        };
+
+        let role = PermissionOverwriteType::Role(RoleId(1));
+        let member = PermissionOverwriteType::Member(UserId(2));
+        let unchanged = PermissionOverwriteType::Role(RoleId(3));
+
+        let old = [
+            overwrite(role, Permissions::SEND_MESSAGES),
+            overwrite(unchanged, Permissions::ADD_REACTIONS),
+        ];
+        let new = [
+            overwrite(unchanged, Permissions::ADD_REACTIONS),
+            overwrite(member, Permissions::VIEW_CHANNEL),
+        ];
+
+        let changes = PermissionOverwriteChanges::new(&old, &new);
+        assert_eq!(changes.added, vec![overwrite(member, Permissions::VIEW_CHANNEL)]);
+        assert_eq!(changes.removed, vec![overwrite(role, Permissions::SEND_MESSAGES)]);
+        assert!(changes.modified.is_empty());
+
+        let modified = [overwrite(unchanged, Permissions::empty())];
+        let changes = PermissionOverwriteChanges::new(&old[1..], &modified);
+        assert_eq!(changes.modified, vec![(old[1].clone(), modified[0].clone())]);
+        assert!(PermissionOverwriteChanges::new(&old, &old).is_empty());
+    }
+
     #[cfg(all(feature = "model", feature = "utils"))]
     mod model_utils {
         use crate::model::prelude::*;
@@ -535,10 +695,15 @@ mod test {
                 rtc_region: None,
                 video_quality_mode: None,
                 message_count: None,
+                total_message_sent: None,
                 member_count: None,
                 thread_metadata: None,
                 member: None,
                 default_auto_archive_duration: None,
+                default_thread_rate_limit_per_user: None,
+                available_tags: Vec::new(),
+                applied_tags: Vec::new(),
+                flags: None,
             }
         }
 
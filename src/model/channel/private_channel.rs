@@ -243,6 +243,29 @@ impl PrivateChannel {
         self.id.reaction_users(&http, message_id, reaction_type, limit, after).await
     }
 
+    /// Gets the list of [`User`]s who have reacted to a [`Message`] with a
+    /// certain [`Emoji`], considering only reactions of the given kind, e.g.
+    /// [`ReactionKind::Burst`] for super reactions.
+    ///
+    /// Refer to [`Self::reaction_users`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a message with the given Id does not exist
+    /// in the channel.
+    #[inline]
+    pub async fn reaction_users_of_kind(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reaction_type: impl Into<ReactionType>,
+        kind: ReactionKind,
+        limit: Option<u8>,
+        after: impl Into<Option<UserId>>,
+    ) -> Result<Vec<User>> {
+        self.id.reaction_users_of_kind(&http, message_id, reaction_type, kind, limit, after).await
+    }
+
     /// Pins a [`Message`] to the channel.
     ///
     /// # Errors
@@ -199,7 +199,7 @@ impl Reaction {
     ///
     /// **Note**: This will send a request to the REST API. Prefer maintaining
     /// your own message cache or otherwise having the message available if
-    /// possible.
+    /// possible, or use [`ChannelId::to_message`] to check the cache first.
     ///
     /// # Errors
     ///
@@ -530,6 +530,31 @@ impl From<EmojiIdentifier> for ReactionType {
     }
 }
 
+/// The kind of a reaction, used to filter the users who reacted to a message.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#get-reactions-reaction-types).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ReactionKind {
+    /// A normal reaction.
+    Normal = 0,
+    /// A super reaction, also known as a burst reaction.
+    Burst = 1,
+    /// An indicator that the reaction kind is unknown.
+    Unknown = !0,
+}
+
+enum_number!(ReactionKind {
+    Normal,
+    Burst
+});
+
+impl Default for ReactionKind {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
 #[derive(Debug)]
 pub struct ReactionConversionError;
 
@@ -3,6 +3,8 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use super::application::interaction::InteractionResponseType;
+use super::id::ForumTagId;
 use super::Permissions;
 
 /// An error returned from the [`model`] module.
@@ -70,8 +72,10 @@ pub enum Error {
     DeleteMessageDaysAmount(u8),
     /// When attempting to send a message with over 10 embeds.
     EmbedAmount,
-    /// Indicates that the textual content of an embed exceeds the maximum
-    /// length.
+    /// Indicates that the combined textual content of a message's embeds
+    /// exceeds the maximum length.
+    ///
+    /// The number of characters larger than the limit is provided.
     EmbedTooLarge(usize),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
@@ -150,13 +154,18 @@ pub enum Error {
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// When attempting to change the current user's username more than twice
+    /// per hour.
+    UsernameRateLimited,
     /// An indicator that the [`ChannelType`] cannot perform an action.
     ///
     /// [`ChannelType`]: super::channel::ChannelType
     InvalidChannelType,
-    /// Indicates that the webhook name is under the 2 characters limit.
+    /// Indicates that the webhook or guild name is under the 2 characters
+    /// limit.
     NameTooShort,
-    /// Indicates that the webhook name is over the 100 characters limit.
+    /// Indicates that the webhook or guild name is over the 100 characters
+    /// limit.
     NameTooLong,
     /// Indicates that the bot is not author of the message.
     /// This error is returned in private/direct channels.
@@ -168,8 +177,74 @@ pub enum Error {
     DeleteNitroSticker,
     /// Indicates that the sticker file is missing.
     NoStickerFileSet,
+    /// Indicates that the emoji image is missing.
+    NoEmojiImageSet,
+    /// Indicates that an emoji image is over the 256 KiB limit.
+    ///
+    /// The number of bytes larger than the limit is provided.
+    EmojiImageTooLarge(usize),
+    /// Indicates that an emoji name contains characters other than
+    /// alphanumeric characters and underscores.
+    InvalidEmojiName,
     /// When attempting to send a message with over 3 stickers.
     StickerAmount,
+    /// When attempting to send a message with over 5 action rows.
+    ActionRowAmount,
+    /// When attempting to send a message with an action row holding over 5
+    /// buttons.
+    ButtonAmount,
+    /// When attempting to set over 5 tags on an application.
+    ApplicationTagAmount,
+    /// Indicates that an application's description is over the 400 characters
+    /// limit.
+    ///
+    /// The number of characters larger than the limit is provided.
+    ApplicationDescriptionTooLong(usize),
+    /// When attempting to pre-select more values in a select menu than its
+    /// maximum number of selections.
+    SelectMenuDefaultValueAmount,
+    /// When attempting to pre-select a value in a select menu that doesn't
+    /// match the select menu's type, e.g. a role in a user select menu.
+    SelectMenuDefaultValueType,
+    /// When attempting to create a guild with roles whose first role is not
+    /// the @everyone role.
+    EveryoneRoleNotFirst,
+    /// When attempting to respond to an interaction with a response type it
+    /// does not support, e.g. with a modal to a modal submit.
+    InvalidInteractionResponseType(InteractionResponseType),
+    /// When attempting to set over 5 role connection metadata records on an
+    /// application.
+    RoleConnectionMetadataAmount,
+    /// Indicates that a role connection metadata key is not made up of 1 to
+    /// 50 lowercase letters, digits or underscores.
+    ///
+    /// The invalid key is provided.
+    InvalidRoleConnectionMetadataKey(String),
+    /// When attempting to respond to an autocomplete interaction with over 25
+    /// choices.
+    AutocompleteChoiceAmount,
+    /// When attempting to move a webhook to another channel while editing it
+    /// with its token, which requires bot authentication instead.
+    WebhookChannelWithToken,
+    /// When attempting to apply more than 5 tags to a thread of a forum
+    /// channel.
+    ForumTagAmount,
+    /// Indicates that a tag applied to a thread is not available in its forum
+    /// channel.
+    ///
+    /// The invalid tag's id is provided.
+    InvalidForumTag(ForumTagId),
+    /// Indicates that the slowmode of a channel or thread is over
+    /// [`constants::RATE_LIMIT_PER_USER_MAX`] seconds.
+    ///
+    /// The invalid slowmode is provided.
+    ///
+    /// [`constants::RATE_LIMIT_PER_USER_MAX`]: crate::constants::RATE_LIMIT_PER_USER_MAX
+    RateLimitPerUserTooLong(u64),
+    /// Indicates that a message with content references a message of another
+    /// channel. Replies must be sent in the channel of the referenced message,
+    /// and forwards cannot have content of their own.
+    CrossChannelReply,
 }
 
 impl Error {
@@ -209,13 +284,47 @@ impl fmt::Display for Error {
             Self::MessageAlreadyCrossposted => f.write_str("Message already crossposted."),
             Self::CannotCrosspostMessage => f.write_str("Cannot crosspost this message type."),
             Self::MessagingBot => f.write_str("Attempted to message another bot user."),
+            Self::UsernameRateLimited => f.write_str("Username changed too many times."),
             Self::NameTooShort => f.write_str("Name is under the character limit."),
             Self::NameTooLong => f.write_str("Name is over the character limit."),
             Self::NotAuthor => f.write_str("The bot is not author of this message."),
             Self::NoTokenSet => f.write_str("Token is not set."),
             Self::DeleteNitroSticker => f.write_str("Cannot delete an official sticker."),
             Self::NoStickerFileSet => f.write_str("Sticker file is not set."),
+            Self::NoEmojiImageSet => f.write_str("Emoji image is not set."),
+            Self::EmojiImageTooLarge(_) => f.write_str("Emoji image is over the size limit."),
+            Self::InvalidEmojiName => f.write_str("Emoji name contains invalid characters."),
             Self::StickerAmount => f.write_str("Too many stickers in a message."),
+            Self::ActionRowAmount => f.write_str("Too many action rows in a message."),
+            Self::ButtonAmount => f.write_str("Too many buttons in an action row."),
+            Self::ApplicationTagAmount => f.write_str("Too many tags on an application."),
+            Self::ApplicationDescriptionTooLong(_) => {
+                f.write_str("Application description is over the character limit.")
+            },
+            Self::SelectMenuDefaultValueAmount => {
+                f.write_str("Too many default values in a select menu.")
+            },
+            Self::SelectMenuDefaultValueType => {
+                f.write_str("Default value does not match the select menu type.")
+            },
+            Self::EveryoneRoleNotFirst => f.write_str("The first role is not the @everyone role."),
+            Self::InvalidInteractionResponseType(kind) => {
+                write!(f, "The interaction cannot be responded to with a {:?} response.", kind)
+            },
+            Self::RoleConnectionMetadataAmount => {
+                f.write_str("Too many role connection metadata records.")
+            },
+            Self::InvalidRoleConnectionMetadataKey(_) => {
+                f.write_str("Invalid role connection metadata key.")
+            },
+            Self::AutocompleteChoiceAmount => f.write_str("Too many autocomplete choices."),
+            Self::WebhookChannelWithToken => {
+                f.write_str("Cannot move a webhook to another channel using its token.")
+            },
+            Self::ForumTagAmount => f.write_str("Too many tags applied to a thread."),
+            Self::InvalidForumTag(_) => f.write_str("The tag is not available in the forum."),
+            Self::RateLimitPerUserTooLong(_) => f.write_str("Slowmode is over the limit."),
+            Self::CrossChannelReply => f.write_str("Cannot reply to a message of another channel."),
         }
     }
 }
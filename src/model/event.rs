@@ -179,6 +179,8 @@ pub struct GuildMemberUpdateEvent {
     pub mute: bool,
     pub avatar: Option<String>,
     pub communication_disabled_until: Option<Timestamp>,
+    #[serde(default)]
+    pub flags: GuildMemberFlags,
 }
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#guild-members-chunk).
@@ -428,10 +430,35 @@ pub struct MessageUpdateEvent {
     #[deprecated(note = "deprecated by Discord")]
     pub stickers: Option<Vec<StickerItem>>,
     pub sticker_items: Option<Vec<StickerItem>>,
+    pub thread: Option<GuildChannel>,
 
     pub guild_id: Option<GuildId>, // TODO: Is this a Message field that can even change?
 }
 
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-add).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessagePollVoteAddEvent {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub guild_id: Option<GuildId>,
+    /// The id of the answer the user voted for.
+    pub answer_id: u64,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-poll-vote-remove).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessagePollVoteRemoveEvent {
+    pub user_id: UserId,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub guild_id: Option<GuildId>,
+    /// The id of the answer the user removed their vote from.
+    pub answer_id: u64,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#presence-update).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(transparent)]
@@ -668,10 +695,13 @@ pub struct ThreadMembersUpdateEvent {
     /// The approximate number of members in the thread, capped at 50.
     pub member_count: u8,
     /// The users who were added to the thread.
+    ///
+    /// **Note**: Unlike other thread members, these include the guild member
+    /// data of the user in [`ThreadMember::member`].
     #[serde(default)]
     pub added_members: Vec<ThreadMember>,
     /// The ids of the users who were removed from the thread.
-    #[serde(default)]
+    #[serde(default, rename = "removed_member_ids")]
     pub removed_members_ids: Vec<UserId>,
 }
 
@@ -912,6 +942,10 @@ pub enum Event {
     MessageDeleteBulk(MessageDeleteBulkEvent),
     /// A message has been edited, either by the user or the system
     MessageUpdate(MessageUpdateEvent),
+    /// A user has voted for an answer of a poll.
+    MessagePollVoteAdd(MessagePollVoteAddEvent),
+    /// A user has removed their vote for an answer of a poll.
+    MessagePollVoteRemove(MessagePollVoteRemoveEvent),
     /// A member's presence state (or username or avatar) has changed
     PresenceUpdate(PresenceUpdateEvent),
     /// The presence list of the user's friends should be replaced entirely
@@ -1221,6 +1255,18 @@ macro_rules! with_related_ids_for_event_types {
                 channel_id: Some(e.channel_id),
                 message_id: Some(e.id),
             },
+            Self::MessagePollVoteAdd, Self::MessagePollVoteAdd(e) => {
+                user_id: Some(e.user_id),
+                guild_id: e.guild_id.into(),
+                channel_id: Some(e.channel_id),
+                message_id: Some(e.message_id),
+            },
+            Self::MessagePollVoteRemove, Self::MessagePollVoteRemove(e) => {
+                user_id: Some(e.user_id),
+                guild_id: e.guild_id.into(),
+                channel_id: Some(e.channel_id),
+                message_id: Some(e.message_id),
+            },
             Self::PresenceUpdate, Self::PresenceUpdate(e) => {
                 user_id: Some(e.presence.user.id),
                 guild_id: e.presence.guild_id.into(),
@@ -1512,6 +1558,8 @@ impl Event {
             Self::MessageDelete(_) => EventType::MessageDelete,
             Self::MessageDeleteBulk(_) => EventType::MessageDeleteBulk,
             Self::MessageUpdate(_) => EventType::MessageUpdate,
+            Self::MessagePollVoteAdd(_) => EventType::MessagePollVoteAdd,
+            Self::MessagePollVoteRemove(_) => EventType::MessagePollVoteRemove,
             Self::PresenceUpdate(_) => EventType::PresenceUpdate,
             Self::PresencesReplace(_) => EventType::PresencesReplace,
             Self::ReactionAdd(_) => EventType::ReactionAdd,
@@ -1666,6 +1714,8 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::ReactionRemove => Event::ReactionRemove(from_value(v)?),
         EventType::ReactionRemoveAll => Event::ReactionRemoveAll(from_value(v)?),
         EventType::MessageUpdate => Event::MessageUpdate(from_value(v)?),
+        EventType::MessagePollVoteAdd => Event::MessagePollVoteAdd(from_value(v)?),
+        EventType::MessagePollVoteRemove => Event::MessagePollVoteRemove(from_value(v)?),
         EventType::PresenceUpdate => Event::PresenceUpdate(from_value(v)?),
         EventType::PresencesReplace => Event::PresencesReplace(from_value(v)?),
         EventType::Ready => Event::Ready(from_value(v)?),
@@ -1838,6 +1888,14 @@ pub enum EventType {
     ///
     /// This maps to [`MessageUpdateEvent`].
     MessageUpdate,
+    /// Indicator that a message poll vote add payload was received.
+    ///
+    /// This maps to [`MessagePollVoteAddEvent`].
+    MessagePollVoteAdd,
+    /// Indicator that a message poll vote remove payload was received.
+    ///
+    /// This maps to [`MessagePollVoteRemoveEvent`].
+    MessagePollVoteRemove,
     /// Indicator that a presence update payload was received.
     ///
     /// This maps to [`PresenceUpdateEvent`].
@@ -2058,6 +2116,8 @@ impl EventType {
     const MESSAGE_REACTION_REMOVE: &'static str = "MESSAGE_REACTION_REMOVE";
     const MESSAGE_REACTION_REMOVE_ALL: &'static str = "MESSAGE_REACTION_REMOVE_ALL";
     const MESSAGE_UPDATE: &'static str = "MESSAGE_UPDATE";
+    const MESSAGE_POLL_VOTE_ADD: &'static str = "MESSAGE_POLL_VOTE_ADD";
+    const MESSAGE_POLL_VOTE_REMOVE: &'static str = "MESSAGE_POLL_VOTE_REMOVE";
     const PRESENCE_UPDATE: &'static str = "PRESENCE_UPDATE";
     const PRESENCES_REPLACE: &'static str = "PRESENCES_REPLACE";
     const READY: &'static str = "READY";
@@ -2127,6 +2187,8 @@ impl EventType {
             Self::ReactionRemove => Some(Self::MESSAGE_REACTION_REMOVE),
             Self::ReactionRemoveAll => Some(Self::MESSAGE_REACTION_REMOVE_ALL),
             Self::MessageUpdate => Some(Self::MESSAGE_UPDATE),
+            Self::MessagePollVoteAdd => Some(Self::MESSAGE_POLL_VOTE_ADD),
+            Self::MessagePollVoteRemove => Some(Self::MESSAGE_POLL_VOTE_REMOVE),
             Self::PresenceUpdate => Some(Self::PRESENCE_UPDATE),
             Self::PresencesReplace => Some(Self::PRESENCES_REPLACE),
             Self::Ready => Some(Self::READY),
@@ -2221,6 +2283,8 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::MESSAGE_REACTION_REMOVE => EventType::ReactionRemove,
                     EventType::MESSAGE_REACTION_REMOVE_ALL => EventType::ReactionRemoveAll,
                     EventType::MESSAGE_UPDATE => EventType::MessageUpdate,
+                    EventType::MESSAGE_POLL_VOTE_ADD => EventType::MessagePollVoteAdd,
+                    EventType::MESSAGE_POLL_VOTE_REMOVE => EventType::MessagePollVoteRemove,
                     EventType::PRESENCE_UPDATE => EventType::PresenceUpdate,
                     EventType::PRESENCES_REPLACE => EventType::PresencesReplace,
                     EventType::READY => EventType::Ready,
@@ -1,9 +1,13 @@
 //! Models pertaining to the gateway.
 
+use std::time::Duration;
+
 use url::Url;
 
 use super::prelude::*;
 use super::utils::*;
+#[cfg(feature = "model")]
+use crate::constants;
 
 /// A representation of the data retrieved from the bot gateway endpoint.
 ///
@@ -267,6 +271,49 @@ impl Activity {
     {
         Activity::new(name.to_string(), ActivityType::Competing)
     }
+
+    /// Creates an [`Activity`] struct that appears as a custom status with the
+    /// given `text`, without a `Playing` or similar prefix.
+    ///
+    /// **Note**: Text longer than 128 characters is truncated. Bots can't set
+    /// an emoji on their custom status.
+    ///
+    /// # Examples
+    ///
+    /// Create a command that sets the current custom status:
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "client")]
+    /// use serenity::client::Context;
+    /// # #[cfg(feature = "framework")]
+    /// use serenity::framework::standard::{macros::command, Args, CommandResult};
+    /// use serenity::model::channel::Message;
+    /// use serenity::model::gateway::Activity;
+    ///
+    /// # #[cfg(feature = "framework")]
+    /// #[command]
+    /// async fn status(ctx: &Context, _msg: &Message, args: Args) -> CommandResult {
+    ///     let text = args.message();
+    ///     ctx.set_activity(Activity::custom(&text)).await;
+    ///
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn custom<T>(text: T) -> Activity
+    where
+        T: ToString,
+    {
+        let mut text = text.to_string();
+
+        if let Some((index, _)) = text.char_indices().nth(constants::CUSTOM_STATUS_MAX_LENGTH) {
+            text.truncate(index);
+        }
+
+        Activity {
+            state: Some(text),
+            ..Activity::new("Custom Status".to_string(), ActivityType::Custom)
+        }
+    }
 }
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#activity-object-activity-buttons).
@@ -552,6 +599,23 @@ pub struct SessionStartLimit {
     /// The number of identify requests allowed per 5 seconds.
     pub max_concurrency: u64,
 }
+
+impl SessionStartLimit {
+    /// Returns the time until the ratelimit period resets.
+    #[must_use]
+    pub fn reset_after_duration(&self) -> Duration {
+        Duration::from_millis(self.reset_after)
+    }
+
+    /// Whether no more sessions can be initiated until the ratelimit period
+    /// resets.
+    ///
+    /// Discord resets the bot's token once the limit was exceeded.
+    #[must_use]
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining == 0
+    }
+}
 /// Timestamps of when a user started and/or is ending their activity.
 ///
 /// [Discord docs](https://discord.com/developers/docs/game-sdk/activities#data-models-activitytimestamps-struct).
@@ -730,6 +794,16 @@ bitflags! {
         ///
         /// - AUTO_MODERATION_ACTION_EXECUTION
         const AUTO_MODERATION_EXECUTION = 1 << 21;
+        /// Enable following gateway events in guilds:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        const GUILD_MESSAGE_POLLS = 1 << 24;
+        /// Enable following gateway events in direct messages:
+        ///
+        /// - MESSAGE_POLL_VOTE_ADD
+        /// - MESSAGE_POLL_VOTE_REMOVE
+        const DIRECT_MESSAGE_POLLS = 1 << 25;
     }
 }
 
@@ -921,6 +995,24 @@ impl GatewayIntents {
     pub fn auto_moderation_execution(self) -> bool {
         self.contains(Self::AUTO_MODERATION_EXECUTION)
     }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [GUILD_MESSAGE_POLLS] intent.
+    ///
+    /// [GUILD_MESSAGE_POLLS]: Self::GUILD_MESSAGE_POLLS
+    #[must_use]
+    pub fn guild_message_polls(self) -> bool {
+        self.contains(Self::GUILD_MESSAGE_POLLS)
+    }
+
+    /// Shorthand for checking that the set of intents contains the
+    /// [DIRECT_MESSAGE_POLLS] intent.
+    ///
+    /// [DIRECT_MESSAGE_POLLS]: Self::DIRECT_MESSAGE_POLLS
+    #[must_use]
+    pub fn direct_message_polls(self) -> bool {
+        self.contains(Self::DIRECT_MESSAGE_POLLS)
+    }
 }
 
 impl Default for GatewayIntents {
@@ -928,3 +1020,42 @@ impl Default for GatewayIntents {
         Self::non_privileged()
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use std::time::Duration;
+
+    use super::{Activity, ActivityType, BotGateway};
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn test_custom_activity() {
+        let activity = Activity::custom("Hello");
+
+        assert_eq!(activity.kind, ActivityType::Custom);
+        assert_eq!(activity.state.as_deref(), Some("Hello"));
+
+        let activity = Activity::custom("é".repeat(130));
+
+        assert_eq!(activity.state.map(|state| state.chars().count()), Some(128));
+    }
+
+    #[test]
+    fn test_session_start_limit() {
+        let gateway: BotGateway = from_value(json!({
+            "url": "wss://gateway.discord.gg",
+            "shards": 9,
+            "session_start_limit": {
+                "total": 1000,
+                "remaining": 0,
+                "reset_after": 14400000,
+                "max_concurrency": 1,
+            },
+        }))
+        .unwrap();
+
+        let limit = gateway.session_start_limit;
+        assert!(limit.is_exhausted());
+        assert_eq!(limit.reset_after_duration(), Duration::from_secs(4 * 60 * 60));
+    }
+}
@@ -51,7 +51,11 @@ pub struct Emoji {
     /// [`Role`]: super::Role
     #[serde(default)]
     pub roles: Vec<RoleId>,
-    /// The user who created the emoji.
+    /// The user who created the emoji. This will be `None` if the current user
+    /// does not have the [Manage Emojis and Stickers] permission, or if the
+    /// emoji was not fetched via HTTP.
+    ///
+    /// [Manage Emojis and Stickers]: crate::model::permissions::Permissions::MANAGE_EMOJIS_AND_STICKERS
     pub user: Option<User>,
 }
 
@@ -218,6 +222,45 @@ impl fmt::Display for Emoji {
     }
 }
 
+/// Replaces each `:name:` shortcode in the content with the emoji returned by
+/// `find` for that name, formatted as described in the [`Display`] impl of
+/// [`Emoji`]. Unknown shortcodes and existing emoji mentions are kept as is.
+///
+/// [`Display`]: fmt::Display
+#[cfg(feature = "model")]
+pub(crate) fn replace_shortcodes<'a>(
+    content: &str,
+    mut find: impl FnMut(&str) -> Option<&'a Emoji>,
+) -> String {
+    let mut result = String::with_capacity(content.len());
+    let mut rest = content;
+
+    while let Some(start) = rest.find(':') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        let is_mention = result.ends_with('<') || result.ends_with("<a");
+
+        if let Some(end) = rest.find(':').filter(|_| !is_mention) {
+            let name = &rest[..end];
+            let is_name = !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+            if let Some(emoji) = is_name.then(|| find(name)).flatten() {
+                fmt::Write::write_fmt(&mut result, format_args!("{}", emoji))
+                    .expect("Writing to a String cannot fail");
+                rest = &rest[end + 1..];
+
+                continue;
+            }
+        }
+
+        result.push(':');
+    }
+
+    result.push_str(rest);
+    result
+}
+
 impl From<Emoji> for EmojiId {
     /// Gets the Id of an [`Emoji`].
     fn from(emoji: Emoji) -> EmojiId {
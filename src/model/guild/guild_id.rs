@@ -10,9 +10,11 @@ use crate::builder::{
     CreateApplicationCommandPermissionsData,
     CreateApplicationCommands,
     CreateChannel,
+    CreateEmoji,
     CreateScheduledEvent,
     CreateSticker,
     EditAutoModRule,
+    EditChannelPositions,
     EditGuild,
     EditGuildWelcomeScreen,
     EditGuildWidget,
@@ -33,6 +35,8 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
+use crate::constants;
+#[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http, UserPagination};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
@@ -407,6 +411,94 @@ impl GuildId {
         http.as_ref().create_emoji(self.0, &map, None).await
     }
 
+    /// Creates an emoji in the guild from an image file or URL, with the data
+    /// set, if any.
+    ///
+    /// The image is read and encoded with its MIME type, detected from its
+    /// contents or filename, so it does not need to be encoded beforehand
+    /// like with [`Self::create_emoji`].
+    ///
+    /// **Note**: Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Examples
+    ///
+    /// Create an emoji from an image on disk, which only a role can use:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{GuildId, RoleId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let emoji = GuildId(7)
+    ///     .create_emoji_with(&http, |e| e.name("ferris").image("./ferris.png").roles(vec![RoleId(8)]))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NoEmojiImageSet`] if no image is set.
+    /// Returns a [`ModelError::NameTooShort`] or [`ModelError::NameTooLong`]
+    /// if the name is not between 2 and 32 characters long, and a
+    /// [`ModelError::InvalidEmojiName`] if it contains characters other than
+    /// alphanumeric characters and underscores.
+    /// Returns a [`ModelError::EmojiImageTooLarge`] if the image is over the
+    /// [`EMOJI_IMAGE_MAX_SIZE`] limit.
+    ///
+    /// May also return [`Error::Io`] if the image is a file that cannot be
+    /// read, or [`Error::Http`] if the image is a URL that cannot be
+    /// downloaded, or if the current user lacks permission.
+    ///
+    /// [`EMOJI_IMAGE_MAX_SIZE`]: crate::constants::EMOJI_IMAGE_MAX_SIZE
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    pub async fn create_emoji_with<'a, F>(self, http: impl AsRef<Http>, f: F) -> Result<Emoji>
+    where
+        for<'b> F: FnOnce(&'b mut CreateEmoji<'a>) -> &'b mut CreateEmoji<'a>,
+    {
+        let mut create_emoji = CreateEmoji::default();
+        f(&mut create_emoji);
+
+        let image = match create_emoji.1 {
+            Some(image) => image,
+            None => return Err(Error::Model(ModelError::NoEmojiImageSet)),
+        };
+
+        let mut map = json::hashmap_to_json_map(create_emoji.0);
+        Self::check_emoji_name(&map)?;
+
+        let http = http.as_ref();
+        let data = image.data(&http.client).await?;
+
+        if data.len() > constants::EMOJI_IMAGE_MAX_SIZE {
+            let overflow = data.len() - constants::EMOJI_IMAGE_MAX_SIZE;
+            return Err(Error::Model(ModelError::EmojiImageTooLarge(overflow)));
+        }
+
+        let filename = image.filename()?;
+        let mime_type = crate::utils::image_mime_type(&data, filename.as_deref());
+        let image = format!("data:{};base64,{}", mime_type, base64::encode(&data));
+        map.insert("image".to_string(), Value::from(image));
+
+        http.create_emoji(self.0, &Value::from(map), None).await
+    }
+
+    pub(crate) fn check_emoji_name(map: &JsonMap) -> Result<()> {
+        let name = map.get("name").and_then(Value::as_str).unwrap_or_default();
+        let length = name.chars().count();
+
+        if length < 2 {
+            return Err(Error::Model(ModelError::NameTooShort));
+        } else if length > constants::EMOJI_NAME_MAX_LENGTH {
+            return Err(Error::Model(ModelError::NameTooLong));
+        } else if !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            return Err(Error::Model(ModelError::InvalidEmojiName));
+        }
+
+        Ok(())
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -516,6 +608,30 @@ impl GuildId {
         Ok(sticker)
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if the name or description are too long.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_template(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let map = json!({
+            "name": name,
+            "description": description,
+        });
+
+        http.as_ref().create_guild_template(self.0, &map).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -628,6 +744,25 @@ impl GuildId {
         http.as_ref().delete_sticker(self.0, sticker_id.into().0, None).await
     }
 
+    /// Deletes a [`GuildTemplate`] by its code, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self.0, code).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -912,6 +1047,9 @@ impl GuildId {
     }
 
     /// Tries to find the [`Guild`] by its Id in the cache.
+    ///
+    /// Refer to [`Self::to_partial_guild_cached_or_fetch`] to request the guild
+    /// if it is not cached.
     #[cfg(feature = "cache")]
     #[inline]
     pub fn to_guild_cached(self, cache: impl AsRef<Cache>) -> Option<Guild> {
@@ -947,8 +1085,42 @@ impl GuildId {
         http.as_ref().get_guild_with_counts(self.0).await
     }
 
+    /// First attempts to find the [`Guild`] by its Id in the cache, converting
+    /// it into a [`PartialGuild`], and upon failure requests it via the REST
+    /// API.
+    ///
+    /// **Note**: A [`PartialGuild`] lacks the members, channels, presences and
+    /// voice states of a cached [`Guild`]. Use [`Self::to_guild_cached`] if
+    /// you need those, or [`Self::members`] and [`Self::channels`] to request
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is not cached and the current
+    /// user is not in the guild.
+    pub async fn to_partial_guild_cached_or_fetch(
+        self,
+        cache_http: impl CacheHttp,
+    ) -> Result<PartialGuild> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(guild) = cache.guild(self) {
+                    let mut guild = PartialGuild::from(guild);
+                    guild.owner = guild.owner_id == cache.current_user_id();
+
+                    return Ok(guild);
+                }
+            }
+        }
+
+        self.to_partial_guild(cache_http.http()).await
+    }
+
     /// Gets all [`Emoji`]s of this guild via HTTP.
     ///
+    /// Refer to [`Http::get_emojis`] for the uploader of each emoji.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the guild is unavailable.
@@ -969,6 +1141,8 @@ impl GuildId {
 
     /// Gets all [`Sticker`]s of this guild via HTTP.
     ///
+    /// Refer to [`Http::get_guild_stickers`] for the uploader of each sticker.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the guild is unavailable.
@@ -987,6 +1161,20 @@ impl GuildId {
         http.as_ref().get_guild_sticker(self.0, sticker_id.0).await
     }
 
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1115,7 +1303,9 @@ impl GuildId {
     ///
     /// This is accomplished and equivalent to repeated calls to [`Self::members`].
     /// A buffer of at most 1,000 members is used to reduce the number of calls
-    /// necessary.
+    /// necessary, and each request waits for the ratelimiter.
+    ///
+    /// The stream ends after yielding an error.
     ///
     /// # Examples
     /// ```rust,no_run
@@ -1247,6 +1437,61 @@ impl GuildId {
         http.as_ref().edit_guild_channel_positions(self.0, &Value::from(items)).await
     }
 
+    /// Edits the positions of several channels of the guild at once,
+    /// optionally moving them to another category.
+    ///
+    /// Refer to [`Self::reorder_channels`] to only change the positions of the
+    /// channels.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// Move a channel to the top of a category, syncing its permissions with
+    /// the category:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::{ChannelId, GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let category_id = ChannelId(2);
+    ///
+    /// GuildId(7)
+    ///     .edit_channel_positions(&http, |p| {
+    ///         p.channel(1, |c| c.position(0).parent_id(Some(category_id)).lock_permissions(true))
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if
+    /// a channel is not in the guild.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    #[inline]
+    pub async fn edit_channel_positions<F>(self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditChannelPositions) -> &mut EditChannelPositions,
+    {
+        let mut positions = EditChannelPositions::default();
+        f(&mut positions);
+
+        self._edit_channel_positions(http.as_ref(), positions).await
+    }
+
+    pub(crate) async fn _edit_channel_positions(
+        self,
+        http: &Http,
+        positions: EditChannelPositions,
+    ) -> Result<()> {
+        http.edit_guild_channel_positions(self.0, &Value::from(positions.0)).await
+    }
+
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
     ///
@@ -1350,6 +1595,49 @@ impl GuildId {
             .await
     }
 
+    /// Streams over all the interested users of the specified event, with their
+    /// [`member`] populated if they are a member of the guild.
+    ///
+    /// This is accomplished and equivalent to repeated calls to
+    /// [`Self::scheduled_event_users_optioned`]. A buffer of at most 100 users
+    /// is used to reduce the number of calls necessary.
+    ///
+    /// The stream ends after yielding an error.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::id::{GuildId, ScheduledEventId};
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let guild_id = GuildId::default();
+    /// # let event_id = ScheduledEventId::default();
+    /// # let ctx = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut users = guild_id.scheduled_event_users_iter(&ctx, event_id).boxed();
+    /// while let Some(user_result) = users.next().await {
+    ///     match user_result {
+    ///         Ok(event_user) => println!("{} is interested", event_user.user.name),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`member`]: ScheduledEventUser::member
+    /// [Manage Events]: Permissions::MANAGE_EVENTS
+    pub fn scheduled_event_users_iter<H: AsRef<Http>>(
+        self,
+        http: H,
+        event_id: impl Into<ScheduledEventId>,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        ScheduledEventUsersIter::<H>::stream(http, self, event_id.into())
+    }
+
     /// Returns the Id of the shard associated with the guild.
     ///
     /// When the cache is enabled this will automatically retrieve the total
@@ -1430,6 +1718,21 @@ impl GuildId {
         http.as_ref().start_guild_prune(self.0, days as u64, None).await
     }
 
+    /// Syncs a [`GuildTemplate`] to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self.0, code).await
+    }
+
     /// Unbans a [`User`] from the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -1460,6 +1763,23 @@ impl GuildId {
         http.as_ref().get_guild_vanity_url(self.0).await
     }
 
+    /// Retrieves the guild's vanity invite code along with its number of
+    /// uses, or [`None`] if the guild has no vanity URL.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user lacks permission.
+    /// Can also return [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn vanity_url_uses(self, http: impl AsRef<Http>) -> Result<Option<GuildVanityUrl>> {
+        http.as_ref().get_guild_vanity_url_uses(self.0).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -1703,22 +2023,29 @@ impl GuildId {
         http.as_ref().get_guild_preview(self.0).await
     }
 
-    /// Get the guild widget.
+    /// Get the settings of the guild widget.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the bot does not have `MANAGE_MESSAGES` permission.
+    /// Returns [`Error::Http`] if the bot does not have the `MANAGE_GUILD` permission.
     pub async fn get_widget(&self, http: impl AsRef<Http>) -> Result<GuildWidget> {
         http.as_ref().get_guild_widget(self.0).await
     }
 
-    /// Get the widget image URL.
+    /// Get the URL of the widget image, as displayed in the given style.
+    ///
+    /// **Note**: The image is only available if the widget is enabled.
     #[must_use]
     pub fn widget_image_url(&self, style: GuildWidgetStyle) -> String {
         api!("/guilds/{}/widget.png?style={}", self.0, style)
     }
 
-    /// Gets the guild active threads.
+    /// Gets all active threads of the guild in a single request, including
+    /// private threads. The endpoint is not paginated, so
+    /// [`ThreadsData::has_more`] is always `false`.
+    ///
+    /// [`ThreadsData::members`] contains a thread member for each returned
+    /// thread the current user has joined.
     ///
     /// # Errors
     ///
@@ -1757,6 +2084,20 @@ impl<'a> From<&'a GuildInfo> for GuildId {
     }
 }
 
+impl From<GuildInfoWithCounts> for GuildId {
+    /// Gets the Id of Guild information struct.
+    fn from(guild_info: GuildInfoWithCounts) -> GuildId {
+        guild_info.info.id
+    }
+}
+
+impl From<&GuildInfoWithCounts> for GuildId {
+    /// Gets the Id of Guild information struct.
+    fn from(guild_info: &GuildInfoWithCounts) -> GuildId {
+        guild_info.info.id
+    }
+}
+
 impl From<InviteGuild> for GuildId {
     /// Gets the Id of Invite Guild struct.
     fn from(invite_guild: InviteGuild) -> GuildId {
@@ -1839,6 +2180,8 @@ impl<H: AsRef<Http>> MembersIter<H> {
     /// A buffer of at most 1,000 members is used to reduce the number of calls
     /// necessary.
     ///
+    /// The stream ends after yielding an error.
+    ///
     /// # Examples
     ///
     /// ```rust,no_run
@@ -1866,6 +2209,96 @@ impl<H: AsRef<Http>> MembersIter<H> {
         futures::stream::unfold(init_state, |mut state| async {
             if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
                 if let Err(error) = state.refresh().await {
+                    // End the stream after yielding the error, instead of
+                    // requesting the same page again.
+                    state.tried_fetch = true;
+                    state.after = None;
+
+                    return Some((Err(error), state));
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+}
+
+/// A helper class returned by [`GuildId::scheduled_event_users_iter`]
+#[derive(Clone, Debug)]
+#[cfg(feature = "model")]
+pub struct ScheduledEventUsersIter<H: AsRef<Http>> {
+    guild_id: GuildId,
+    event_id: ScheduledEventId,
+    http: H,
+    buffer: Vec<ScheduledEventUser>,
+    after: Option<UserId>,
+    tried_fetch: bool,
+}
+
+#[cfg(feature = "model")]
+impl<H: AsRef<Http>> ScheduledEventUsersIter<H> {
+    fn new(guild_id: GuildId, event_id: ScheduledEventId, http: H) -> ScheduledEventUsersIter<H> {
+        ScheduledEventUsersIter {
+            guild_id,
+            event_id,
+            http,
+            buffer: Vec::new(),
+            after: None,
+            tried_fetch: false,
+        }
+    }
+
+    /// Fills the `self.buffer` cache of scheduled event users.
+    ///
+    /// This drops any users that were currently in the buffer, so it should
+    /// only be called when `self.buffer` is empty. Additionally, this updates
+    /// `self.after` so that the next call does not return duplicate items. If
+    /// there are no more users to be fetched, then this marks `self.after` as
+    /// None, indicating that no more calls ought to be made.
+    async fn refresh(&mut self) -> Result<()> {
+        let grab_size = constants::SCHEDULED_EVENT_USER_FETCH_LIMIT;
+
+        self.buffer = self
+            .guild_id
+            .scheduled_event_users_optioned(
+                &self.http,
+                self.event_id,
+                Some(grab_size),
+                self.after.map(UserPagination::After),
+                Some(true),
+            )
+            .await?;
+
+        // Get the last user. If shorter than the limit, there are no more
+        // results anyway.
+        self.after = self.buffer.get(grab_size as usize - 1).map(|event_user| event_user.user.id);
+
+        // Reverse to optimize pop()
+        self.buffer.reverse();
+
+        self.tried_fetch = true;
+
+        Ok(())
+    }
+
+    /// Streams over all the interested users of a scheduled event.
+    ///
+    /// Refer to [`GuildId::scheduled_event_users_iter`] for more information.
+    pub fn stream(
+        http: impl AsRef<Http>,
+        guild_id: GuildId,
+        event_id: ScheduledEventId,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        let init_state = ScheduledEventUsersIter::new(guild_id, event_id, http);
+
+        futures::stream::unfold(init_state, |mut state| async {
+            if state.buffer.is_empty() && state.after.is_some() || !state.tried_fetch {
+                if let Err(error) = state.refresh().await {
+                    // End the stream after yielding the error, instead of
+                    // requesting the same page again.
+                    state.tried_fetch = true;
+                    state.after = None;
+
                     return Some((Err(error), state));
                 }
             }
@@ -1896,3 +2329,25 @@ impl fmt::Display for GuildWidgetStyle {
         }
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "model")]
+mod test {
+    use super::GuildId;
+    use crate::json::{json, Value};
+    use crate::model::ModelError;
+    use crate::Error;
+
+    #[test]
+    fn test_check_emoji_name() {
+        let check = |name: Value| {
+            let map = json!({ "name": name });
+            GuildId::check_emoji_name(map.as_object().unwrap())
+        };
+
+        assert!(check(json!("ferris_2")).is_ok());
+        assert!(matches!(check(json!("a")), Err(Error::Model(ModelError::NameTooShort))));
+        assert!(matches!(check(json!("a".repeat(33))), Err(Error::Model(ModelError::NameTooLong))));
+        assert!(matches!(check(json!("a-b")), Err(Error::Model(ModelError::InvalidEmojiName))));
+    }
+}
@@ -9,24 +9,80 @@ use crate::model::Timestamp;
 pub struct Integration {
     pub id: IntegrationId,
     pub guild_id: GuildId,
+    /// The account of the integration, e.g. the Twitch channel or the bot
+    /// user.
     pub account: IntegrationAccount,
     pub enabled: bool,
-    #[serde(rename = "expire_behaviour")]
+    /// What happens to subscribers once their subscription expires.
+    ///
+    /// **Note**: Only present for streaming integrations.
+    #[serde(rename = "expire_behavior")]
     pub expire_behaviour: Option<IntegrationExpireBehaviour>,
+    /// The grace period in days before expiring subscribers.
+    ///
+    /// **Note**: Only present for streaming integrations.
     pub expire_grace_period: Option<u64>,
+    /// The type of integration, one of `twitch`, `youtube`, `discord` or
+    /// `guild_subscription`.
     #[serde(rename = "type")]
     pub kind: String,
     pub name: String,
+    /// The Id of the role used for subscribers.
+    ///
+    /// **Note**: Only present for streaming integrations.
     pub role_id: Option<RoleId>,
+    /// **Note**: Only present for streaming integrations.
     pub synced_at: Option<Timestamp>,
+    /// **Note**: Only present for streaming integrations.
     pub syncing: Option<bool>,
+    /// The user that added the integration.
     pub user: Option<User>,
+    /// Whether emoticons should be synced for this integration.
+    ///
+    /// **Note**: Only present for Twitch integrations.
     pub enable_emoticons: Option<bool>,
+    /// **Note**: Only present for streaming integrations.
     pub subscriber_count: Option<u64>,
     pub revoked: Option<bool>,
+    /// The bot or OAuth2 application of the integration.
+    ///
+    /// **Note**: Only present for Discord (bot) integrations.
     pub application: Option<IntegrationApplication>,
 }
 
+#[cfg(feature = "model")]
+impl Integration {
+    /// Whether this is a streaming integration, i.e. a Twitch or YouTube
+    /// connection, which syncs subscribers to a role.
+    #[must_use]
+    pub fn is_streaming(&self) -> bool {
+        matches!(self.kind.as_str(), "twitch" | "youtube")
+    }
+
+    /// Whether this is the integration of a bot or OAuth2 application.
+    #[must_use]
+    pub fn is_bot(&self) -> bool {
+        self.kind == "discord"
+    }
+
+    /// Deletes the integration from its guild.
+    ///
+    /// Refer to [`GuildId::delete_integration`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if the integration does not exist anymore.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<()> {
+        self.guild_id.delete_integration(http, self.id).await
+    }
+}
+
 /// The behavior once the integration expires.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#integration-object-integration-expire-behaviors).
@@ -59,6 +59,9 @@ pub struct Member {
     ///
     /// Will be None or a time in the past if the user is not timed out.
     pub communication_disabled_until: Option<Timestamp>,
+    /// The member's flags, e.g. whether they have completed onboarding.
+    #[serde(default)]
+    pub flags: GuildMemberFlags,
 }
 
 /// Helper for deserialization without a `GuildId` but then later updated to the correct `GuildId`.
@@ -80,6 +83,8 @@ pub(crate) struct InterimMember {
     pub permissions: Option<Permissions>,
     pub avatar: Option<String>,
     pub communication_disabled_until: Option<Timestamp>,
+    #[serde(default)]
+    pub flags: GuildMemberFlags,
 }
 
 impl From<InterimMember> for Member {
@@ -97,6 +102,7 @@ impl From<InterimMember> for Member {
             permissions: m.permissions,
             avatar: m.avatar,
             communication_disabled_until: m.communication_disabled_until,
+            flags: m.flags,
         }
     }
 }
@@ -513,6 +519,61 @@ impl Member {
         }
     }
 
+    /// Checks whether the member has all of the given permissions in a
+    /// channel of its guild, taking the channel's permission overwrites into
+    /// account. Members with the [Administrator] permission have all
+    /// permissions.
+    ///
+    /// For a thread, the permissions of its parent channel are checked.
+    ///
+    /// **Note**: This relies on the guild, its roles and the channel being
+    /// cached. If any of them is not, `false` is returned. Use
+    /// [`Guild::user_permissions_in`] to compute the full set of permissions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,ignore
+    /// // assuming there's a `member`, a `channel_id` and a `cache`.
+    /// if member.has_permission(&cache, channel_id, Permissions::SEND_MESSAGES) {
+    ///     println!("{} can send messages there", member.user.name);
+    /// }
+    /// ```
+    ///
+    /// [Administrator]: Permissions::ADMINISTRATOR
+    /// [`Guild::user_permissions_in`]: crate::model::guild::Guild::user_permissions_in
+    #[cfg(feature = "cache")]
+    pub fn has_permission(
+        &self,
+        cache: impl AsRef<Cache>,
+        channel_id: impl Into<ChannelId>,
+        permissions: Permissions,
+    ) -> bool {
+        let channel_id = channel_id.into();
+
+        cache
+            .as_ref()
+            .guild_field(self.guild_id, |guild| {
+                if guild._member_permission_from_member(self).administrator() {
+                    return true;
+                }
+
+                let channel_id = guild
+                    .threads
+                    .iter()
+                    .find(|thread| thread.id == channel_id)
+                    .and_then(|thread| thread.parent_id)
+                    .unwrap_or(channel_id);
+
+                match guild.channels.get(&channel_id) {
+                    Some(Channel::Guild(channel)) => guild
+                        .user_permissions_in(channel, self)
+                        .map_or(false, |p| p.contains(permissions)),
+                    _ => false,
+                }
+            })
+            .unwrap_or(false)
+    }
+
     /// Removes a [`Role`] from the member, editing its roles in-place if the
     /// request was successful.
     ///
@@ -699,6 +760,39 @@ fn avatar_url(guild_id: GuildId, user_id: UserId, hash: Option<&String>) -> Opti
     })
 }
 
+bitflags! {
+    /// Describes extra information about a member of a guild.
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-member-object-guild-member-flags).
+    #[derive(Default)]
+    pub struct GuildMemberFlags: u64 {
+        /// The member has left and rejoined the guild.
+        const DID_REJOIN = 1 << 0;
+        /// The member has completed onboarding.
+        const COMPLETED_ONBOARDING = 1 << 1;
+        /// The member is exempt from guild verification requirements.
+        ///
+        /// This is the only flag that can be edited, via [`EditMember::flags`].
+        ///
+        /// [`EditMember::flags`]: crate::builder::EditMember::flags
+        const BYPASSES_VERIFICATION = 1 << 2;
+        /// The member has started onboarding.
+        const STARTED_ONBOARDING = 1 << 3;
+        /// The member is a guest and can only access the voice channel they
+        /// were invited to.
+        const IS_GUEST = 1 << 4;
+        /// The member has started the Server Guide new member actions.
+        const STARTED_HOME_ACTIONS = 1 << 5;
+        /// The member has completed the Server Guide new member actions.
+        const COMPLETED_HOME_ACTIONS = 1 << 6;
+        /// The member's username, display name or nickname is blocked by
+        /// AutoMod.
+        const AUTOMOD_QUARANTINED_USERNAME = 1 << 7;
+        /// The member has dismissed the DM settings upsell.
+        const DM_SETTINGS_UPSELL_ACKNOWLEDGED = 1 << 9;
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#thread-member-object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
@@ -711,6 +805,13 @@ pub struct ThreadMember {
     pub join_timestamp: Timestamp,
     /// Any user-thread settings, currently only used for notifications
     pub flags: ThreadMemberFlags,
+    /// The guild member data of the user.
+    ///
+    /// **Note**: This is only available when requested, e.g. via
+    /// [`ChannelId::thread_members_iter`].
+    ///
+    /// [`ChannelId::thread_members_iter`]: crate::model::id::ChannelId::thread_members_iter
+    pub member: Option<PartialMember>,
 }
 
 bitflags! {
@@ -723,3 +824,176 @@ bitflags! {
         const NOTIFICATIONS = 1 << 0;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{GuildMemberFlags, Member, ThreadMember};
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn test_member_flags() {
+        let mut value = json!({
+            "deaf": false,
+            "guild_id": "1",
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "nick": null,
+            "roles": [],
+            "user": {
+                "id": "2",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+            "premium_since": null,
+            "permissions": null,
+            "avatar": null,
+            "communication_disabled_until": null,
+        });
+
+        let member: Member = from_value(value.clone()).unwrap();
+        assert!(member.flags.is_empty());
+
+        value["flags"] = json!(6);
+
+        let member: Member = from_value(value).unwrap();
+        assert_eq!(
+            member.flags,
+            GuildMemberFlags::COMPLETED_ONBOARDING | GuildMemberFlags::BYPASSES_VERIFICATION
+        );
+    }
+
+    #[test]
+    fn test_thread_member_with_member() {
+        let mut value = json!({
+            "id": "1",
+            "user_id": "2",
+            "join_timestamp": "2023-01-01T00:00:00.000000+00:00",
+            "flags": 0,
+        });
+
+        let thread_member: ThreadMember = from_value(value.clone()).unwrap();
+        assert!(thread_member.member.is_none());
+
+        value["member"] = json!({
+            "deaf": false,
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "nick": "nick",
+            "roles": [],
+            "user": {
+                "id": "2",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+        });
+
+        let thread_member: ThreadMember = from_value(value).unwrap();
+        let member = thread_member.member.unwrap();
+        assert_eq!(member.nick.as_deref(), Some("nick"));
+        assert_eq!(member.user.map(|u| u.id.0), Some(2));
+    }
+
+    #[cfg(feature = "cache")]
+    #[test]
+    fn test_has_permission() {
+        use crate::cache::Cache;
+        use crate::model::event::GuildCreateEvent;
+        use crate::model::id::{ChannelId, RoleId};
+        use crate::model::Permissions;
+
+        let cache = Cache::new();
+        let mut guild_create: GuildCreateEvent = from_value(json!({
+            "id": "1",
+            "afk_timeout": 0,
+            "channels": [{
+                "id": "2",
+                "guild_id": "1",
+                "type": 0,
+                "name": "general",
+                "position": 0,
+                "permission_overwrites": [{
+                    "id": "1",
+                    "type": 0,
+                    "allow": "0",
+                    "deny": Permissions::SEND_MESSAGES.bits().to_string(),
+                }],
+            }],
+            "default_message_notifications": 0,
+            "emojis": [],
+            "explicit_content_filter": 0,
+            "features": [],
+            "icon": null,
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "large": false,
+            "member_count": 0,
+            "members": [],
+            "mfa_level": 0,
+            "name": "guild",
+            "owner_id": "9",
+            "presences": [],
+            "roles": [{
+                "id": "1",
+                "name": "@everyone",
+                "color": 0,
+                "hoist": false,
+                "position": 0,
+                "permissions": (Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES)
+                    .bits()
+                    .to_string(),
+                "managed": false,
+                "mentionable": false,
+            }, {
+                "id": "5",
+                "name": "admin",
+                "color": 0,
+                "hoist": false,
+                "position": 1,
+                "permissions": Permissions::ADMINISTRATOR.bits().to_string(),
+                "managed": false,
+                "mentionable": false,
+            }],
+            "splash": null,
+            "verification_level": 0,
+            "voice_states": [],
+            "preferred_locale": "en-US",
+            "nsfw_level": 0,
+            "system_channel_flags": 0,
+            "stickers": [],
+            "threads": [{
+                "id": "3",
+                "guild_id": "1",
+                "parent_id": "2",
+                "type": 11,
+                "name": "thread",
+            }],
+        }))
+        .unwrap();
+        cache.update(&mut guild_create);
+
+        let mut member: Member = from_value(json!({
+            "deaf": false,
+            "guild_id": "1",
+            "joined_at": "2023-01-01T00:00:00.000000+00:00",
+            "mute": false,
+            "roles": [],
+            "user": {
+                "id": "2",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            },
+        }))
+        .unwrap();
+
+        assert!(member.has_permission(&cache, ChannelId(2), Permissions::VIEW_CHANNEL));
+        assert!(!member.has_permission(&cache, ChannelId(2), Permissions::SEND_MESSAGES));
+        assert!(!member.has_permission(&cache, ChannelId(3), Permissions::SEND_MESSAGES));
+        assert!(!member.has_permission(&cache, ChannelId(4), Permissions::VIEW_CHANNEL));
+
+        member.roles.push(RoleId(5));
+        assert!(member.has_permission(&cache, ChannelId(2), Permissions::SEND_MESSAGES));
+        assert!(member.has_permission(&cache, ChannelId(4), Permissions::MANAGE_GUILD));
+    }
+}
@@ -12,10 +12,11 @@ mod premium_tier;
 mod role;
 mod scheduled_event;
 mod system_channel;
+mod template;
 mod welcome_screen;
 
 #[cfg(feature = "model")]
-use futures::stream::StreamExt;
+use futures::stream::{Stream, StreamExt};
 use serde::de::Error as DeError;
 #[cfg(feature = "model")]
 use tracing::error;
@@ -35,6 +36,7 @@ pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
 pub use self::system_channel::*;
+pub use self::template::*;
 pub use self::welcome_screen::*;
 use super::utils::*;
 #[cfg(feature = "model")]
@@ -44,9 +46,12 @@ use crate::builder::{
     CreateApplicationCommandPermissionsData,
     CreateApplicationCommands,
     CreateChannel,
+    CreateEmoji,
+    CreateGuild,
     CreateScheduledEvent,
     CreateSticker,
     EditAutoModRule,
+    EditChannelPositions,
     EditGuild,
     EditGuildWelcomeScreen,
     EditGuildWidget,
@@ -215,6 +220,9 @@ pub struct Guild {
     /// Indicator of the current verification level of the guild.
     pub verification_level: VerificationLevel,
     /// A mapping of [`User`]s to their current voice state.
+    ///
+    /// Only users connected to a voice channel are present. When using the
+    /// cache, users are removed once they disconnect.
     #[serde(serialize_with = "serialize_map_values")]
     pub voice_states: HashMap<UserId, VoiceState>,
     /// The server's description, if it has one.
@@ -547,9 +555,15 @@ impl Guild {
     }
 
     /// Returns the formatted URL of the guild's banner image, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the guild has a GIF banner.
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
-        self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp?size=1024", self.id, banner))
+        self.banner.as_ref().map(|banner| {
+            let ext = if banner.starts_with("a_") { "gif" } else { "webp" };
+
+            cdn!("/banners/{}/{}.{}?size=1024", self.id, banner, ext)
+        })
     }
 
     /// Retrieves a list of [`Ban`]s for the guild.
@@ -630,6 +644,58 @@ impl Guild {
         self.id.channels(&http).await
     }
 
+    /// Returns the guild's channels in the order the Discord client displays
+    /// them.
+    ///
+    /// Channels without a category come first, followed by each category and
+    /// the channels within it. Categories are ordered by position then Id.
+    /// Within a category, text-based channels are listed before voice and
+    /// stage channels, each ordered by position then Id.
+    ///
+    /// **Note**: Threads are not included, as they are not stored in
+    /// [`Self::channels`].
+    #[must_use]
+    pub fn channels_sorted(&self) -> Vec<&Channel> {
+        let mut categories = self
+            .channels
+            .values()
+            .filter_map(|channel| match channel {
+                Channel::Category(category) => Some(category),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        categories.sort_by_key(|category| (category.position, category.id));
+
+        let ranks = categories
+            .iter()
+            .enumerate()
+            .map(|(rank, category)| (category.id, rank))
+            .collect::<HashMap<_, _>>();
+
+        let mut channels = self
+            .channels
+            .values()
+            .filter(|channel| !matches!(channel, Channel::Private(_)))
+            .collect::<Vec<_>>();
+
+        // Sorts by the category's rank, then puts the category itself before
+        // its children, and voice channels after text-based ones.
+        channels.sort_by_key(|channel| match channel {
+            Channel::Category(category) => {
+                (ranks.get(&category.id).copied(), false, false, category.position, category.id)
+            },
+            Channel::Guild(channel) => {
+                let rank = channel.parent_id.and_then(|id| ranks.get(&id).copied());
+                let is_voice = matches!(channel.kind, ChannelType::Voice | ChannelType::Stage);
+
+                (rank, true, is_voice, channel.position, channel.id)
+            },
+            Channel::Private(channel) => (None, false, false, 0, channel.id),
+        });
+
+        channels
+    }
+
     /// Creates a guild with the data provided.
     ///
     /// Only a [`PartialGuild`] will be immediately returned, and a full
@@ -669,6 +735,93 @@ impl Guild {
         http.as_ref().create_guild(&map).await
     }
 
+    /// Creates a guild with the data set by the [`CreateGuild`] builder,
+    /// including its initial roles and channels.
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full
+    /// [`Guild`] will be received over a [`Shard`].
+    ///
+    /// **Note**: This endpoint can be used only by bots in less than 10
+    /// guilds.
+    ///
+    /// # Examples
+    ///
+    /// Create a guild with a moderator role and a text channel only visible to
+    /// moderators:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::new("token");
+    /// use serenity::model::guild::Guild;
+    /// use serenity::model::prelude::*;
+    ///
+    /// let _guild = Guild::create_with(&http, |g| {
+    ///     g.name("test")
+    ///         .everyone_role(|r| r.permissions(Permissions::empty()))
+    ///         .add_role(1, |r| r.name("moderator"))
+    ///         .add_channel(2, |c| {
+    ///             c.name("moderation").permissions(vec![PermissionOverwrite {
+    ///                 allow: Permissions::VIEW_CHANNEL,
+    ///                 deny: Permissions::empty(),
+    ///                 kind: PermissionOverwriteType::Role(RoleId(1)),
+    ///             }])
+    ///         })
+    /// })
+    /// .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NameTooShort`] or [`ModelError::NameTooLong`]
+    /// if the name is not between 2 and 100 characters long, or a
+    /// [`ModelError::EveryoneRoleNotFirst`] if roles were added without
+    /// setting the [@everyone role] first.
+    ///
+    /// Otherwise returns [`Error::Http`] if the current user cannot create a
+    /// guild.
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    /// [@everyone role]: CreateGuild::everyone_role
+    pub async fn create_with<F>(http: impl AsRef<Http>, f: F) -> Result<PartialGuild>
+    where
+        F: FnOnce(&mut CreateGuild) -> &mut CreateGuild,
+    {
+        let mut guild = CreateGuild::default();
+        f(&mut guild);
+        let map = crate::json::hashmap_to_json_map(guild.0);
+
+        Self::check_create_guild(&map)?;
+
+        http.as_ref().create_guild(&Value::from(map)).await
+    }
+
+    fn check_create_guild(map: &JsonMap) -> Result<()> {
+        let length = match map.get("name") {
+            Some(Value::String(name)) => name.chars().count(),
+            _ => 0,
+        };
+
+        if length < 2 {
+            return Err(Error::Model(ModelError::NameTooShort));
+        } else if length > 100 {
+            return Err(Error::Model(ModelError::NameTooLong));
+        }
+
+        if let Some(Value::Array(roles)) = map.get("roles") {
+            if let Some(role) = roles.first() {
+                if role.get("id").and_then(Value::as_u64) != Some(0) {
+                    return Err(Error::Model(ModelError::EveryoneRoleNotFirst));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Creates a new [`Channel`] in the guild.
     ///
     /// **Note**: Requires the [Manage Channels] permission.
@@ -745,6 +898,40 @@ impl Guild {
         self.id.create_emoji(&http, name, image).await
     }
 
+    /// Creates an emoji in the guild from an image file or URL, with the data
+    /// set, if any.
+    ///
+    /// Refer to the documentation for [`GuildId::create_emoji_with`] for more
+    /// information.
+    ///
+    /// **Note**: Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage emojis.
+    ///
+    /// Otherwise returns the same errors as [`GuildId::create_emoji_with`].
+    ///
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    pub async fn create_emoji_with<'a, F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Emoji>
+    where
+        for<'b> F: FnOnce(&'b mut CreateEmoji<'a>) -> &'b mut CreateEmoji<'a>,
+    {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_EMOJIS_AND_STICKERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.create_emoji_with(cache_http.http(), f).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1561,6 +1748,27 @@ impl Guild {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Gets all [`Sticker`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn stickers(&self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        self.id.stickers(http).await
+    }
+
+    /// Gets a [`Sticker`] of this guild by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a [`Sticker`] with the given Id does not
+    /// exist for the guild.
+    #[inline]
+    pub async fn sticker(&self, http: impl AsRef<Http>, sticker_id: StickerId) -> Result<Sticker> {
+        self.id.sticker(http, sticker_id).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1579,6 +1787,76 @@ impl Guild {
         self.id.integrations(&http).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if the name or description are too long.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(&http, name, description).await
+    }
+
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(&http).await
+    }
+
+    /// Syncs a [`GuildTemplate`] to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(&self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        self.id.sync_template(&http, code).await
+    }
+
+    /// Deletes a [`GuildTemplate`] by its code, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.id.delete_template(&http, code).await
+    }
+
     /// Retrieves the active invites for the guild.
     ///
     /// **Note**: Requires the [Manage Guild] permission.
@@ -1693,6 +1971,33 @@ impl Guild {
         self.id.members(&http, limit, after).await
     }
 
+    /// Streams over all the members in the guild.
+    ///
+    /// Refer to [`GuildId::members_iter`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::Guild;
+    /// #
+    /// # async fn run(http: Http, guild: Guild) {
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut members = guild.members_iter(&http).boxed();
+    /// while let Some(member_result) = members.next().await {
+    ///     match member_result {
+    ///         Ok(member) => println!("{} is {}", member, member.display_name()),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn members_iter<H: AsRef<Http>>(&self, http: H) -> impl Stream<Item = Result<Member>> {
+        self.id.members_iter(http)
+    }
+
     /// Gets a list of all the members (satisfying the status provided to the function) in this
     /// guild.
     #[must_use]
@@ -1710,6 +2015,46 @@ impl Guild {
         members
     }
 
+    /// Gets the members currently connected to the given voice or stage
+    /// channel, based on the guild's [voice states].
+    ///
+    /// **Note**: Voice states are only received with the
+    /// [`GUILD_VOICE_STATES`] intent. A member is only returned if they, or
+    /// their voice state's member data, are cached.
+    ///
+    /// [voice states]: Self::voice_states
+    /// [`GUILD_VOICE_STATES`]: crate::model::gateway::GatewayIntents::GUILD_VOICE_STATES
+    #[must_use]
+    pub fn voice_channel_members(&self, channel_id: impl Into<ChannelId>) -> Vec<&Member> {
+        let channel_id = Some(channel_id.into());
+
+        self.voice_states
+            .values()
+            .filter(|voice_state| voice_state.channel_id == channel_id)
+            .filter_map(|voice_state| {
+                self.members.get(&voice_state.user_id).or(voice_state.member.as_ref())
+            })
+            .collect()
+    }
+
+    /// Gets the presence of a member, holding their [status] and
+    /// [activities].
+    ///
+    /// Returns [`None`] if the member is offline, or if presences are not
+    /// received or cached.
+    ///
+    /// **Note**: Presences are only received with the [`GUILD_PRESENCES`]
+    /// intent, and only cached if [`Settings::cache_presences`] is enabled.
+    ///
+    /// [status]: Presence::status
+    /// [activities]: Presence::activities
+    /// [`GUILD_PRESENCES`]: crate::model::gateway::GatewayIntents::GUILD_PRESENCES
+    /// [`Settings::cache_presences`]: crate::cache::Settings::cache_presences
+    #[must_use]
+    pub fn presence(&self, user_id: impl Into<UserId>) -> Option<&Presence> {
+        self.presences.get(&user_id.into())
+    }
+
     /// Retrieves the first [`Member`] found that matches the name - with an
     /// optional discriminator - provided.
     ///
@@ -2036,7 +2381,7 @@ impl Guild {
             return Permissions::all();
         }
 
-        let everyone = if let Some(everyone) = self.roles.get(&RoleId(self.id.0)) {
+        let everyone = if let Some(everyone) = self.everyone_role() {
             everyone
         } else {
             error!("@everyone role ({}) missing in '{}'", self.id, self.name);
@@ -2306,17 +2651,57 @@ impl Guild {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::WrongGuild`] if a channel is not in the guild.
+    ///
     /// Returns an [`Error::Http`] if the current user is lacking permission.
     ///
     /// [Manage Channels]: Permissions::MANAGE_CHANNELS
-    #[inline]
     pub async fn reorder_channels<It>(&self, http: impl AsRef<Http>, channels: It) -> Result<()>
     where
         It: IntoIterator<Item = (ChannelId, u64)>,
     {
+        let channels = channels.into_iter().collect::<Vec<_>>();
+        self.check_channels(channels.iter().map(|(id, _)| *id))?;
+
         self.id.reorder_channels(&http, channels).await
     }
 
+    /// Edits the positions of several channels of the guild at once,
+    /// optionally moving them to another category.
+    ///
+    /// Refer to [`GuildId::edit_channel_positions`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::WrongGuild`] if a channel, or a category the
+    /// channels are moved to, is not in the guild.
+    ///
+    /// Returns an [`Error::Http`] if the current user is lacking permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn edit_channel_positions<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditChannelPositions) -> &mut EditChannelPositions,
+    {
+        let mut positions = EditChannelPositions::default();
+        f(&mut positions);
+
+        self.check_channels(positions.channel_ids())?;
+
+        self.id._edit_channel_positions(http.as_ref(), positions).await
+    }
+
+    /// Checks that all channels are in the guild.
+    fn check_channels(&self, mut channel_ids: impl Iterator<Item = ChannelId>) -> Result<()> {
+        if channel_ids.all(|id| self.channels.contains_key(&id)) {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::WrongGuild))
+        }
+    }
+
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
     ///
@@ -2569,6 +2954,23 @@ impl Guild {
         self.id.vanity_url(&http).await
     }
 
+    /// Retrieves the guild's vanity invite code along with its number of
+    /// uses, or [`None`] if the guild has no vanity URL.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user is lacking permissions.
+    /// Can also return an [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    #[inline]
+    pub async fn vanity_url_uses(&self, http: impl AsRef<Http>) -> Result<Option<GuildVanityUrl>> {
+        self.id.vanity_url_uses(&http).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -2585,6 +2987,30 @@ impl Guild {
         self.id.webhooks(&http).await
     }
 
+    /// Retrieves the settings of the guild's [`GuildWidget`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is lacking permissions.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn widget(&self, http: impl AsRef<Http>) -> Result<GuildWidget> {
+        self.id.get_widget(http).await
+    }
+
+    /// Returns the URL of the guild's widget image, as displayed in the given
+    /// style.
+    ///
+    /// **Note**: The image is only available if the widget is enabled.
+    #[inline]
+    #[must_use]
+    pub fn widget_image_url(&self, style: GuildWidgetStyle) -> String {
+        self.id.widget_image_url(style)
+    }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -2627,6 +3053,63 @@ impl Guild {
         self.roles.values().find(|role| role_name == role.name)
     }
 
+    /// Gets the `@everyone` role of the guild, whose ID is the same as the
+    /// guild's ID.
+    ///
+    /// Returns [`None`] if the role is not in [`Self::roles`].
+    #[inline]
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        self.roles.get(&RoleId(self.id.0))
+    }
+
+    /// Gets the permissions every member of the guild has, which are the
+    /// permissions of the [`@everyone` role].
+    ///
+    /// Returns empty permissions if the role is not in [`Self::roles`].
+    ///
+    /// [`@everyone` role]: Self::everyone_role
+    #[inline]
+    #[must_use]
+    pub fn default_permissions(&self) -> Permissions {
+        self.everyone_role().map_or_else(Permissions::empty, |role| role.permissions)
+    }
+
+    /// Gets the emoji of the guild with the given name, if there is one.
+    ///
+    /// Only this guild's emojis are searched, so an emoji of the same name
+    /// in another guild is never returned. If several of the guild's emojis
+    /// share the name, any of them may be returned.
+    ///
+    /// The emoji can be used in message content via its [`Display`] impl,
+    /// which formats it as `<:name:id>`, or `<a:name:id>` if it is animated.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn emoji_by_name(&self, name: &str) -> Option<&Emoji> {
+        self.emojis.values().find(|emoji| emoji.name == name)
+    }
+
+    /// Replaces each `:name:` shortcode in the content with the guild's emoji
+    /// of that name, as found by [`Self::emoji_by_name`].
+    ///
+    /// Shortcodes not matching any of the guild's emojis are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::guild::Guild;
+    /// # fn run(guild: Guild) {
+    /// // With an animated emoji named "party" in the guild, prints e.g.
+    /// // "Welcome! <a:party:81384788765712384>"
+    /// println!("{}", guild.format_emojis("Welcome! :party:"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn format_emojis(&self, content: &str) -> String {
+        replace_shortcodes(content, |name| self.emoji_by_name(name))
+    }
+
     /// Returns a future that will await one message sent in this guild.
     #[cfg(feature = "collector")]
     pub fn await_reply(&self, shard_messenger: impl AsRef<ShardMessenger>) -> CollectReply {
@@ -2657,7 +3140,12 @@ impl Guild {
         ReactionCollectorBuilder::new(shard_messenger).guild_id(self.id.0)
     }
 
-    /// Gets the guild active threads.
+    /// Gets all active threads of the guild in a single request, including
+    /// private threads. The endpoint is not paginated, so
+    /// [`ThreadsData::has_more`] is always `false`.
+    ///
+    /// [`ThreadsData::members`] contains a thread member for each returned
+    /// thread the current user has joined.
     ///
     /// # Errors
     ///
@@ -2698,6 +3186,14 @@ impl<'de> Deserialize<'de> for Guild {
                     }
                 }
             }
+
+            if let Some(array) = map.get_mut("voice_states").and_then(Value::as_array_mut) {
+                for value in array {
+                    if let Some(voice_state) = value.as_object_mut() {
+                        voice_state.insert("guild_id".to_string(), from_number(guild_id));
+                    }
+                }
+            }
         }
 
         let afk_channel_id = match map.remove("afk_channel_id") {
@@ -3045,6 +3541,18 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// The vanity invite of a [`Guild`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildVanityUrl {
+    /// The vanity invite code.
+    pub code: String,
+    /// The number of times the vanity invite was used.
+    pub uses: u64,
+}
+
 /// Basic information about a guild.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object), subset undocumented (closest thing is
@@ -3067,6 +3575,23 @@ pub struct GuildInfo {
     pub permissions: Permissions,
 }
 
+/// Basic information about a guild, along with its approximate member counts.
+///
+/// Refer to [`Http::get_current_user_guilds_with_counts`] to get it.
+///
+/// [`Http::get_current_user_guilds_with_counts`]: crate::http::Http::get_current_user_guilds_with_counts
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildInfoWithCounts {
+    /// The basic information about the guild.
+    #[serde(flatten)]
+    pub info: GuildInfo,
+    /// Approximate number of members in this guild.
+    pub approximate_member_count: u64,
+    /// Approximate number of non-offline members in this guild.
+    pub approximate_presence_count: u64,
+}
+
 #[cfg(any(feature = "model", feature = "utils"))]
 impl GuildInfo {
     /// Returns the formatted URL of the guild's icon, if the guild has an icon.
@@ -3241,6 +3766,7 @@ mod test {
     mod model {
         use std::collections::*;
 
+        use crate::json::{from_value, json};
         use crate::model::prelude::*;
 
         fn gen_user() -> User {
@@ -3266,6 +3792,7 @@ mod test {
                 permissions: None,
                 avatar: None,
                 communication_disabled_until: None,
+                flags: GuildMemberFlags::default(),
             }
         }
 
@@ -3338,6 +3865,63 @@ mod test {
             }
         }
 
+        #[test]
+        fn everyone_role() {
+            use crate::json::{from_value, json};
+
+            let mut guild = gen();
+            assert!(guild.everyone_role().is_none());
+            assert_eq!(guild.default_permissions(), Permissions::empty());
+
+            let everyone: Role = from_value(json!({
+                "id": "1",
+                "guild_id": "1",
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "name": "@everyone",
+                "permissions": "1024",
+                "position": 0,
+            }))
+            .unwrap();
+            guild.roles.insert(everyone.id, everyone);
+
+            assert_eq!(guild.everyone_role().map(|role| role.id), Some(RoleId(1)));
+            assert_eq!(guild.default_permissions(), Permissions::VIEW_CHANNEL);
+        }
+
+        #[test]
+        fn voice_channel_members() {
+            use crate::json::{from_value, json};
+
+            let mut guild = gen();
+
+            for (user_id, channel_id) in [(210, Some("5")), (2, Some("6")), (3, None)] {
+                let voice_state: VoiceState = from_value(json!({
+                    "channel_id": channel_id,
+                    "user_id": user_id.to_string(),
+                    "session_id": "session",
+                    "deaf": false,
+                    "mute": false,
+                    "self_deaf": false,
+                    "self_mute": false,
+                    "self_video": false,
+                    "suppress": false,
+                }))
+                .unwrap();
+
+                guild.voice_states.insert(UserId(user_id), voice_state);
+            }
+
+            let members = guild.voice_channel_members(ChannelId(5));
+            assert_eq!(members.len(), 1);
+            assert_eq!(members[0].user.id, UserId(210));
+
+            // The member of user 2 is not cached.
+            assert!(guild.voice_channel_members(ChannelId(6)).is_empty());
+            assert!(guild.voice_channel_members(ChannelId(7)).is_empty());
+        }
+
         #[test]
         fn member_named_username() {
             let guild = gen();
@@ -3353,5 +3937,128 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn channels_sorted() {
+            let mut guild = gen();
+
+            let channels = [
+                (1, 4, None, 1),
+                (2, 4, None, 0),
+                (3, 0, Some(2), 0),
+                (4, 2, Some(1), 0),
+                (5, 0, Some(1), 1),
+                (6, 2, None, 0),
+                (7, 0, None, 5),
+                (8, 0, Some(9), 0),
+            ];
+
+            for (id, kind, parent_id, position) in channels {
+                let channel = from_value::<Channel>(json!({
+                    "id": id.to_string(),
+                    "guild_id": "1",
+                    "type": kind,
+                    "name": "channel",
+                    "parent_id": parent_id.map(|id: u64| id.to_string()),
+                    "position": position,
+                    "permission_overwrites": [],
+                    "nsfw": false,
+                }))
+                .unwrap();
+
+                guild.channels.insert(channel.id(), channel);
+            }
+
+            let ids = guild.channels_sorted().iter().map(|c| c.id().0).collect::<Vec<_>>();
+
+            assert_eq!(ids, vec![8, 7, 6, 2, 3, 1, 5, 4]);
+        }
+
+        #[test]
+        fn format_emojis() {
+            let mut guild = gen();
+
+            for (id, name, animated) in [(1, "wave", false), (2, "party", true)] {
+                let emoji = from_value::<Emoji>(json!({
+                    "id": id.to_string(),
+                    "name": name,
+                    "animated": animated,
+                }))
+                .unwrap();
+
+                guild.emojis.insert(emoji.id, emoji);
+            }
+
+            assert_eq!(guild.emoji_by_name("party").map(|e| e.id), Some(EmojiId(2)));
+            assert!(guild.emoji_by_name("unknown").is_none());
+
+            assert_eq!(
+                guild.format_emojis(":wave: hi :unknown: :party:: <:wave:1> 12:30 :"),
+                "<:wave:1> hi :unknown: <a:party:2>: <:wave:1> 12:30 :"
+            );
+        }
+
+        #[test]
+        fn guild_info_with_counts() {
+            let value = json!({
+                "id": "1",
+                "icon": null,
+                "name": "guild",
+                "owner": false,
+                "permissions": "8",
+                "approximate_member_count": 10,
+                "approximate_presence_count": 3,
+            });
+
+            let guild = from_value::<GuildInfoWithCounts>(value.clone()).unwrap();
+            assert_eq!(guild.info.id, GuildId(1));
+            assert_eq!(guild.info.name, "guild");
+            assert_eq!(guild.info.permissions, Permissions::ADMINISTRATOR);
+            assert_eq!(guild.approximate_member_count, 10);
+            assert_eq!(guild.approximate_presence_count, 3);
+
+            let guild = from_value::<GuildInfo>(value).unwrap();
+            assert_eq!(GuildId::from(&guild), GuildId(1));
+        }
+
+        #[test]
+        fn check_create_guild() {
+            use crate::json::{JsonMap, Value};
+            use crate::Error;
+
+            fn map(value: Value) -> JsonMap {
+                match value {
+                    Value::Object(map) => map,
+                    _ => unreachable!(),
+                }
+            }
+
+            for length in [2, 100] {
+                let valid = map(json!({ "name": "a".repeat(length) }));
+                assert!(Guild::check_create_guild(&valid).is_ok());
+            }
+
+            let too_short = map(json!({ "name": "a" }));
+            assert!(matches!(
+                Guild::check_create_guild(&too_short),
+                Err(Error::Model(ModelError::NameTooShort))
+            ));
+
+            let too_long = map(json!({ "name": "a".repeat(101) }));
+            assert!(matches!(
+                Guild::check_create_guild(&too_long),
+                Err(Error::Model(ModelError::NameTooLong))
+            ));
+
+            let everyone_first =
+                map(json!({ "name": "guild", "roles": [{ "id": 0 }, { "id": 1 }] }));
+            assert!(Guild::check_create_guild(&everyone_first).is_ok());
+
+            let everyone_not_first = map(json!({ "name": "guild", "roles": [{ "id": 1 }] }));
+            assert!(matches!(
+                Guild::check_create_guild(&everyone_not_first),
+                Err(Error::Model(ModelError::EveryoneRoleNotFirst))
+            ));
+        }
     }
 }
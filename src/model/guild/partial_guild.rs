@@ -1,3 +1,5 @@
+#[cfg(feature = "model")]
+use futures::stream::Stream;
 use serde::de::Error as DeError;
 #[cfg(feature = "cache")]
 use tracing::{error, warn};
@@ -8,8 +10,10 @@ use crate::builder::{
     CreateApplicationCommandPermissionsData,
     CreateApplicationCommands,
     CreateChannel,
+    CreateEmoji,
     CreateSticker,
     EditAutoModRule,
+    EditChannelPositions,
     EditGuild,
     EditGuildWelcomeScreen,
     EditGuildWidget,
@@ -36,6 +40,8 @@ use crate::json::{from_number, from_value};
 use crate::model::application::command::{Command, CommandPermission};
 #[cfg(feature = "model")]
 use crate::model::guild::automod::Rule;
+#[cfg(feature = "model")]
+use crate::model::guild::emoji::replace_shortcodes;
 use crate::model::prelude::*;
 use crate::model::utils::{emojis, roles, stickers};
 
@@ -440,6 +446,40 @@ impl PartialGuild {
         self.id.create_emoji(&http, name, image).await
     }
 
+    /// Creates an emoji in the guild from an image file or URL, with the data
+    /// set, if any.
+    ///
+    /// Refer to the documentation for [`GuildId::create_emoji_with`] for more
+    /// information.
+    ///
+    /// **Note**: Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
+    /// if the current user does not have permission to manage emojis.
+    ///
+    /// Otherwise returns the same errors as [`GuildId::create_emoji_with`].
+    ///
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    pub async fn create_emoji_with<'a, F>(&self, cache_http: impl CacheHttp, f: F) -> Result<Emoji>
+    where
+        for<'b> F: FnOnce(&'b mut CreateEmoji<'a>) -> &'b mut CreateEmoji<'a>,
+    {
+        #[cfg(feature = "cache")]
+        {
+            if cache_http.cache().is_some() {
+                let req = Permissions::MANAGE_EMOJIS_AND_STICKERS;
+
+                if !self.has_perms(&cache_http, req).await {
+                    return Err(Error::Model(ModelError::InvalidPermissions(req)));
+                }
+            }
+        }
+
+        self.id.create_emoji_with(cache_http.http(), f).await
+    }
+
     /// Creates an integration for the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1114,7 +1154,7 @@ impl PartialGuild {
             return Ok(Permissions::all());
         }
 
-        let everyone = if let Some(everyone) = self.roles.get(&RoleId(self.id.0)) {
+        let everyone = if let Some(everyone) = self.everyone_role() {
             everyone
         } else {
             error!("@everyone role ({}) missing in '{}'", self.id, self.name,);
@@ -1162,6 +1202,27 @@ impl PartialGuild {
         self.id.reorder_channels(&http, channels).await
     }
 
+    /// Edits the positions of several channels of the guild at once,
+    /// optionally moving them to another category.
+    ///
+    /// Refer to [`GuildId::edit_channel_positions`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the current user is lacking permission,
+    /// or if a channel is not in the guild.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    #[inline]
+    pub async fn edit_channel_positions<F>(&self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditChannelPositions) -> &mut EditChannelPositions,
+    {
+        self.id.edit_channel_positions(&http, f).await
+    }
+
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
     ///
@@ -1263,15 +1324,27 @@ impl PartialGuild {
     }
 
     /// Returns a formatted URL of the guild's icon, if the guild has an icon.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the guild has a GIF icon.
     #[must_use]
     pub fn icon_url(&self) -> Option<String> {
-        self.icon.as_ref().map(|icon| cdn!("/icons/{}/{}.webp", self.id, icon))
+        self.icon.as_ref().map(|icon| {
+            let ext = if icon.starts_with("a_") { "gif" } else { "webp" };
+
+            cdn!("/icons/{}/{}.{}", self.id, icon, ext)
+        })
     }
 
     /// Returns a formatted URL of the guild's banner, if the guild has a banner.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the guild has a GIF banner.
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
-        self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp", self.id, banner))
+        self.banner.as_ref().map(|banner| {
+            let ext = if banner.starts_with("a_") { "gif" } else { "webp" };
+
+            cdn!("/banners/{}/{}.{}", self.id, banner, ext)
+        })
     }
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
@@ -1295,6 +1368,27 @@ impl PartialGuild {
         self.id.emoji(http, emoji_id).await
     }
 
+    /// Gets all [`Sticker`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn stickers(&self, http: impl AsRef<Http>) -> Result<Vec<Sticker>> {
+        self.id.stickers(http).await
+    }
+
+    /// Gets a [`Sticker`] of this guild by its Id via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a [`Sticker`] with the given Id does not
+    /// exist for the guild.
+    #[inline]
+    pub async fn sticker(&self, http: impl AsRef<Http>, sticker_id: StickerId) -> Result<Sticker> {
+        self.id.sticker(http, sticker_id).await
+    }
+
     /// Gets all integration of the guild.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1309,6 +1403,76 @@ impl PartialGuild {
         self.id.integrations(&http).await
     }
 
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// Refer to [`GuildId::create_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if the name or description are too long.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        self.id.create_template(&http, name, description).await
+    }
+
+    /// Gets the [`GuildTemplate`]s of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(&self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        self.id.templates(&http).await
+    }
+
+    /// Syncs a [`GuildTemplate`] to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(&self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        self.id.sync_template(&http, code).await
+    }
+
+    /// Deletes a [`GuildTemplate`] by its code, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a template with that code does not exist.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        &self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        self.id.delete_template(&http, code).await
+    }
+
     /// Gets all of the guild's invites.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1373,6 +1537,33 @@ impl PartialGuild {
         self.id.members(&http, limit, after).await
     }
 
+    /// Streams over all the members in the guild.
+    ///
+    /// Refer to [`GuildId::members_iter`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::guild::PartialGuild;
+    /// #
+    /// # async fn run(http: Http, guild: PartialGuild) {
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut members = guild.members_iter(&http).boxed();
+    /// while let Some(member_result) = members.next().await {
+    ///     match member_result {
+    ///         Ok(member) => println!("{} is {}", member, member.display_name()),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    #[inline]
+    pub fn members_iter<H: AsRef<Http>>(&self, http: H) -> impl Stream<Item = Result<Member>> {
+        self.id.members_iter(http)
+    }
+
     /// Moves a member to a specific voice channel.
     ///
     /// Requires the [Move Members] permission.
@@ -1536,6 +1727,22 @@ impl PartialGuild {
         self.id.vanity_url(&http).await
     }
 
+    /// Retrieves the guild's vanity invite code along with its number of
+    /// uses, or [`None`] if the guild has no vanity URL.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Guild::vanity_url_uses`].
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [`Guild::vanity_url_uses`]: crate::model::guild::Guild::vanity_url_uses
+    #[inline]
+    pub async fn vanity_url_uses(&self, http: impl AsRef<Http>) -> Result<Option<GuildVanityUrl>> {
+        self.id.vanity_url_uses(&http).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -1551,6 +1758,31 @@ impl PartialGuild {
         self.id.webhooks(&http).await
     }
 
+    /// Retrieves the settings of the guild's [`GuildWidget`].
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// See [`Guild::widget`].
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    /// [`Guild::widget`]: crate::model::guild::Guild::widget
+    #[inline]
+    pub async fn widget(&self, http: impl AsRef<Http>) -> Result<GuildWidget> {
+        self.id.get_widget(http).await
+    }
+
+    /// Returns the URL of the guild's widget image, as displayed in the given
+    /// style.
+    ///
+    /// **Note**: The image is only available if the widget is enabled.
+    #[inline]
+    #[must_use]
+    pub fn widget_image_url(&self, style: GuildWidgetStyle) -> String {
+        self.id.widget_image_url(style)
+    }
+
     /// Obtain a reference to a role by its name.
     ///
     /// **Note**: If two or more roles have the same name, obtained reference will be one of
@@ -1594,6 +1826,63 @@ impl PartialGuild {
         self.roles.values().find(|role| role_name == role.name)
     }
 
+    /// Gets the `@everyone` role of the guild, whose ID is the same as the
+    /// guild's ID.
+    ///
+    /// Returns [`None`] if the role is not in [`Self::roles`].
+    #[inline]
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        self.roles.get(&RoleId(self.id.0))
+    }
+
+    /// Gets the permissions every member of the guild has, which are the
+    /// permissions of the [`@everyone` role].
+    ///
+    /// Returns empty permissions if the role is not in [`Self::roles`].
+    ///
+    /// [`@everyone` role]: Self::everyone_role
+    #[inline]
+    #[must_use]
+    pub fn default_permissions(&self) -> Permissions {
+        self.everyone_role().map_or_else(Permissions::empty, |role| role.permissions)
+    }
+
+    /// Gets the emoji of the guild with the given name, if there is one.
+    ///
+    /// Only this guild's emojis are searched, so an emoji of the same name
+    /// in another guild is never returned. If several of the guild's emojis
+    /// share the name, any of them may be returned.
+    ///
+    /// The emoji can be used in message content via its [`Display`] impl,
+    /// which formats it as `<:name:id>`, or `<a:name:id>` if it is animated.
+    ///
+    /// [`Display`]: std::fmt::Display
+    #[must_use]
+    pub fn emoji_by_name(&self, name: &str) -> Option<&Emoji> {
+        self.emojis.values().find(|emoji| emoji.name == name)
+    }
+
+    /// Replaces each `:name:` shortcode in the content with the guild's emoji
+    /// of that name, as found by [`Self::emoji_by_name`].
+    ///
+    /// Shortcodes not matching any of the guild's emojis are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::guild::PartialGuild;
+    /// # fn run(guild: PartialGuild) {
+    /// // With an animated emoji named "party" in the guild, prints e.g.
+    /// // "Welcome! <a:party:81384788765712384>"
+    /// println!("{}", guild.format_emojis("Welcome! :party:"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn format_emojis(&self, content: &str) -> String {
+        replace_shortcodes(content, |name| self.emoji_by_name(name))
+    }
+
     /// Returns a future that will await one message sent in this guild.
     #[cfg(feature = "collector")]
     pub fn await_reply(&self, shard_messenger: impl AsRef<ShardMessenger>) -> CollectReply {
@@ -1624,7 +1913,12 @@ impl PartialGuild {
         ReactionCollectorBuilder::new(shard_messenger).guild_id(self.id.0)
     }
 
-    /// Gets the guild active threads.
+    /// Gets all active threads of the guild in a single request, including
+    /// private threads. The endpoint is not paginated, so
+    /// [`ThreadsData::has_more`] is always `false`.
+    ///
+    /// [`ThreadsData::members`] contains a thread member for each returned
+    /// thread the current user has joined.
     ///
     /// # Errors
     ///
@@ -196,6 +196,18 @@ impl Role {
             self.permissions.contains(permissions)
         }
     }
+
+    /// Returns the formatted URL of the role's icon, if one exists.
+    ///
+    /// This will produce a WEBP image URL, or GIF if the role has a GIF icon.
+    #[must_use]
+    pub fn icon_url(&self) -> Option<String> {
+        self.icon.as_ref().map(|icon| {
+            let ext = if icon.starts_with("a_") { "gif" } else { "webp" };
+
+            cdn!("/role-icons/{}/{}.{}?size=1024", self.id, icon, ext)
+        })
+    }
 }
 
 impl fmt::Display for Role {
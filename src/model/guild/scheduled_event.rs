@@ -1,3 +1,10 @@
+#[cfg(feature = "model")]
+use futures::stream::{Stream, TryStreamExt};
+
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
 use crate::model::prelude::*;
 
 /// Information about a guild scheduled event.
@@ -48,6 +55,38 @@ pub struct ScheduledEvent {
     pub image: Option<String>,
 }
 
+#[cfg(feature = "model")]
+impl ScheduledEvent {
+    /// Fetches all users interested in the event, with their [`member`]
+    /// populated if they are a member of the guild.
+    ///
+    /// This requests the users in pages of at most 100, refer to
+    /// [`Self::subscribers_iter`] to stream over them instead.
+    ///
+    /// **Note**: Requires the [Manage Events] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the
+    /// event no longer exists.
+    ///
+    /// [`member`]: ScheduledEventUser::member
+    /// [Manage Events]: Permissions::MANAGE_EVENTS
+    pub async fn subscribers(&self, http: impl AsRef<Http>) -> Result<Vec<ScheduledEventUser>> {
+        self.subscribers_iter(http).try_collect().await
+    }
+
+    /// Streams over all users interested in the event.
+    ///
+    /// Refer to [`GuildId::scheduled_event_users_iter`] for more information.
+    pub fn subscribers_iter<H: AsRef<Http>>(
+        &self,
+        http: H,
+    ) -> impl Stream<Item = Result<ScheduledEventUser>> {
+        self.guild_id.scheduled_event_users_iter(http, self.id)
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/guild-scheduled-event#guild-scheduled-event-object-guild-scheduled-event-status).
 #[derive(Copy, Clone, Debug)]
 pub enum ScheduledEventStatus {
@@ -0,0 +1,273 @@
+use super::*;
+use crate::model::Timestamp;
+
+/// A snapshot of a guild's setup, which can be used to create new guilds.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The unique code of the template.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of times the template has been used.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced to the source guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template is based on.
+    pub source_guild_id: GuildId,
+    /// The snapshot of the source guild.
+    pub serialized_source_guild: TemplateGuild,
+    /// Whether the source guild has changes not yet synced to the template.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(feature = "model")]
+impl GuildTemplate {
+    /// Creates a new guild from the template.
+    ///
+    /// Only a [`PartialGuild`] will be immediately returned, and a full
+    /// [`Guild`] will be received over a [`Shard`].
+    ///
+    /// **Note**: This endpoint can be used only by bots in less than 10
+    /// guilds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user cannot create a guild.
+    ///
+    /// [`Shard`]: crate::gateway::Shard
+    pub async fn create_guild(
+        &self,
+        http: impl AsRef<Http>,
+        name: &str,
+        icon: Option<&str>,
+    ) -> Result<PartialGuild> {
+        let map = json!({
+            "icon": icon,
+            "name": name,
+        });
+
+        http.as_ref().create_guild_from_template(&self.code, &map).await
+    }
+
+    /// Syncs the template to the current state of its source guild.
+    ///
+    /// Refer to [`GuildId::sync_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.sync_template(http, &self.code).await
+    }
+
+    /// Deletes the template.
+    ///
+    /// Refer to [`GuildId::delete_template`] for more information.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if the template does not exist anymore.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete(&self, http: impl AsRef<Http>) -> Result<GuildTemplate> {
+        self.source_guild_id.delete_template(http, &self.code).await
+    }
+}
+
+/// The guild snapshot stored in a [`GuildTemplate`].
+///
+/// Roles and channels are identified by placeholder Ids, which are only unique
+/// within the template. The `@everyone` role always has the Id `0`.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object-example-guild-template-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateGuild {
+    /// The name of the guild.
+    pub name: String,
+    /// The description of the guild.
+    pub description: Option<String>,
+    /// The voice region of the guild.
+    pub region: Option<String>,
+    /// The verification level of the guild.
+    pub verification_level: VerificationLevel,
+    /// The default notification level of the guild.
+    pub default_message_notifications: DefaultMessageNotificationLevel,
+    /// The explicit content filter level of the guild.
+    pub explicit_content_filter: ExplicitContentFilter,
+    /// The preferred locale of the guild.
+    pub preferred_locale: String,
+    /// The number of seconds until a member is moved to the AFK channel.
+    pub afk_timeout: u64,
+    /// The roles of the guild.
+    pub roles: Vec<TemplateRole>,
+    /// The channels of the guild.
+    pub channels: Vec<TemplateChannel>,
+    /// The placeholder Id of the AFK channel.
+    pub afk_channel_id: Option<u64>,
+    /// The placeholder Id of the system channel.
+    pub system_channel_id: Option<u64>,
+    /// The system channel flags of the guild.
+    pub system_channel_flags: SystemChannelFlags,
+    /// The icon hash of the guild.
+    pub icon_hash: Option<String>,
+}
+
+/// A role stored in a [`TemplateGuild`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateRole {
+    /// The placeholder Id of the role.
+    pub id: u64,
+    /// The name of the role.
+    pub name: String,
+    /// The permissions of the role.
+    pub permissions: Permissions,
+    /// The colour of the role. This is an ergonomic representation of the inner
+    /// value.
+    #[cfg(feature = "utils")]
+    #[serde(rename = "color")]
+    pub colour: Colour,
+    /// The colour of the role.
+    #[cfg(not(feature = "utils"))]
+    #[serde(rename = "color")]
+    pub colour: u32,
+    /// Whether the role is pinned above lesser roles.
+    pub hoist: bool,
+    /// Whether the role can be mentioned.
+    pub mentionable: bool,
+}
+
+/// A channel stored in a [`TemplateGuild`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct TemplateChannel {
+    /// The placeholder Id of the channel.
+    pub id: u64,
+    /// The type of the channel.
+    #[serde(rename = "type")]
+    pub kind: ChannelType,
+    /// The name of the channel.
+    pub name: String,
+    /// The position of the channel.
+    pub position: i64,
+    /// The topic of the channel.
+    pub topic: Option<String>,
+    /// The bitrate of the voice channel.
+    pub bitrate: Option<u64>,
+    /// The user limit of the voice channel.
+    pub user_limit: Option<u64>,
+    /// Whether the channel is NSFW.
+    #[serde(default)]
+    pub nsfw: bool,
+    /// The slowmode of the channel, in seconds.
+    pub rate_limit_per_user: Option<u64>,
+    /// The placeholder Id of the parent category.
+    pub parent_id: Option<u64>,
+    /// The permission overwrites of the channel.
+    ///
+    /// **Note**: Role overwrites refer to the placeholder Ids of
+    /// [`TemplateRole`]s.
+    pub permission_overwrites: Vec<PermissionOverwrite>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::GuildTemplate;
+    use crate::json::{from_value, json};
+    use crate::model::channel::{ChannelType, PermissionOverwriteType};
+    use crate::model::id::RoleId;
+
+    #[test]
+    fn template_deserialize() {
+        let value = json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": null,
+            "usage_count": 49605,
+            "creator_id": "132837293881950208",
+            "creator": {
+                "id": "132837293881950208",
+                "username": "hoges",
+                "avatar": "79b0d2ea3212b4f4b3c8d1f2f0c0fbd3",
+                "discriminator": "0001",
+                "public_flags": 131072
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "description": null,
+                "region": "us-west",
+                "verification_level": 0,
+                "default_message_notifications": 0,
+                "explicit_content_filter": 0,
+                "preferred_locale": "en-US",
+                "afk_timeout": 300,
+                "roles": [{
+                    "id": 0,
+                    "name": "@everyone",
+                    "permissions": "104324689",
+                    "color": 0,
+                    "hoist": false,
+                    "mentionable": false
+                }],
+                "channels": [{
+                    "name": "general",
+                    "position": 0,
+                    "topic": null,
+                    "bitrate": 64000,
+                    "user_limit": 0,
+                    "nsfw": false,
+                    "rate_limit_per_user": 0,
+                    "parent_id": null,
+                    "permission_overwrites": [{
+                        "id": 0,
+                        "type": 0,
+                        "allow": "0",
+                        "deny": "2048"
+                    }],
+                    "id": 2,
+                    "type": 0
+                }],
+                "afk_channel_id": null,
+                "system_channel_id": 2,
+                "system_channel_flags": 0,
+                "icon_hash": null
+            },
+            "is_dirty": null
+        });
+
+        let template = from_value::<GuildTemplate>(value).unwrap();
+        let guild = &template.serialized_source_guild;
+
+        assert_eq!(guild.roles[0].id, 0);
+        assert_eq!(guild.channels[0].kind, ChannelType::Text);
+        assert_eq!(guild.system_channel_id, Some(2));
+        assert_eq!(
+            guild.channels[0].permission_overwrites[0].kind,
+            PermissionOverwriteType::Role(RoleId(0))
+        );
+    }
+}
@@ -73,6 +73,11 @@ pub trait Mentionable {
 /// [`Mention`] is created using [`Mentionable::mention()`], or with
 /// [`From`]/[`Into`].
 ///
+/// Role mentions always use the `<@&ROLE_ID>` format, including for the
+/// `@everyone` role, whose ID is the same as the guild's. Discord does not
+/// ping anyone for such a mention, so send the literal `@everyone` text to
+/// mention everyone instead.
+///
 /// [`Display`]: fmt::Display
 ///
 /// # Examples
@@ -227,10 +232,15 @@ mod test {
             rtc_region: None,
             video_quality_mode: None,
             message_count: None,
+            total_message_sent: None,
             member_count: None,
             thread_metadata: None,
             member: None,
             default_auto_archive_duration: None,
+            default_thread_rate_limit_per_user: None,
+            available_tags: Vec::new(),
+            applied_tags: Vec::new(),
+            flags: None,
         });
         let emoji = Emoji {
             animated: false,
@@ -279,6 +289,7 @@ mod test {
             permissions: None,
             avatar: None,
             communication_disabled_until: None,
+            flags: GuildMemberFlags::default(),
         };
 
         assert_eq!(ChannelId(1).mention().to_string(), "<#1>");
@@ -288,6 +299,11 @@ mod test {
         assert_eq!(member.mention().to_string(), "<@6>");
         assert_eq!(role.mention().to_string(), "<@&2>");
         assert_eq!(role.id.mention().to_string(), "<@&2>");
+        let everyone = Role {
+            id: RoleId(1),
+            ..role
+        };
+        assert_eq!(everyone.mention().to_string(), everyone.id.mention().to_string());
         assert_eq!(user.mention().to_string(), "<@6>");
         assert_eq!(user.id.mention().to_string(), "<@6>");
     }
@@ -219,6 +219,25 @@ pub const PRESET_VOICE: Permissions = Permissions {
     bits: Permissions::CONNECT.bits | Permissions::SPEAK.bits | Permissions::USE_VAD.bits,
 };
 
+/// Returns the set of permissions making a member a moderator of a stage
+/// channel.
+///
+/// Stage moderators can invite members to speak, move speakers back to the
+/// audience and manage the channel's stage instance. This requires all of:
+///
+/// - [Manage Channels]
+/// - [Mute Members]
+/// - [Move Members]
+///
+/// [Manage Channels]: Permissions::MANAGE_CHANNELS
+/// [Mute Members]: Permissions::MUTE_MEMBERS
+/// [Move Members]: Permissions::MOVE_MEMBERS
+pub const STAGE_MODERATOR: Permissions = Permissions {
+    bits: Permissions::MANAGE_CHANNELS.bits
+        | Permissions::MUTE_MEMBERS.bits
+        | Permissions::MOVE_MEMBERS.bits,
+};
+
 bitflags::bitflags! {
     /// A set of permissions that can be assigned to [`User`]s and [`Role`]s via
     /// [`PermissionOverwrite`]s, roles globally in a [`Guild`], and to
@@ -740,6 +759,15 @@ impl Permissions {
         self.contains(Self::REQUEST_TO_SPEAK)
     }
 
+    /// Shorthand for checking that the set of permissions contains all
+    /// [stage moderator] permissions.
+    ///
+    /// [stage moderator]: STAGE_MODERATOR
+    #[must_use]
+    pub fn stage_moderator(self) -> bool {
+        self.contains(STAGE_MODERATOR)
+    }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Use Embedded Activities] permission.
     ///
@@ -851,4 +879,11 @@ mod tests {
         let value = Permissions::MANAGE_GUILD | Permissions::MANAGE_ROLES;
         assert_tokens(&value, &[Token::Str("268435488")]);
     }
+
+    #[test]
+    fn stage_moderator() {
+        assert!(STAGE_MODERATOR.stage_moderator());
+        assert!((STAGE_MODERATOR | Permissions::SPEAK).stage_moderator());
+        assert!(!(Permissions::MUTE_MEMBERS | Permissions::MOVE_MEMBERS).stage_moderator());
+    }
 }
@@ -22,9 +22,13 @@ use crate::collector::{
     MessageCollectorBuilder,
     ReactionCollectorBuilder,
 };
+#[cfg(all(feature = "cache", feature = "model"))]
+use crate::http::error::DiscordJsonErrorCode;
 #[cfg(feature = "model")]
 use crate::http::GuildPagination;
 #[cfg(feature = "model")]
+use crate::http::HttpError;
+#[cfg(feature = "model")]
 use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -260,6 +264,9 @@ impl CurrentUser {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::UsernameRateLimited`] if the username was
+    /// changed too often: Discord only allows 2 username changes per hour.
+    ///
     /// Returns an [`Error::Http`] if an invalid value is set.
     /// May also return an [`Error::Json`] if there is an error in
     /// deserializing the API response.
@@ -278,7 +285,12 @@ impl CurrentUser {
         f(&mut edit_profile);
         let map = json::hashmap_to_json_map(edit_profile.0);
 
-        *self = http.as_ref().edit_profile(&map).await?;
+        *self = match http.as_ref().edit_profile(&map).await {
+            Err(Error::Http(why)) if is_username_rate_limited(&why) => {
+                return Err(Error::Model(ModelError::UsernameRateLimited));
+            },
+            result => result?,
+        };
 
         Ok(())
     }
@@ -853,7 +865,8 @@ impl User {
     /// is a bot user.
     ///
     /// May also return an [`Error::Http`] if the message was illformed, or if the
-    /// user cannot be sent a direct message.
+    /// user cannot be sent a direct message. The latter can be detected with
+    /// [`HttpError::is_cannot_message_user`].
     ///
     /// [`Error::Json`] can also be returned if there is an error deserializing
     /// the API response.
@@ -861,7 +874,35 @@ impl User {
     where
         for<'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
     {
-        self.create_dm_channel(&cache_http).await?.send_message(&cache_http.http(), f).await
+        let mut create_message = CreateMessage::default();
+        f(&mut create_message);
+
+        let channel_id = self.create_dm_channel(&cache_http).await?.id;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                // The cached channel may have been closed in the meantime, in
+                // which case it is dropped and a new one is created.
+                let builder = create_message.clone();
+
+                match channel_id.send_message(cache_http.http(), |m| set_builder(m, builder)).await
+                {
+                    Err(Error::Http(why)) if is_unknown_channel(&why) => {
+                        cache.remove_user_private_channel(self.id);
+                    },
+                    result => return result,
+                }
+
+                let channel_id = self.create_dm_channel(&cache_http).await?.id;
+
+                return channel_id
+                    .send_message(cache_http.http(), |m| set_builder(m, create_message))
+                    .await;
+            }
+        }
+
+        channel_id.send_message(cache_http.http(), |m| set_builder(m, create_message)).await
     }
 
     /// This is an alias of [`Self::direct_message`].
@@ -1110,12 +1151,8 @@ impl UserId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                for channel_entry in cache.private_channels().iter() {
-                    let channel = channel_entry.value();
-
-                    if channel.recipient.id == self {
-                        return Ok(channel.clone());
-                    }
+                if let Some(channel) = cache.user_private_channel(self) {
+                    return Ok(channel);
                 }
             }
         }
@@ -1124,7 +1161,21 @@ impl UserId {
             "recipient_id": self.0,
         });
 
-        cache_http.http().create_private_channel(&map).await
+        let channel = cache_http.http().create_private_channel(&map).await?;
+
+        // Discord doesn't send a `CHANNEL_CREATE` event for DM channels
+        // anymore, so the channel is cached here to avoid creating it again
+        // for every message.
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cache.update(&mut ChannelCreateEvent {
+                    channel: Channel::Private(channel.clone()),
+                });
+            }
+        }
+
+        Ok(channel)
     }
 
     /// Attempts to find a [`User`] by its Id in the cache.
@@ -1274,6 +1325,30 @@ fn banner_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
     })
 }
 
+#[cfg(feature = "model")]
+fn set_builder<'a, 'b>(
+    m: &'b mut CreateMessage<'a>,
+    builder: CreateMessage<'a>,
+) -> &'b mut CreateMessage<'a> {
+    *m = builder;
+    m
+}
+
+#[cfg(all(feature = "cache", feature = "model"))]
+fn is_unknown_channel(why: &HttpError) -> bool {
+    why.discord_error_code() == Some(DiscordJsonErrorCode::UnknownChannel)
+}
+
+#[cfg(feature = "model")]
+fn is_username_rate_limited(why: &HttpError) -> bool {
+    why.error_response().map_or(false, |res| {
+        res.error
+            .errors
+            .iter()
+            .any(|error| error.path == "username" && error.code == "USERNAME_RATE_LIMITED")
+    })
+}
+
 #[cfg(feature = "model")]
 fn tag(name: &str, discriminator: u16) -> String {
     // 32: max length of username
@@ -1371,7 +1446,9 @@ mod test {
 
     #[cfg(feature = "model")]
     mod model {
-        use crate::model::user::User;
+        use crate::http::error::{DiscordJsonError, DiscordJsonSingleError, ErrorResponse};
+        use crate::http::HttpError;
+        use crate::model::user::{is_username_rate_limited, User};
 
         #[test]
         fn test_core() {
@@ -1410,5 +1487,50 @@ mod test {
             user.discriminator = 4;
             assert!(user.default_avatar_url().ends_with("4.png"));
         }
+
+        #[test]
+        fn test_username_rate_limited() {
+            let error = |path: &str, code: &str| {
+                HttpError::UnsuccessfulRequest(ErrorResponse {
+                    status_code: reqwest::StatusCode::BAD_REQUEST,
+                    url: String::from("https://ferris.crab").parse().unwrap(),
+                    error: DiscordJsonError {
+                        code: 50035,
+                        message: String::from("Invalid Form Body"),
+                        errors: vec![DiscordJsonSingleError {
+                            code: code.to_string(),
+                            message: String::new(),
+                            path: path.to_string(),
+                        }],
+                    },
+                })
+            };
+
+            assert!(is_username_rate_limited(&error("username", "USERNAME_RATE_LIMITED")));
+            assert!(!is_username_rate_limited(&error("username", "BASE_TYPE_BAD_LENGTH")));
+            assert!(!is_username_rate_limited(&error("avatar", "USERNAME_RATE_LIMITED")));
+        }
+
+        #[cfg(feature = "cache")]
+        #[test]
+        fn test_unknown_channel() {
+            use crate::model::user::is_unknown_channel;
+
+            let error = |code| {
+                HttpError::UnsuccessfulRequest(ErrorResponse {
+                    status_code: reqwest::StatusCode::NOT_FOUND,
+                    url: String::from("https://ferris.crab").parse().unwrap(),
+                    error: DiscordJsonError {
+                        code,
+                        message: String::new(),
+                        errors: vec![],
+                    },
+                })
+            };
+
+            assert!(is_unknown_channel(&error(10003)));
+            assert!(!is_unknown_channel(&error(10008)));
+            assert!(!is_unknown_channel(&error(50007)));
+        }
     }
 }
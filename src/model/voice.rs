@@ -32,18 +32,33 @@ pub struct VoiceRegion {
 #[derive(Clone, Serialize)]
 #[non_exhaustive]
 pub struct VoiceState {
+    /// The Id of the voice channel the user is connected to.
+    ///
+    /// This is [`None`] when the user disconnected.
     pub channel_id: Option<ChannelId>,
+    /// Whether the user is deafened by the server.
     pub deaf: bool,
+    /// The Id of the guild the voice state is for.
     pub guild_id: Option<GuildId>,
+    /// The guild member the voice state is for.
     pub member: Option<Member>,
+    /// Whether the user is muted by the server.
     pub mute: bool,
+    /// Whether the user deafened themselves.
     pub self_deaf: bool,
+    /// Whether the user muted themselves.
     pub self_mute: bool,
+    /// Whether the user is streaming using "Go Live".
     pub self_stream: Option<bool>,
+    /// Whether the user's camera is enabled.
     pub self_video: bool,
+    /// The session Id of the voice state.
     pub session_id: String,
+    /// Whether the user is muted by the current user, or is not allowed to
+    /// speak in a stage channel.
     pub suppress: bool,
     pub token: Option<String>,
+    /// The Id of the user the voice state is for.
     pub user_id: UserId,
     /// When unsuppressed, non-bot users will have this set to the current time.
     /// Bot users will be set to [`None`]. When suppressed, the user will have
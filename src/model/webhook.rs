@@ -7,7 +7,7 @@ use super::channel::Message;
 use super::id::{ChannelId, GuildId, WebhookId};
 use super::user::User;
 #[cfg(feature = "model")]
-use crate::builder::{EditWebhookMessage, ExecuteWebhook};
+use crate::builder::{EditWebhook, EditWebhookMessage, ExecuteWebhook};
 #[cfg(feature = "model")]
 use crate::http::Http;
 #[cfg(feature = "model")]
@@ -222,6 +222,115 @@ impl Webhook {
         http.as_ref().delete_webhook_with_token(self.id.0, token).await
     }
 
+    /// Edits the webhook, using bot authentication.
+    ///
+    /// This allows to move the webhook to another channel, as opposed to
+    /// [`Self::edit_with_token`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// # Examples
+    ///
+    /// Move a webhook to another channel and rename it:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::webhook::Webhook;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let mut webhook = Webhook::from_id(&http, 245037420704169985).await?;
+    ///
+    /// webhook.edit(&http, |w| w.name("new name").channel_id(81384788765712384)).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`WebhookId::edit`].
+    ///
+    /// [Manage Webhooks]: super::permissions::Permissions::MANAGE_WEBHOOKS
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditWebhook) -> &mut EditWebhook,
+    {
+        let webhook = self.id.edit(http, f).await?;
+        let token = self.token.take();
+
+        *self = webhook;
+
+        // The token is not always included when editing with bot authentication.
+        if self.token.is_none() {
+            self.token = token;
+        }
+
+        Ok(())
+    }
+
+    /// Edits the webhook using its token, which does not require
+    /// authentication.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::webhook::Webhook;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let url = "https://discord.com/api/webhooks/245037420704169985/ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV";
+    /// let mut webhook = Webhook::from_url(&http, url).await?;
+    ///
+    /// webhook.edit_with_token(&http, |w| w.name("new name").avatar(None)).await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Model`] if the [`Self::token`] is [`None`], or a
+    /// [`ModelError::WebhookChannelWithToken`] if [`EditWebhook::channel_id`]
+    /// was set, as moving the webhook requires bot authentication.
+    ///
+    /// Returns a [`ModelError::NameTooShort`] or [`ModelError::NameTooLong`] if
+    /// the name is not between 2 and 100 characters long.
+    ///
+    /// May also return an [`Error::Http`] if the content is malformed, or if the token is invalid.
+    pub async fn edit_with_token<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditWebhook) -> &mut EditWebhook,
+    {
+        let token = self.token.as_ref().ok_or(ModelError::NoTokenSet)?;
+
+        let mut edit_webhook = EditWebhook::default();
+        f(&mut edit_webhook);
+
+        let map = json::hashmap_to_json_map(edit_webhook.0);
+        Self::check_name(&map)?;
+
+        if map.contains_key("channel_id") {
+            return Err(Error::Model(ModelError::WebhookChannelWithToken));
+        }
+
+        *self = http.as_ref().edit_webhook_with_token(self.id.0, token, &map).await?;
+        Ok(())
+    }
+
+    pub(crate) fn check_name(map: &JsonMap) -> Result<()> {
+        if let Some(name) = map.get("name").and_then(Value::as_str) {
+            let length = name.chars().count();
+
+            if length < 2 {
+                return Err(Error::Model(ModelError::NameTooShort));
+            } else if length > 100 {
+                return Err(Error::Model(ModelError::NameTooLong));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Edits the name of a webhook.
     ///
     /// Refer to [`Http::edit_webhook`] for restrictions on webhook names.
@@ -540,4 +649,69 @@ impl WebhookId {
     pub async fn to_webhook(self, http: impl AsRef<Http>) -> Result<Webhook> {
         http.as_ref().get_webhook(self.0).await
     }
+
+    /// Edits the webhook, using bot authentication.
+    ///
+    /// Unlike editing a webhook with its token, this allows to move the webhook
+    /// to another channel via [`EditWebhook::channel_id`].
+    ///
+    /// **Note**: Requires the [Manage Webhooks] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::NameTooShort`] or [`ModelError::NameTooLong`] if
+    /// the name is not between 2 and 100 characters long.
+    ///
+    /// Otherwise returns an [`Error::Http`] if the current user lacks
+    /// permission, or if the content is malformed.
+    ///
+    /// [Manage Webhooks]: super::permissions::Permissions::MANAGE_WEBHOOKS
+    pub async fn edit<F>(self, http: impl AsRef<Http>, f: F) -> Result<Webhook>
+    where
+        F: FnOnce(&mut EditWebhook) -> &mut EditWebhook,
+    {
+        let mut edit_webhook = EditWebhook::default();
+        f(&mut edit_webhook);
+
+        let map = json::hashmap_to_json_map(edit_webhook.0);
+        Webhook::check_name(&map)?;
+
+        http.as_ref().edit_webhook(self.0, &Value::from(map), None).await
+    }
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::Webhook;
+    use crate::http::Http;
+    use crate::json::{from_value, json};
+    use crate::model::ModelError;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn test_edit_with_token_checks() {
+        let http = Http::new("");
+        let mut webhook: Webhook = from_value(json!({
+            "id": "1",
+            "type": 1,
+            "token": "token",
+        }))
+        .unwrap();
+
+        assert!(matches!(
+            webhook.edit_with_token(&http, |w| w.channel_id(2)).await,
+            Err(Error::Model(ModelError::WebhookChannelWithToken))
+        ));
+        assert!(matches!(
+            webhook.edit_with_token(&http, |w| w.name("a")).await,
+            Err(Error::Model(ModelError::NameTooShort))
+        ));
+
+        webhook.token = None;
+
+        assert!(matches!(
+            webhook.edit_with_token(&http, |w| w.name("name")).await,
+            Err(Error::Model(ModelError::NoTokenSet))
+        ));
+    }
 }
@@ -366,6 +366,7 @@ mod tests {
             permissions: None,
             avatar: None,
             communication_disabled_until: None,
+            flags: GuildMemberFlags::default(),
         };
 
         let role = Role {
@@ -401,10 +402,15 @@ mod tests {
             rtc_region: None,
             video_quality_mode: None,
             message_count: None,
+            total_message_sent: None,
             member_count: None,
             thread_metadata: None,
             member: None,
             default_auto_archive_duration: None,
+            default_thread_rate_limit_per_user: None,
+            available_tags: Vec::new(),
+            applied_tags: Vec::new(),
+            flags: None,
         };
 
         let cache = Arc::new(Cache::default());
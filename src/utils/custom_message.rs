@@ -275,7 +275,11 @@ fn dummy_message() -> Message {
         flags: None,
         sticker_items: Vec::new(),
         referenced_message: None,
+        referenced_message_deleted: false,
         interaction: None,
+        interaction_metadata: None,
         components: vec![],
+        call: None,
+        thread: None,
     }
 }
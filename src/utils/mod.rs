@@ -9,6 +9,7 @@ mod colour;
 mod content_safe;
 mod custom_message;
 mod message_builder;
+mod split_message;
 
 pub mod token;
 
@@ -21,6 +22,7 @@ use url::Url;
 pub use self::colour::{colours, Colour};
 pub use self::custom_message::CustomMessage;
 pub use self::message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder};
+pub use self::split_message::split_message;
 #[doc(inline)]
 pub use self::token::{parse as parse_token, validate as validate_token};
 pub type Color = Colour;
@@ -41,6 +43,33 @@ pub(crate) fn encode_image(raw: &[u8]) -> String {
     encoded
 }
 
+/// Returns the MIME type of an image, detected from its first bytes, or from
+/// the extension of its filename if they are not recognised.
+#[cfg(feature = "model")]
+pub(crate) fn image_mime_type(raw: &[u8], filename: Option<&str>) -> &'static str {
+    if raw.starts_with(b"\x89PNG\r\n\x1a\n") {
+        return "image/png";
+    } else if raw.starts_with(b"GIF87a") || raw.starts_with(b"GIF89a") {
+        return "image/gif";
+    } else if raw.starts_with(b"\xff\xd8\xff") {
+        return "image/jpeg";
+    } else if raw.len() >= 12 && raw.starts_with(b"RIFF") && &raw[8..12] == b"WEBP" {
+        return "image/webp";
+    }
+
+    let extension = filename
+        .and_then(|filename| Path::new(filename).extension())
+        .and_then(OsStr::to_str)
+        .map(str::to_lowercase);
+
+    match extension.as_deref() {
+        Some("gif") => "image/gif",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("webp") => "image/webp",
+        _ => "image/png",
+    }
+}
+
 /// Retrieves the "code" part of an invite out of a URL.
 ///
 /// # Examples
@@ -82,6 +111,57 @@ pub fn parse_invite(code: &str) -> &str {
     }
 }
 
+/// The hosts and paths invite codes are preceded by in invite links, in lowercase.
+const INVITE_HOSTS: &[&str] = &["discord.gg/", "discord.com/invite/", "discordapp.com/invite/"];
+
+/// Finds the invite links in a text and retrieves their "code" parts, in the
+/// order they appear in.
+///
+/// Links are recognised regardless of case, protocol prefix, and `www.`
+/// subdomain, for the `discord.gg`, `discord.com/invite` and
+/// `discordapp.com/invite` forms. Links that are part of another word or host,
+/// such as `notdiscord.gg/abc`, are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::parse_invites;
+///
+/// let content = "Join discord.gg/abc or https://www.discord.com/invite/Def-123!";
+///
+/// assert_eq!(parse_invites(content), vec!["abc", "Def-123"]);
+/// assert!(parse_invites("notdiscord.gg/abc").is_empty());
+/// ```
+#[must_use]
+pub fn parse_invites(content: &str) -> Vec<String> {
+    // ASCII lowercasing keeps the byte indices of both strings in sync.
+    let lower = content.to_ascii_lowercase();
+
+    let mut invites = INVITE_HOSTS
+        .iter()
+        .flat_map(|host| lower.match_indices(host).map(move |(index, _)| (index, host.len())))
+        .filter(|&(index, _)| {
+            let before = lower[..index].strip_suffix("www.").unwrap_or(&lower[..index]);
+
+            match before.chars().next_back() {
+                None => true,
+                Some('/') => before.ends_with("://"),
+                Some(c) => !(c.is_alphanumeric() || matches!(c, '.' | '-' | '_' | '@')),
+            }
+        })
+        .filter_map(|(index, host_len)| {
+            let rest = &content[index + host_len..];
+            let end =
+                rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-')).unwrap_or(rest.len());
+
+            (end > 0).then(|| (index, rest[..end].to_string()))
+        })
+        .collect::<Vec<_>>();
+
+    invites.sort_by_key(|&(index, _)| index);
+    invites.into_iter().map(|(_, code)| code).collect()
+}
+
 /// Retrieves the username and discriminator out of a user tag (`name#discrim`).
 ///
 /// If the user tag is invalid, None is returned.
@@ -440,9 +520,52 @@ pub fn parse_webhook(url: &Url) -> Option<(u64, &str)> {
     Some((webhook_id.parse().ok()?, token))
 }
 
+/// Sets the size of an image URL of Discord's CDN, such as the ones returned
+/// by [`User::avatar_url`] or [`Guild::icon_url`], replacing any size the URL
+/// already requests.
+///
+/// The size must be a power of 2 between 16 and 4096. The URL is returned
+/// unchanged if it can't be parsed.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils;
+///
+/// let url = "https://cdn.discordapp.com/avatars/1/abc.webp?size=1024";
+///
+/// assert_eq!(
+///     utils::cdn_url_with_size(url, 256),
+///     "https://cdn.discordapp.com/avatars/1/abc.webp?size=256",
+/// );
+/// ```
+///
+/// [`User::avatar_url`]: crate::model::user::User::avatar_url
+/// [`Guild::icon_url`]: crate::model::guild::Guild::icon_url
+#[must_use]
+pub fn cdn_url_with_size(url: &str, size: u16) -> String {
+    let mut parsed = match Url::parse(url) {
+        Ok(parsed) => parsed,
+        Err(_) => return url.to_string(),
+    };
+
+    let query = parsed
+        .query_pairs()
+        .filter(|(key, _)| key != "size")
+        .map(|(key, value)| (key.into_owned(), value.into_owned()))
+        .collect::<Vec<_>>();
+
+    parsed.query_pairs_mut().clear().extend_pairs(query).append_pair("size", &size.to_string());
+
+    parsed.into()
+}
+
 /// Calculates the Id of the shard responsible for a guild, given its Id and
 /// total number of shards used.
 ///
+/// **Note**: Direct messages are not tied to a guild and are always sent to
+/// shard `0`.
+///
 /// # Examples
 ///
 /// Retrieve the Id of the shard for a guild with Id `81384788765712384`, using
@@ -462,6 +585,17 @@ pub fn shard_id(guild_id: impl Into<u64>, shard_count: u64) -> u64 {
 mod test {
     use super::*;
 
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_image_mime_type() {
+        assert_eq!(image_mime_type(b"\x89PNG\r\n\x1a\n\0\0", None), "image/png");
+        assert_eq!(image_mime_type(b"GIF89a\0\0", Some("emoji.png")), "image/gif");
+        assert_eq!(image_mime_type(b"\xff\xd8\xff\xe0", None), "image/jpeg");
+        assert_eq!(image_mime_type(b"RIFF\0\0\0\0WEBPVP8 ", None), "image/webp");
+        assert_eq!(image_mime_type(b"", Some("emoji.JPG")), "image/jpeg");
+        assert_eq!(image_mime_type(b"", None), "image/png");
+    }
+
     #[test]
     fn test_invite_parser() {
         assert_eq!(parse_invite("https://discord.gg/abc"), "abc");
@@ -473,6 +607,21 @@ mod test {
         assert_eq!(parse_invite("discord.com/invite/abc"), "abc");
     }
 
+    #[test]
+    fn test_invites_parser() {
+        assert_eq!(parse_invites("discord.gg/abc"), vec!["abc"]);
+        assert_eq!(
+            parse_invites("see <https://discordapp.com/invite/abc>, (DISCORD.GG/def)."),
+            vec!["abc", "def"]
+        );
+        assert_eq!(
+            parse_invites("http://www.discord.com/invite/a-b?event=1 and discord.gg/c"),
+            vec!["a-b", "c"]
+        );
+        assert!(parse_invites("notdiscord.gg/abc mydiscord.com/invite/abc").is_empty());
+        assert!(parse_invites("example.com/discord.gg/abc discord.gg/ and discord.gg").is_empty());
+    }
+
     #[test]
     fn test_username_parser() {
         assert_eq!(parse_username("<@12345>").unwrap(), 12_345);
@@ -509,4 +658,17 @@ mod test {
         assert_eq!(id, 245037420704169985);
         assert_eq!(token, "ig5AO-wdVWpCBtUUMxmgsWryqgsW3DChbKYOINftJ4DCrUbnkedoYZD0VOH1QLr-S3sV");
     }
+
+    #[test]
+    fn test_cdn_url_with_size() {
+        assert_eq!(
+            cdn_url_with_size("https://cdn.discordapp.com/icons/1/a_abc.gif", 64),
+            "https://cdn.discordapp.com/icons/1/a_abc.gif?size=64"
+        );
+        assert_eq!(
+            cdn_url_with_size("https://cdn.discordapp.com/avatars/1/abc.webp?size=1024", 4096),
+            "https://cdn.discordapp.com/avatars/1/abc.webp?size=4096"
+        );
+        assert_eq!(cdn_url_with_size("not a url", 64), "not a url");
+    }
 }
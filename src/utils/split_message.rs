@@ -0,0 +1,199 @@
+use std::mem;
+
+use crate::constants::MESSAGE_CODE_LIMIT;
+
+const FENCE: &str = "```";
+
+/// Splits content into multiple messages, each of which is at most
+/// [`MESSAGE_CODE_LIMIT`] unicode code points long.
+///
+/// The content is split at newlines where possible. Lines that are too long
+/// for a single message are split at whitespace, or at any character if they
+/// contain none.
+///
+/// Code blocks are kept balanced: if a split happens inside a code block, the
+/// block is closed at the end of the message and reopened, with the same
+/// language, at the start of the next one.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::utils::split_message;
+///
+/// let content = "a line\n".repeat(500);
+/// let messages = split_message(&content);
+///
+/// assert_eq!(messages.len(), 2);
+/// assert!(messages.iter().all(|m| m.chars().count() <= 2000));
+/// ```
+#[must_use]
+pub fn split_message(content: &str) -> Vec<String> {
+    split_message_with_limit(content, MESSAGE_CODE_LIMIT)
+}
+
+fn split_message_with_limit(content: &str, limit: usize) -> Vec<String> {
+    let mut splitter = Splitter {
+        limit,
+        ..Splitter::default()
+    };
+
+    for line in content.split_inclusive('\n') {
+        splitter.push_line(line);
+    }
+
+    splitter.finish()
+}
+
+#[derive(Default)]
+struct Splitter<'a> {
+    limit: usize,
+    messages: Vec<String>,
+    current: String,
+    /// The length of `current` in unicode code points.
+    current_len: usize,
+    /// The length of the reopened code block at the start of `current`.
+    header_len: usize,
+    /// The opening fence of the code block at the end of `current`, if any.
+    fence: Option<&'a str>,
+}
+
+impl<'a> Splitter<'a> {
+    fn push_line(&mut self, line: &'a str) {
+        // A line with an odd number of fences opens or closes a code block.
+        let fence = if line.matches(FENCE).count() % 2 == 1 {
+            match self.fence {
+                Some(_) => None,
+                None => Some(opening_fence(line)),
+            }
+        } else {
+            self.fence
+        };
+
+        let mut rest = line;
+
+        while !rest.is_empty() {
+            // Reserve room to close the code block the line ends in.
+            let reserved = if fence.is_some() { FENCE.len() + 1 } else { 0 };
+            let available = self.limit.saturating_sub(self.current_len + reserved);
+            let len = rest.chars().count();
+
+            if len <= available {
+                self.push_str(rest, len);
+
+                break;
+            }
+
+            // Try to fit the whole line into the next message first.
+            if self.current_len > self.header_len {
+                self.flush();
+
+                continue;
+            }
+
+            let end = rest.char_indices().nth(available.max(1)).map_or(rest.len(), |(i, _)| i);
+            let split = rest[..end]
+                .char_indices()
+                .rev()
+                .find(|(_, c)| c.is_whitespace())
+                .map_or(end, |(i, c)| i + c.len_utf8());
+
+            let (piece, remainder) = rest.split_at(split);
+            self.push_str(piece, piece.chars().count());
+            self.flush();
+
+            rest = remainder;
+        }
+
+        self.fence = fence;
+    }
+
+    fn push_str(&mut self, s: &str, len: usize) {
+        self.current.push_str(s);
+        self.current_len += len;
+    }
+
+    /// Ends the current message, closing and reopening the current code block.
+    fn flush(&mut self) {
+        let mut message = mem::take(&mut self.current);
+        message.truncate(message.trim_end().len());
+
+        if let Some(fence) = self.fence {
+            message.push('\n');
+            message.push_str(FENCE);
+
+            self.current.push_str(fence);
+            self.current.push('\n');
+        }
+
+        if !message.is_empty() {
+            self.messages.push(message);
+        }
+
+        self.current_len = self.current.chars().count();
+        self.header_len = self.current_len;
+    }
+
+    fn finish(mut self) -> Vec<String> {
+        if self.current_len > self.header_len {
+            let message = self.current.trim_end();
+            self.messages.push(message.to_string());
+        }
+
+        self.messages
+    }
+}
+
+/// Returns the fence to reopen a code block started by the given line with,
+/// keeping its language.
+fn opening_fence(line: &str) -> &str {
+    let line = line.trim();
+
+    match line.strip_prefix(FENCE) {
+        // Only keep a plausible language, as the fence is repeated in every
+        // message the code block spans.
+        Some(language)
+            if !language.is_empty()
+                && language.len() <= 32
+                && !language.contains(char::is_whitespace)
+                && !language.contains('`') =>
+        {
+            line
+        },
+        _ => FENCE,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::split_message_with_limit;
+
+    #[test]
+    fn test_split_lines() {
+        assert!(split_message_with_limit("", 10).is_empty());
+        assert_eq!(split_message_with_limit("short", 10), vec!["short"]);
+        assert_eq!(split_message_with_limit("abc\ndef\nghi\n", 8), vec!["abc\ndef", "ghi"]);
+    }
+
+    #[test]
+    fn test_split_long_line() {
+        assert_eq!(split_message_with_limit("abc def ghi", 8), vec!["abc def", "ghi"]);
+        assert_eq!(split_message_with_limit("abcdefghij", 4), vec!["abcd", "efgh", "ij"]);
+        assert_eq!(split_message_with_limit("äöüäöü", 4), vec!["äöüä", "öü"]);
+    }
+
+    #[test]
+    fn test_split_code_block() {
+        let content = "text\n```rust\nlet a = 1;\nlet b = 2;\n```\nafter";
+        let messages = split_message_with_limit(content, 30);
+
+        assert_eq!(messages, vec![
+            "text\n```rust\nlet a = 1;\n```",
+            "```rust\nlet b = 2;\n```\nafter",
+        ]);
+
+        for message in messages {
+            assert!(message.chars().count() <= 30);
+            assert_eq!(message.matches("```").count() % 2, 0);
+        }
+    }
+}